@@ -4,7 +4,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -24,6 +24,10 @@ impl AppDefinition for ActionLint {
     formatcp!("https://{ORG}.github.io/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn latest_installable_version(&self, log: Log) -> Result<Version> {
     github_releases::latest(ORG, REPO, &self.tag_format(), log)
   }
@@ -47,6 +51,9 @@ impl AppDefinition for ActionLint {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/actionlint_{version}_{os}_{cpu}.{ext}").into(),
+          checksum: Checksum::File {
+            url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/actionlint_{version}_checksums.txt").into(),
+          },
           bin_folder: BinFolder::Root,
         },
         Method::CompileGoSource {
@@ -85,7 +92,7 @@ mod tests {
     use crate::applications::actionlint::ActionLint;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -102,6 +109,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_linux_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -125,6 +135,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_linux_amd64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -148,6 +161,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_darwin_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -171,6 +187,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_darwin_amd64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -194,6 +213,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_windows_arm64.zip".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -217,6 +239,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_windows_amd64.zip".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/rhysd/actionlint/releases/download/v1.7.12/actionlint_1.7.12_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -0,0 +1,471 @@
+//! applications that the user requests by their location, like "github:org/repo" or "npm:package", without them being built into run-that-app
+
+use super::{AnalyzeResult, AppDefinition, ApplicationName};
+use crate::Log;
+use crate::configuration::{TagFormat, Version};
+use crate::error::{Result, UserError};
+use crate::executables::{Executable, ExecutableNameUnix, RunMethod};
+use crate::hosting::{crates_io, github_releases, go_proxy, npm_registry};
+use crate::installation::{BinFolder, Method};
+use crate::platform::Platform;
+
+/// prefix of the names of apps that get installed from the release assets of a GitHub repository
+const GITHUB_PREFIX: &str = "github:";
+
+/// prefix of the names of apps that get installed as npm packages
+const NPM_PREFIX: &str = "npm:";
+
+/// prefix of the names of apps that get compiled from Go source code
+const GO_PREFIX: &str = "go:";
+
+/// prefix of the names of apps that get compiled from Rust crates on crates.io
+const CARGO_PREFIX: &str = "cargo:";
+
+/// creates the ad-hoc app with the given name, None if the given name doesn't describe an ad-hoc app
+pub(super) fn create(name: &str) -> Option<Result<Box<dyn AppDefinition>>> {
+  if let Some(location) = name.strip_prefix(GITHUB_PREFIX) {
+    return Some(GitHubApp::parse(location).map(|app| Box::new(app) as Box<dyn AppDefinition>));
+  }
+  if let Some(package) = name.strip_prefix(NPM_PREFIX) {
+    return Some(NpmPackage::parse(package).map(|app| Box::new(app) as Box<dyn AppDefinition>));
+  }
+  if let Some(import_path) = name.strip_prefix(GO_PREFIX) {
+    return Some(GoPackage::parse(import_path).map(|app| Box::new(app) as Box<dyn AppDefinition>));
+  }
+  if let Some(crate_name) = name.strip_prefix(CARGO_PREFIX) {
+    return Some(RustCrate::parse(crate_name).map(|app| Box::new(app) as Box<dyn AppDefinition>));
+  }
+  None
+}
+
+/// an application installed from the release assets of a GitHub repository, requested as "github:org/repo"
+#[derive(Clone, Debug, PartialEq)]
+pub struct GitHubApp {
+  name: String,
+  homepage: String,
+  org: String,
+  repo: String,
+}
+
+impl GitHubApp {
+  /// parses the given "org/repo" location
+  fn parse(location: &str) -> Result<GitHubApp> {
+    let Some((org, repo)) = location.split_once('/') else {
+      return Err(UserError::InvalidAdhocAppName {
+        name: format!("{GITHUB_PREFIX}{location}"),
+        format: "github:owner/repo",
+      });
+    };
+    if !is_valid_name(org) || !is_valid_name(repo) {
+      return Err(UserError::InvalidAdhocAppName {
+        name: format!("{GITHUB_PREFIX}{location}"),
+        format: "github:owner/repo",
+      });
+    }
+    Ok(GitHubApp {
+      name: format!("{GITHUB_PREFIX}{org}/{repo}"),
+      homepage: format!("https://github.com/{org}/{repo}"),
+      org: org.to_string(),
+      repo: repo.to_string(),
+    })
+  }
+}
+
+impl AppDefinition for GitHubApp {
+  fn name(&self) -> ApplicationName {
+    self.name.clone().into()
+  }
+
+  fn executable_filename(&self) -> ExecutableNameUnix {
+    ExecutableNameUnix::from(self.repo.as_str())
+  }
+
+  fn homepage(&self) -> &str {
+    &self.homepage
+  }
+
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((&self.org, &self.repo))
+  }
+
+  fn latest_installable_version(&self, log: Log) -> Result<Version> {
+    github_releases::latest(&self.org, &self.repo, &self.tag_format(), log)
+  }
+
+  fn run_method(&self, version: &Version, _platform: Platform) -> RunMethod {
+    RunMethod::ThisApp {
+      install_methods: vec![Method::DownloadGitHubAsset {
+        org: self.org.clone(),
+        repo: self.repo.clone(),
+        tag: self.tag_format().format_version(version),
+      }],
+    }
+  }
+
+  fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
+    github_releases::versions(&self.org, &self.repo, amount, &self.tag_format(), log)
+  }
+
+  fn analyze_executable(&self, _executable: &Executable) -> Result<AnalyzeResult> {
+    // there is no general way to ask an arbitrary executable for its version
+    Ok(AnalyzeResult::IdentifiedButUnknownVersion)
+  }
+
+  fn tag_format(&self) -> TagFormat {
+    // the version of ad-hoc apps is the Git tag of the release
+    TagFormat::Plain
+  }
+}
+
+/// an npm package, requested as "npm:package" or "npm:@scope/package"
+#[derive(Clone, Debug, PartialEq)]
+pub struct NpmPackage {
+  name: String,
+  homepage: String,
+  package: String,
+  /// name of the executable that the package provides, which by convention is the package name without the scope
+  script: String,
+}
+
+impl NpmPackage {
+  /// parses the given npm package name
+  fn parse(package: &str) -> Result<NpmPackage> {
+    let script = match package.strip_prefix('@') {
+      Some(scoped) => match scoped.split_once('/') {
+        Some((scope, name)) if is_valid_name(scope) => Some(name),
+        _ => None,
+      },
+      None => Some(package),
+    };
+    let Some(script) = script.filter(|script| is_valid_name(script)) else {
+      return Err(UserError::InvalidAdhocAppName {
+        name: format!("{NPM_PREFIX}{package}"),
+        format: "npm:package or npm:@scope/package",
+      });
+    };
+    Ok(NpmPackage {
+      name: format!("{NPM_PREFIX}{package}"),
+      homepage: format!("https://www.npmjs.com/package/{package}"),
+      script: script.to_string(),
+      package: package.to_string(),
+    })
+  }
+}
+
+impl AppDefinition for NpmPackage {
+  fn name(&self) -> ApplicationName {
+    self.name.clone().into()
+  }
+
+  fn executable_filename(&self) -> ExecutableNameUnix {
+    ExecutableNameUnix::from(self.script.as_str())
+  }
+
+  fn homepage(&self) -> &str {
+    &self.homepage
+  }
+
+  fn latest_installable_version(&self, log: Log) -> Result<Version> {
+    npm_registry::latest(&self.package, log)
+  }
+
+  fn run_method(&self, _version: &Version, _platform: Platform) -> RunMethod {
+    RunMethod::NodeJS {
+      package: self.package.clone(),
+      script: self.script.clone(),
+    }
+  }
+
+  fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
+    npm_registry::versions(&self.package, amount, log)
+  }
+
+  fn analyze_executable(&self, _executable: &Executable) -> Result<AnalyzeResult> {
+    Ok(AnalyzeResult::IdentifiedButUnknownVersion)
+  }
+
+  fn tag_format(&self) -> TagFormat {
+    TagFormat::Plain
+  }
+}
+
+/// a Go package containing a main function, requested as "go:import/path"
+#[derive(Clone, Debug, PartialEq)]
+pub struct GoPackage {
+  name: String,
+  homepage: String,
+  import_path: String,
+  /// name of the executable that "go install" creates, which is the last element of the import path
+  executable: String,
+}
+
+impl GoPackage {
+  /// parses the given Go import path
+  fn parse(import_path: &str) -> Result<GoPackage> {
+    let elements: Vec<&str> = import_path.split('/').collect();
+    // "go install" names the executable after the last path element that isn't a major version suffix like "v2"
+    let executable = elements.iter().rev().find(|element| !is_major_version_suffix(element));
+    let Some(executable) = executable.filter(|_| elements.len() > 1 && elements.iter().all(|element| is_valid_name(element))) else {
+      return Err(UserError::InvalidAdhocAppName {
+        name: format!("{GO_PREFIX}{import_path}"),
+        format: "go:example.com/org/repo/cmd/tool",
+      });
+    };
+    Ok(GoPackage {
+      name: format!("{GO_PREFIX}{import_path}"),
+      homepage: format!("https://pkg.go.dev/{import_path}"),
+      executable: executable.to_string(),
+      import_path: import_path.to_string(),
+    })
+  }
+}
+
+impl AppDefinition for GoPackage {
+  fn name(&self) -> ApplicationName {
+    self.name.clone().into()
+  }
+
+  fn executable_filename(&self) -> ExecutableNameUnix {
+    ExecutableNameUnix::from(self.executable.as_str())
+  }
+
+  fn homepage(&self) -> &str {
+    &self.homepage
+  }
+
+  fn latest_installable_version(&self, log: Log) -> Result<Version> {
+    go_proxy::latest(&self.import_path, log)
+  }
+
+  fn run_method(&self, version: &Version, _platform: Platform) -> RunMethod {
+    RunMethod::ThisApp {
+      install_methods: vec![Method::CompileGoSource {
+        import_path: format!("{}@{version}", self.import_path),
+      }],
+    }
+  }
+
+  fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
+    go_proxy::versions(&self.import_path, amount, log)
+  }
+
+  fn analyze_executable(&self, _executable: &Executable) -> Result<AnalyzeResult> {
+    Ok(AnalyzeResult::IdentifiedButUnknownVersion)
+  }
+
+  fn tag_format(&self) -> TagFormat {
+    // Go module versions include the "v" prefix, e.g. "v0.20.0"
+    TagFormat::Plain
+  }
+}
+
+/// a Rust crate on crates.io that contains an executable, requested as "cargo:crate"
+#[derive(Clone, Debug, PartialEq)]
+pub struct RustCrate {
+  name: String,
+  homepage: String,
+  crate_name: String,
+}
+
+impl RustCrate {
+  /// parses the given crate name
+  fn parse(crate_name: &str) -> Result<RustCrate> {
+    if !is_valid_name(crate_name) || crate_name.contains('.') {
+      return Err(UserError::InvalidAdhocAppName {
+        name: format!("{CARGO_PREFIX}{crate_name}"),
+        format: "cargo:crate",
+      });
+    }
+    Ok(RustCrate {
+      name: format!("{CARGO_PREFIX}{crate_name}"),
+      homepage: format!("https://crates.io/crates/{crate_name}"),
+      crate_name: crate_name.to_string(),
+    })
+  }
+}
+
+impl AppDefinition for RustCrate {
+  fn name(&self) -> ApplicationName {
+    self.name.clone().into()
+  }
+
+  fn executable_filename(&self) -> ExecutableNameUnix {
+    ExecutableNameUnix::from(self.crate_name.as_str())
+  }
+
+  fn homepage(&self) -> &str {
+    &self.homepage
+  }
+
+  fn latest_installable_version(&self, log: Log) -> Result<Version> {
+    crates_io::latest(&self.crate_name, log)
+  }
+
+  fn run_method(&self, version: &Version, _platform: Platform) -> RunMethod {
+    RunMethod::ThisApp {
+      install_methods: vec![Method::CompileRustCrate {
+        // the versions of this app are crates.io versions, so cargo can install exactly the requested one
+        name: format!("{}@{version}", self.crate_name),
+        bin_folder: BinFolder::Subfolder { path: "bin".into() },
+      }],
+    }
+  }
+
+  fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
+    crates_io::versions(&self.crate_name, amount, log)
+  }
+
+  fn analyze_executable(&self, _executable: &Executable) -> Result<AnalyzeResult> {
+    Ok(AnalyzeResult::IdentifiedButUnknownVersion)
+  }
+
+  fn tag_format(&self) -> TagFormat {
+    TagFormat::Plain
+  }
+}
+
+/// indicates whether the given element of a Go import path is a major version suffix like "v2"
+fn is_major_version_suffix(element: &str) -> bool {
+  element
+    .strip_prefix('v')
+    .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// indicates whether the given text is a valid name for a GitHub organization or repository, a package, or an element of an import path
+fn is_valid_name(text: &str) -> bool {
+  !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod create {
+    use super::super::create;
+    use crate::error::UserError;
+    use crate::executables::RunMethod;
+    use crate::installation::{BinFolder, Method};
+    use crate::platform::{Cpu, Os, Platform};
+    use big_s::S;
+
+    const PLATFORM: Platform = Platform {
+      os: Os::Linux,
+      cpu: Cpu::Intel64,
+    };
+
+    #[test]
+    fn github_app() {
+      let app = create("github:org/tool").unwrap().unwrap();
+      assert_eq!(app.name(), "github:org/tool");
+      assert_eq!(app.executable_filename().as_ref(), "tool");
+      assert_eq!(app.homepage(), "https://github.com/org/tool");
+      assert_eq!(app.github_repo(), Some(("org", "tool")));
+      let have = app.run_method(&"v1.2.3".into(), PLATFORM);
+      let want = RunMethod::ThisApp {
+        install_methods: vec![Method::DownloadGitHubAsset {
+          org: S("org"),
+          repo: S("tool"),
+          tag: S("v1.2.3"),
+        }],
+      };
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn missing_repo() {
+      let have = create("github:org").unwrap();
+      assert_eq!(
+        have.err(),
+        Some(UserError::InvalidAdhocAppName {
+          name: S("github:org"),
+          format: "github:owner/repo",
+        })
+      );
+    }
+
+    #[test]
+    fn invalid_characters() {
+      let have = create("github:org/tool/extra").unwrap();
+      assert_eq!(
+        have.err(),
+        Some(UserError::InvalidAdhocAppName {
+          name: S("github:org/tool/extra"),
+          format: "github:owner/repo",
+        })
+      );
+    }
+
+    #[test]
+    fn npm_package() {
+      let app = create("npm:cowsay").unwrap().unwrap();
+      assert_eq!(app.executable_filename().as_ref(), "cowsay");
+      let have = app.run_method(&"1.6.0".into(), PLATFORM);
+      let want = RunMethod::NodeJS {
+        package: "cowsay".into(),
+        script: "cowsay".into(),
+      };
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn scoped_npm_package() {
+      let app = create("npm:@biomejs/biome").unwrap().unwrap();
+      assert_eq!(app.executable_filename().as_ref(), "biome");
+      assert_eq!(app.homepage(), "https://www.npmjs.com/package/@biomejs/biome");
+    }
+
+    #[test]
+    fn invalid_npm_package() {
+      assert!(create("npm:@biomejs").unwrap().is_err());
+      assert!(create("npm:").unwrap().is_err());
+    }
+
+    #[test]
+    fn go_package() {
+      let app = create("go:golang.org/x/tools/cmd/stringer").unwrap().unwrap();
+      assert_eq!(app.executable_filename().as_ref(), "stringer");
+      let have = app.run_method(&"v0.20.0".into(), PLATFORM);
+      let want = RunMethod::ThisApp {
+        install_methods: vec![Method::CompileGoSource {
+          import_path: S("golang.org/x/tools/cmd/stringer@v0.20.0"),
+        }],
+      };
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn go_package_with_major_version() {
+      let app = create("go:github.com/org/tool/v2").unwrap().unwrap();
+      assert_eq!(app.executable_filename().as_ref(), "tool");
+    }
+
+    #[test]
+    fn invalid_go_package() {
+      assert!(create("go:stringer").unwrap().is_err());
+      assert!(create("go:golang.org//stringer").unwrap().is_err());
+    }
+
+    #[test]
+    fn rust_crate() {
+      let app = create("cargo:tokei").unwrap().unwrap();
+      assert_eq!(app.executable_filename().as_ref(), "tokei");
+      let have = app.run_method(&"12.1.2".into(), PLATFORM);
+      let want = RunMethod::ThisApp {
+        install_methods: vec![Method::CompileRustCrate {
+          name: "tokei@12.1.2".into(),
+          bin_folder: BinFolder::Subfolder { path: "bin".into() },
+        }],
+      };
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn invalid_rust_crate() {
+      assert!(create("cargo:to/kei").unwrap().is_err());
+    }
+
+    #[test]
+    fn not_adhoc() {
+      assert!(create("shellcheck").is_none());
+    }
+  }
+}
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::Method;
+use crate::installation::{Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Biome {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm64",
@@ -42,6 +46,7 @@ impl AppDefinition for Biome {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadExecutable {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/biome-{os}-{cpu}{ext}").into(),
+        checksum: Checksum::None,
       }],
     }
   }
@@ -65,7 +70,7 @@ impl AppDefinition for Biome {
     }
   }
   fn tag_format(&self) -> TagFormat {
-    TagFormat::Prefix("@biomejs/biome@")
+    TagFormat::Prefix("@biomejs/biome@".into())
   }
 }
 
@@ -77,7 +82,7 @@ mod tests {
     use crate::applications::biome::Biome;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::Method;
+    use crate::installation::{Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -92,6 +97,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/biomejs/biome/releases/download/%40biomejs%2Fbiome%402.4.8/biome-linux-arm64".into(),
+          checksum: Checksum::None,
         }],
       };
       assert_eq!(have, want);
@@ -109,6 +115,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/biomejs/biome/releases/download/%40biomejs%2Fbiome%402.4.8/biome-linux-x64".into(),
+          checksum: Checksum::None,
         }],
       };
       assert_eq!(have, want);
@@ -126,6 +133,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/biomejs/biome/releases/download/%40biomejs%2Fbiome%402.4.8/biome-darwin-arm64".into(),
+          checksum: Checksum::None,
         }],
       };
       assert_eq!(have, want);
@@ -143,6 +151,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/biomejs/biome/releases/download/%40biomejs%2Fbiome%402.4.8/biome-darwin-x64".into(),
+          checksum: Checksum::None,
         }],
       };
       assert_eq!(have, want);
@@ -160,6 +169,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/biomejs/biome/releases/download/%40biomejs%2Fbiome%402.4.8/biome-win32-arm64.exe".into(),
+          checksum: Checksum::None,
         }],
       };
       assert_eq!(have, want);
@@ -177,6 +187,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/biomejs/biome/releases/download/%40biomejs%2Fbiome%402.4.8/biome-win32-x64.exe".into(),
+          checksum: Checksum::None,
         }],
       };
       assert_eq!(have, want);
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Bun {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -37,6 +41,9 @@ impl AppDefinition for Bun {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/bun-{os}-{cpu}.zip").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/SHASUMS256.txt").into(),
+        },
         bin_folder: BinFolder::Subfolder {
           path: format!("bun-{os}-{cpu}").into(),
         },
@@ -64,7 +71,7 @@ impl AppDefinition for Bun {
   }
 
   fn tag_format(&self) -> TagFormat {
-    TagFormat::Prefix("bun-v")
+    TagFormat::Prefix("bun-v".into())
   }
 }
 
@@ -76,7 +83,7 @@ mod tests {
     use crate::applications::bun::Bun;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -91,6 +98,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/bun-linux-aarch64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "bun-linux-aarch64".into(),
           },
@@ -111,6 +121,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/bun-linux-x64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "bun-linux-x64".into() },
         }],
       };
@@ -129,6 +142,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/bun-darwin-aarch64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "bun-darwin-aarch64".into(),
           },
@@ -149,6 +165,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/bun-darwin-x64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "bun-darwin-x64".into() },
         }],
       };
@@ -167,6 +186,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/bun-windows-aarch64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "bun-windows-aarch64".into(),
           },
@@ -187,6 +209,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/bun-windows-x64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/oven-sh/bun/releases/download/bun-v1.3.8/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "bun-windows-x64".into(),
           },
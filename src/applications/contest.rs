@@ -4,7 +4,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -24,6 +24,10 @@ impl AppDefinition for Contest {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm_64",
@@ -43,6 +47,7 @@ impl AppDefinition for Contest {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/contest_{os}_{cpu}.{ext}").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileRustRepo { url: self.homepage().into() },
@@ -82,7 +87,7 @@ mod tests {
     use crate::applications::contest::Contest;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -98,6 +103,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/contest-framework/server/releases/download/v0.4.0/contest_linux_arm_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -121,6 +127,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/contest-framework/server/releases/download/v0.4.0/contest_linux_intel_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -144,6 +151,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/contest-framework/server/releases/download/v0.4.0/contest_macos_arm_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -167,6 +175,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/contest-framework/server/releases/download/v0.4.0/contest_macos_intel_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -190,6 +199,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/contest-framework/server/releases/download/v0.4.0/contest_windows_arm_64.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -213,6 +223,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/contest-framework/server/releases/download/v0.4.0/contest_windows_intel_64.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
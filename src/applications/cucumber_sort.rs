@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for CucumberSort {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm_64",
@@ -42,6 +46,7 @@ impl AppDefinition for CucumberSort {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/cucumber_sort_{os}_{cpu}.{ext}").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileRustRepo { url: self.homepage().into() },
@@ -81,7 +86,7 @@ mod tests {
     use crate::applications::cucumber_sort::CucumberSort;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -97,6 +102,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/kevgo/cucumber-sort/releases/download/v0.1.0/cucumber_sort_linux_arm_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -120,6 +126,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/kevgo/cucumber-sort/releases/download/v0.1.0/cucumber_sort_linux_intel_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -143,6 +150,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/kevgo/cucumber-sort/releases/download/v0.1.0/cucumber_sort_macos_arm_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -166,6 +174,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/kevgo/cucumber-sort/releases/download/v0.1.0/cucumber_sort_macos_intel_64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -189,6 +198,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/kevgo/cucumber-sort/releases/download/v0.1.0/cucumber_sort_windows_arm_64.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
@@ -212,6 +222,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/kevgo/cucumber-sort/releases/download/v0.1.0/cucumber_sort_windows_intel_64.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustRepo {
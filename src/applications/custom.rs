@@ -0,0 +1,403 @@
+//! applications that users define in their project, without them being built into run-that-app
+
+use super::{AnalyzeResult, AppDefinition, ApplicationName};
+use crate::configuration::{TagFormat, Version};
+use crate::error::{Result, UserError};
+use crate::executables::{Executable, ExecutableNameUnix, RunMethod};
+use crate::hosting::github_releases;
+use crate::installation::{BinFolder, Checksum, Method};
+use crate::platform::{Cpu, Os, Platform};
+use crate::{Log, archives, strings, subshell};
+use regex::Regex;
+use serde_json::Value;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// name of the file that defines custom apps, which lives next to the config file
+pub const APPS_FILE_NAME: &str = "run-that-app.apps.json";
+
+/// an application defined in the apps file of the project
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomApp {
+  name: String,
+  homepage: String,
+  /// the GitHub organization that publishes the releases of this app
+  org: String,
+  /// the GitHub repository that publishes the releases of this app
+  repo: String,
+  /// the prefix of the Git tags for releases, e.g. "v" for tags like "v1.2.3"
+  tag_prefix: String,
+  /// template of the URL to download the app from
+  url: String,
+  /// template of the URL of a file containing the checksums of the downloads
+  checksum_url: Option<String>,
+  /// template of the folder inside the downloaded archive that contains the executable, None if it is in the root folder
+  bin_folder: Option<String>,
+  /// name of the executable if it differs from the app name
+  executable: Option<String>,
+  /// arguments that make the executable print its version
+  version_args: Vec<String>,
+  /// regex whose first capture extracts the version from the output of calling the executable with `version_args`
+  version_regex: Option<String>,
+  /// values for the `{os}` placeholder
+  os: OsValues,
+  /// values for the `{cpu}` placeholder
+  cpu: CpuValues,
+  /// values for the `{ext}` placeholder
+  ext: OsValues,
+}
+
+impl AppDefinition for CustomApp {
+  fn name(&self) -> ApplicationName {
+    self.name.clone().into()
+  }
+
+  fn executable_filename(&self) -> ExecutableNameUnix {
+    ExecutableNameUnix::from(self.executable.as_deref().unwrap_or(&self.name))
+  }
+
+  fn homepage(&self) -> &str {
+    &self.homepage
+  }
+
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((&self.org, &self.repo))
+  }
+
+  fn latest_installable_version(&self, log: Log) -> Result<Version> {
+    github_releases::latest(&self.org, &self.repo, &self.tag_format(), log)
+  }
+
+  fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
+    let url = self.fill(&self.url, version, platform);
+    let checksum = match &self.checksum_url {
+      Some(checksum_url) => Checksum::File {
+        url: self.fill(checksum_url, version, platform).into(),
+      },
+      None => Checksum::None,
+    };
+    let install_method = if archives::is_archive(&url) {
+      Method::DownloadArchive {
+        url: url.into(),
+        checksum,
+        bin_folder: match &self.bin_folder {
+          Some(bin_folder) => BinFolder::Subfolder {
+            path: PathBuf::from(self.fill(bin_folder, version, platform)),
+          },
+          None => BinFolder::Root,
+        },
+      }
+    } else {
+      Method::DownloadExecutable { url: url.into(), checksum }
+    };
+    RunMethod::ThisApp {
+      install_methods: vec![install_method],
+    }
+  }
+
+  fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
+    github_releases::versions(&self.org, &self.repo, amount, &self.tag_format(), log)
+  }
+
+  fn analyze_executable(&self, executable: &Executable) -> Result<AnalyzeResult> {
+    let args: Vec<&str> = self.version_args.iter().map(String::as_str).collect();
+    let output = subshell::capture_output(executable, &args)?;
+    let Some(version_regex) = &self.version_regex else {
+      return Ok(AnalyzeResult::IdentifiedButUnknownVersion);
+    };
+    match strings::first_capture(&output, version_regex) {
+      Ok(version) => Ok(AnalyzeResult::IdentifiedWithVersion(version.into())),
+      Err(_) => Ok(AnalyzeResult::NotIdentified { output }),
+    }
+  }
+
+  fn tag_format(&self) -> TagFormat {
+    match self.tag_prefix.as_str() {
+      "" => TagFormat::Plain,
+      "v" => TagFormat::PrefixV,
+      prefix => TagFormat::Prefix(prefix.to_string()),
+    }
+  }
+}
+
+impl CustomApp {
+  /// replaces the placeholders in the given template
+  fn fill(&self, template: &str, version: &Version, platform: Platform) -> String {
+    template
+      .replace("{version}", version.as_str())
+      .replace("{os}", self.os.get(platform.os))
+      .replace("{cpu}", self.cpu.get(platform.cpu))
+      .replace("{ext}", self.ext.get(platform.os))
+  }
+}
+
+/// values of a placeholder for each operating system
+#[derive(Clone, Debug, PartialEq)]
+struct OsValues {
+  linux: String,
+  macos: String,
+  windows: String,
+}
+
+impl OsValues {
+  fn get(&self, os: Os) -> &str {
+    match os {
+      Os::Linux => &self.linux,
+      Os::MacOS => &self.macos,
+      Os::Windows => &self.windows,
+    }
+  }
+
+  /// parses the given JSON object, using the given defaults for missing operating systems
+  fn parse(value: Option<&Value>, defaults: [&str; 3], problem: &dyn Fn(String) -> UserError) -> Result<OsValues> {
+    let [linux, macos, windows] = defaults;
+    Ok(OsValues {
+      linux: optional_string(value, "linux", problem)?.unwrap_or(linux).to_string(),
+      macos: optional_string(value, "macos", problem)?.unwrap_or(macos).to_string(),
+      windows: optional_string(value, "windows", problem)?.unwrap_or(windows).to_string(),
+    })
+  }
+}
+
+/// values of a placeholder for each CPU architecture
+#[derive(Clone, Debug, PartialEq)]
+struct CpuValues {
+  arm64: String,
+  intel64: String,
+}
+
+impl CpuValues {
+  fn get(&self, cpu: Cpu) -> &str {
+    match cpu {
+      Cpu::Arm64 => &self.arm64,
+      Cpu::Intel64 => &self.intel64,
+    }
+  }
+
+  /// parses the given JSON object, using the default values for missing architectures
+  fn parse(value: Option<&Value>, problem: &dyn Fn(String) -> UserError) -> Result<CpuValues> {
+    Ok(CpuValues {
+      arm64: optional_string(value, "arm64", problem)?.unwrap_or("arm64").to_string(),
+      intel64: optional_string(value, "intel64", problem)?.unwrap_or("amd64").to_string(),
+    })
+  }
+}
+
+/// loads the apps defined in the apps file next to the given config file
+pub fn load_next_to(config_path: &Path) -> Result<Vec<CustomApp>> {
+  let path = config_path.with_file_name(APPS_FILE_NAME);
+  let text = match fs::read_to_string(&path) {
+    Ok(text) => text,
+    Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+    Err(err) => {
+      return Err(UserError::InvalidAppsFile {
+        path,
+        problem: err.to_string(),
+      });
+    }
+  };
+  parse(&text, &path)
+}
+
+/// parses the given content of the apps file at the given path
+fn parse(text: &str, path: &Path) -> Result<Vec<CustomApp>> {
+  let invalid = |problem: String| UserError::InvalidAppsFile {
+    path: path.to_path_buf(),
+    problem,
+  };
+  let value: Value = serde_json::from_str(text).map_err(|err| invalid(err.to_string()))?;
+  let Some(entries) = value.as_array() else {
+    return Err(invalid("the content must be a list of app definitions".into()));
+  };
+  let mut result = vec![];
+  for entry in entries {
+    let Some(name) = optional_string(Some(entry), "name", &invalid)? else {
+      return Err(invalid(r#"an app definition is missing the "name" field"#.into()));
+    };
+    // app names become folder names in the yard and tokens in the config file
+    if name.is_empty() || name.contains(['@', '/']) || name.contains(char::is_whitespace) {
+      return Err(invalid(format!(
+        r#"invalid app name "{name}": app names must not be empty or contain "@", "/", or whitespace"#
+      )));
+    }
+    // errors from here on mention the app they occur in
+    let problem = |problem: String| invalid(format!("app {name}: {problem}"));
+    let required = |key: &str| match optional_string(Some(entry), key, &problem) {
+      Ok(Some(value)) => Ok(value),
+      Ok(None) => Err(problem(format!(r#"missing the "{key}" field"#))),
+      Err(err) => Err(err),
+    };
+    let github = required("github")?;
+    let Some((org, repo)) = github.split_once('/') else {
+      return Err(problem(format!(r#""github" must have the format "org/repo", found "{github}""#)));
+    };
+    let version_regex = optional_string(Some(entry), "version_regex", &problem)?;
+    if let Some(version_regex) = version_regex
+      && let Err(err) = Regex::new(version_regex)
+    {
+      return Err(problem(format!("invalid version_regex: {err}")));
+    }
+    let version_args = match entry.get("version_args") {
+      Some(value) => {
+        let args = value
+          .as_array()
+          .and_then(|args| args.iter().map(|arg| arg.as_str().map(String::from)).collect::<Option<Vec<_>>>());
+        let Some(args) = args else {
+          return Err(problem(r#""version_args" must be a list of strings"#.into()));
+        };
+        args
+      }
+      None => vec![String::from("--version")],
+    };
+    let homepage = optional_string(Some(entry), "homepage", &problem)?.map_or_else(|| format!("https://github.com/{org}/{repo}"), String::from);
+    result.push(CustomApp {
+      name: name.to_string(),
+      homepage,
+      org: org.to_string(),
+      repo: repo.to_string(),
+      tag_prefix: optional_string(Some(entry), "tag_prefix", &problem)?.unwrap_or("").to_string(),
+      url: required("url")?.to_string(),
+      checksum_url: optional_string(Some(entry), "checksum_url", &problem)?.map(String::from),
+      bin_folder: optional_string(Some(entry), "bin_folder", &problem)?.map(String::from),
+      executable: optional_string(Some(entry), "executable", &problem)?.map(String::from),
+      version_args,
+      version_regex: version_regex.map(String::from),
+      os: OsValues::parse(entry.get("os"), ["linux", "darwin", "windows"], &problem)?,
+      cpu: CpuValues::parse(entry.get("cpu"), &problem)?,
+      ext: OsValues::parse(entry.get("ext"), ["tar.gz", "tar.gz", "zip"], &problem)?,
+    });
+  }
+  Ok(result)
+}
+
+/// provides the string value of the given key in the given JSON object
+fn optional_string<'a>(object: Option<&'a Value>, key: &str, problem: &dyn Fn(String) -> UserError) -> Result<Option<&'a str>> {
+  let Some(value) = object.and_then(|object| object.get(key)) else {
+    return Ok(None);
+  };
+  match value.as_str() {
+    Some(text) => Ok(Some(text)),
+    None => Err(problem(format!(r#""{key}" must be a string"#))),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse {
+    use super::super::parse;
+    use crate::applications::AppDefinition;
+    use crate::configuration::Version;
+    use crate::error::UserError;
+    use crate::executables::RunMethod;
+    use crate::installation::{BinFolder, Checksum, Method};
+    use crate::platform::{Cpu, Os, Platform};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn archive() {
+      let give = r#"
+        [
+          {
+            "name": "mytool",
+            "github": "acme/mytool",
+            "tag_prefix": "v",
+            "url": "https://github.com/acme/mytool/releases/download/v{version}/mytool_{version}_{os}_{cpu}.{ext}",
+            "checksum_url": "https://github.com/acme/mytool/releases/download/v{version}/checksums.txt",
+            "bin_folder": "mytool-{version}",
+            "version_regex": "mytool (\\d+\\.\\d+\\.\\d+)",
+            "os": { "macos": "macOS" }
+          }
+        ]"#;
+      let apps = parse(give, Path::new("run-that-app.apps.json")).unwrap();
+      assert_eq!(apps.len(), 1);
+      let app = &apps[0];
+      assert_eq!(app.name(), "mytool");
+      assert_eq!(app.homepage(), "https://github.com/acme/mytool");
+      assert_eq!(app.tag_format().format_version(&Version::from("1.2.3")), "v1.2.3");
+      let platform = Platform {
+        os: Os::MacOS,
+        cpu: Cpu::Arm64,
+      };
+      let have = app.run_method(&Version::from("1.2.3"), platform);
+      let want = RunMethod::ThisApp {
+        install_methods: vec![Method::DownloadArchive {
+          url: "https://github.com/acme/mytool/releases/download/v1.2.3/mytool_1.2.3_macOS_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/acme/mytool/releases/download/v1.2.3/checksums.txt".into(),
+          },
+          bin_folder: BinFolder::Subfolder {
+            path: PathBuf::from("mytool-1.2.3"),
+          },
+        }],
+      };
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn executable() {
+      let give = r#"[{ "name": "mytool", "github": "acme/mytool", "url": "https://acme.com/mytool-{version}-{os}-{cpu}" }]"#;
+      let apps = parse(give, Path::new("run-that-app.apps.json")).unwrap();
+      let platform = Platform {
+        os: Os::Linux,
+        cpu: Cpu::Intel64,
+      };
+      let have = apps[0].run_method(&Version::from("1.2.3"), platform);
+      let want = RunMethod::ThisApp {
+        install_methods: vec![Method::DownloadExecutable {
+          url: "https://acme.com/mytool-1.2.3-linux-amd64".into(),
+          checksum: Checksum::None,
+        }],
+      };
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn missing_url() {
+      let give = r#"[{ "name": "mytool", "github": "acme/mytool" }]"#;
+      let have = parse(give, Path::new("run-that-app.apps.json"));
+      let want = Err(UserError::InvalidAppsFile {
+        path: PathBuf::from("run-that-app.apps.json"),
+        problem: r#"app mytool: missing the "url" field"#.into(),
+      });
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn invalid_github() {
+      let give = r#"[{ "name": "mytool", "github": "mytool", "url": "https://acme.com/mytool" }]"#;
+      let have = parse(give, Path::new("run-that-app.apps.json"));
+      let want = Err(UserError::InvalidAppsFile {
+        path: PathBuf::from("run-that-app.apps.json"),
+        problem: r#"app mytool: "github" must have the format "org/repo", found "mytool""#.into(),
+      });
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn invalid_name() {
+      let give = r#"[{ "name": "my tool", "github": "acme/mytool", "url": "https://acme.com/mytool" }]"#;
+      let have = parse(give, Path::new("run-that-app.apps.json"));
+      let want = Err(UserError::InvalidAppsFile {
+        path: PathBuf::from("run-that-app.apps.json"),
+        problem: r#"invalid app name "my tool": app names must not be empty or contain "@", "/", or whitespace"#.into(),
+      });
+      pretty::assert_eq!(have, want);
+      assert!(parse(r#"[{ "name": "", "github": "a/b", "url": "u" }]"#, Path::new("x")).is_err());
+      assert!(parse(r#"[{ "name": "acme/tool", "github": "a/b", "url": "u" }]"#, Path::new("x")).is_err());
+      assert!(parse(r#"[{ "name": "tool@1", "github": "a/b", "url": "u" }]"#, Path::new("x")).is_err());
+    }
+
+    #[test]
+    fn not_a_list() {
+      let give = r#"{ "name": "mytool" }"#;
+      let have = parse(give, Path::new("run-that-app.apps.json"));
+      let want = Err(UserError::InvalidAppsFile {
+        path: PathBuf::from("run-that-app.apps.json"),
+        problem: "the content must be a list of app definitions".into(),
+      });
+      pretty::assert_eq!(have, want);
+    }
+  }
+}
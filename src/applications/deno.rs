@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for Deno {
     "https://deno.com"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -36,6 +40,9 @@ impl AppDefinition for Deno {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/deno-{cpu}-{os}.zip").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/deno-{cpu}-{os}.zip.sha256sum").into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     }
@@ -72,7 +79,7 @@ mod tests {
   use crate::applications::AppDefinition;
   use crate::configuration::Version;
   use crate::executables::RunMethod;
-  use crate::installation::{BinFolder, Method};
+  use crate::installation::{BinFolder, Checksum, Method};
   use crate::platform::{Cpu, Os, Platform};
 
   #[test]
@@ -87,6 +94,9 @@ mod tests {
     let want = RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-aarch64-unknown-linux-gnu.zip".into(),
+        checksum: Checksum::File {
+          url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-aarch64-unknown-linux-gnu.zip.sha256sum".into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     };
@@ -105,6 +115,9 @@ mod tests {
     let want = RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-x86_64-unknown-linux-gnu.zip".into(),
+        checksum: Checksum::File {
+          url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-x86_64-unknown-linux-gnu.zip.sha256sum".into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     };
@@ -123,6 +136,9 @@ mod tests {
     let want = RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-aarch64-apple-darwin.zip".into(),
+        checksum: Checksum::File {
+          url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-aarch64-apple-darwin.zip.sha256sum".into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     };
@@ -141,6 +157,9 @@ mod tests {
     let want = RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-x86_64-apple-darwin.zip".into(),
+        checksum: Checksum::File {
+          url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-x86_64-apple-darwin.zip.sha256sum".into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     };
@@ -159,6 +178,9 @@ mod tests {
     let want = RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-aarch64-pc-windows-msvc.zip".into(),
+        checksum: Checksum::File {
+          url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-aarch64-pc-windows-msvc.zip.sha256sum".into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     };
@@ -177,6 +199,9 @@ mod tests {
     let want = RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-x86_64-pc-windows-msvc.zip".into(),
+        checksum: Checksum::File {
+          url: "https://github.com/denoland/deno/releases/download/v2.9.0/deno-x86_64-pc-windows-msvc.zip.sha256sum".into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     };
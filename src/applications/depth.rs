@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::Method;
+use crate::installation::{Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Depth {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64", // the "arm" binaries don't run on Apple Silicon
@@ -42,6 +46,7 @@ impl AppDefinition for Depth {
       install_methods: vec![
         Method::DownloadExecutable {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/depth_{version}_{os}_{cpu}{ext}").into(),
+          checksum: Checksum::None,
         },
         Method::CompileGoSource {
           import_path: format!("github.com/{ORG}/{REPO}/cmd/depth@{tag}"),
@@ -80,7 +85,7 @@ mod tests {
     use crate::applications::AppDefinition;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::Method;
+    use crate::installation::{Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -97,6 +102,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/KyleBanks/depth/releases/download/v1.2.1/depth_1.2.1_linux_aarch64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/KyleBanks/depth/cmd/depth@v1.2.1"),
@@ -119,6 +125,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/KyleBanks/depth/releases/download/v1.2.1/depth_1.2.1_linux_amd64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/KyleBanks/depth/cmd/depth@v1.2.1"),
@@ -141,6 +148,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/KyleBanks/depth/releases/download/v1.2.1/depth_1.2.1_darwin_aarch64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/KyleBanks/depth/cmd/depth@v1.2.1"),
@@ -163,6 +171,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/KyleBanks/depth/releases/download/v1.2.1/depth_1.2.1_darwin_amd64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/KyleBanks/depth/cmd/depth@v1.2.1"),
@@ -185,6 +194,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/KyleBanks/depth/releases/download/v1.2.1/depth_1.2.1_windows_aarch64.exe".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/KyleBanks/depth/cmd/depth@v1.2.1"),
@@ -207,6 +217,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/KyleBanks/depth/releases/download/v1.2.1/depth_1.2.1_windows_amd64.exe".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/KyleBanks/depth/cmd/depth@v1.2.1"),
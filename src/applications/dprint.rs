@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for Dprint {
     "https://dprint.dev"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -37,10 +41,11 @@ impl AppDefinition for Dprint {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/dprint-{cpu}-{os}.zip").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileRustCrate {
-          name: "dprint",
+          name: "dprint".into(),
           bin_folder: BinFolder::Subfolder { path: "bin".into() },
         },
       ],
@@ -79,7 +84,7 @@ mod tests {
     use crate::applications::AppDefinition;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -95,10 +100,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dprint/dprint/releases/download/0.48.0/dprint-aarch64-unknown-linux-gnu.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "dprint",
+            name: "dprint".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -119,10 +125,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dprint/dprint/releases/download/0.48.0/dprint-x86_64-unknown-linux-gnu.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "dprint",
+            name: "dprint".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -143,10 +150,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dprint/dprint/releases/download/0.48.0/dprint-aarch64-apple-darwin.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "dprint",
+            name: "dprint".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -167,10 +175,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dprint/dprint/releases/download/0.48.0/dprint-x86_64-apple-darwin.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "dprint",
+            name: "dprint".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -191,10 +200,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dprint/dprint/releases/download/0.48.0/dprint-aarch64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "dprint",
+            name: "dprint".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -215,10 +225,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dprint/dprint/releases/download/0.48.0/dprint-x86_64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "dprint",
+            name: "dprint".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -23,6 +23,10 @@ impl AppDefinition for Exhaustruct {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn latest_installable_version(&self, log: Log) -> Result<Version> {
     github_releases::latest(ORG, REPO, &self.tag_format(), log)
   }
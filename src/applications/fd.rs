@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Fd {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -42,12 +46,13 @@ impl AppDefinition for Fd {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/fd-{tag}-{cpu}-{os}.{ext}").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: format!("fd-{tag}-{cpu}-{os}").into(),
           },
         },
         Method::CompileRustCrate {
-          name: "fd-find",
+          name: "fd-find".into(),
           bin_folder: BinFolder::Root,
         },
       ],
@@ -86,7 +91,7 @@ mod tests {
     use crate::applications::AppDefinition;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -102,12 +107,13 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/sharkdp/fd/releases/download/v10.3.0/fd-v10.3.0-aarch64-unknown-linux-gnu.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Subfolder {
               path: "fd-v10.3.0-aarch64-unknown-linux-gnu".into(),
             },
           },
           Method::CompileRustCrate {
-            name: "fd-find",
+            name: "fd-find".into(),
             bin_folder: BinFolder::Root,
           },
         ],
@@ -128,12 +134,13 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/sharkdp/fd/releases/download/v10.3.0/fd-v10.3.0-x86_64-unknown-linux-gnu.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Subfolder {
               path: "fd-v10.3.0-x86_64-unknown-linux-gnu".into(),
             },
           },
           Method::CompileRustCrate {
-            name: "fd-find",
+            name: "fd-find".into(),
             bin_folder: BinFolder::Root,
           },
         ],
@@ -154,12 +161,13 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/sharkdp/fd/releases/download/v10.3.0/fd-v10.3.0-aarch64-apple-darwin.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Subfolder {
               path: "fd-v10.3.0-aarch64-apple-darwin".into(),
             },
           },
           Method::CompileRustCrate {
-            name: "fd-find",
+            name: "fd-find".into(),
             bin_folder: BinFolder::Root,
           },
         ],
@@ -180,12 +188,13 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/sharkdp/fd/releases/download/v10.3.0/fd-v10.3.0-x86_64-apple-darwin.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Subfolder {
               path: "fd-v10.3.0-x86_64-apple-darwin".into(),
             },
           },
           Method::CompileRustCrate {
-            name: "fd-find",
+            name: "fd-find".into(),
             bin_folder: BinFolder::Root,
           },
         ],
@@ -206,12 +215,13 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/sharkdp/fd/releases/download/v10.3.0/fd-v10.3.0-aarch64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Subfolder {
               path: "fd-v10.3.0-aarch64-pc-windows-msvc".into(),
             },
           },
           Method::CompileRustCrate {
-            name: "fd-find",
+            name: "fd-find".into(),
             bin_folder: BinFolder::Root,
           },
         ],
@@ -232,12 +242,13 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/sharkdp/fd/releases/download/v10.3.0/fd-v10.3.0-x86_64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Subfolder {
               path: "fd-v10.3.0-x86_64-pc-windows-msvc".into(),
             },
           },
           Method::CompileRustCrate {
-            name: "fd-find",
+            name: "fd-find".into(),
             bin_folder: BinFolder::Root,
           },
         ],
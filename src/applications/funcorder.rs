@@ -23,6 +23,10 @@ impl AppDefinition for FuncOrder {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, _platform: Platform) -> RunMethod {
     let tag = self.tag_format().format_version(version);
     RunMethod::ThisApp {
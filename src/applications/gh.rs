@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use std::path::MAIN_SEPARATOR;
@@ -23,6 +23,10 @@ impl AppDefinition for Gh {
     "https://cli.github.com"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -41,6 +45,9 @@ impl AppDefinition for Gh {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/gh_{version}_{os}_{cpu}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/gh_{version}_checksums.txt").into(),
+        },
         bin_folder: BinFolder::Subfolders {
           options: vec!["bin".into(), format!("gh_{version}_{os}_{cpu}{MAIN_SEPARATOR}bin").into()],
         },
@@ -81,7 +88,7 @@ mod tests {
     use crate::applications::gh::Gh;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -97,6 +104,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_linux_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolders {
             options: vec!["bin".into(), "gh_2.39.1_linux_arm64/bin".into()],
           },
@@ -118,6 +128,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_linux_amd64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolders {
             options: vec!["bin".into(), "gh_2.39.1_linux_amd64/bin".into()],
           },
@@ -139,6 +152,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_macOS_arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolders {
             options: vec!["bin".into(), "gh_2.39.1_macOS_arm64/bin".into()],
           },
@@ -160,6 +176,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_macOS_amd64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolders {
             options: vec!["bin".into(), "gh_2.39.1_macOS_amd64/bin".into()],
           },
@@ -181,6 +200,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_windows_arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolders {
             options: vec!["bin".into(), r"gh_2.39.1_windows_arm64\bin".into()],
           },
@@ -202,6 +224,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_windows_amd64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolders {
             options: vec!["bin".into(), r"gh_2.39.1_windows_amd64\bin".into()],
           },
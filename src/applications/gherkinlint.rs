@@ -22,10 +22,14 @@ impl AppDefinition for GherkinLint {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, _version: &Version, _platform: Platform) -> RunMethod {
     RunMethod::NodeJS {
-      package: "gherkin-lint",
-      script: "gherkin-lint",
+      package: "gherkin-lint".into(),
+      script: "gherkin-lint".into(),
     }
   }
   fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
@@ -69,8 +73,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "gherkin-lint",
-        script: "gherkin-lint",
+        package: "gherkin-lint".into(),
+        script: "gherkin-lint".into(),
       };
       assert_eq!(have, want);
     }
@@ -85,8 +89,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "gherkin-lint",
-        script: "gherkin-lint",
+        package: "gherkin-lint".into(),
+        script: "gherkin-lint".into(),
       };
       assert_eq!(have, want);
     }
@@ -101,8 +105,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "gherkin-lint",
-        script: "gherkin-lint",
+        package: "gherkin-lint".into(),
+        script: "gherkin-lint".into(),
       };
       assert_eq!(have, want);
     }
@@ -117,8 +121,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "gherkin-lint",
-        script: "gherkin-lint",
+        package: "gherkin-lint".into(),
+        script: "gherkin-lint".into(),
       };
       assert_eq!(have, want);
     }
@@ -133,8 +137,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "gherkin-lint",
-        script: "gherkin-lint",
+        package: "gherkin-lint".into(),
+        script: "gherkin-lint".into(),
       };
       assert_eq!(have, want);
     }
@@ -149,8 +153,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "gherkin-lint",
-        script: "gherkin-lint",
+        package: "gherkin-lint".into(),
+        script: "gherkin-lint".into(),
       };
       assert_eq!(have, want);
     }
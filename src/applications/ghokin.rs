@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Ghokin {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm64",
@@ -38,6 +42,7 @@ impl AppDefinition for Ghokin {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ghokin_{version}_{os}_{cpu}.tar.gz").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileGoSource {
@@ -76,7 +81,7 @@ mod tests {
     use crate::applications::ghokin::Ghokin;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -93,6 +98,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/antham/ghokin/releases/download/v3.4.1/ghokin_3.4.1_linux_arm64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -116,6 +122,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/antham/ghokin/releases/download/v3.4.1/ghokin_3.4.1_linux_amd64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -139,6 +146,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/antham/ghokin/releases/download/v3.4.1/ghokin_3.4.1_darwin_arm64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -162,6 +170,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/antham/ghokin/releases/download/v3.4.1/ghokin_3.4.1_darwin_amd64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -185,6 +194,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/antham/ghokin/releases/download/v3.4.1/ghokin_3.4.1_windows_arm64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -208,6 +218,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/antham/ghokin/releases/download/v3.4.1/ghokin_3.4.1_windows_amd64.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
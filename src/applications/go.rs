@@ -1,12 +1,14 @@
 use super::{AnalyzeResult, AppDefinition, ApplicationName};
-use crate::configuration::{TagFormat, Version};
+use crate::configuration::{TagFormat, Version, VersionRange};
 use crate::error::{Result, UserError};
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_tags;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
+use crate::yard::VersionsCache;
 use crate::{Log, filesystem, strings, subshell};
 use std::path::MAIN_SEPARATOR;
+use std::{env, fs};
 
 #[derive(Clone)]
 pub struct Go {}
@@ -23,6 +25,10 @@ impl AppDefinition for Go {
     "https://go.dev"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -41,6 +47,9 @@ impl AppDefinition for Go {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://go.dev/dl/{tag}.{os}-{cpu}.{ext}").into(),
+        checksum: Checksum::Listing {
+          url: "https://go.dev/dl/?mode=json&include=all".into(),
+        },
         bin_folder: BinFolder::Subfolder {
           path: format!("go{MAIN_SEPARATOR}bin").into(),
         },
@@ -84,27 +93,108 @@ impl AppDefinition for Go {
     }
   }
 
-  fn allowed_versions(&self) -> Result<semver::VersionReq> {
-    let Some(go_mod_content) = filesystem::read_file("go.mod")? else {
-      return Ok(semver::VersionReq::STAR);
+  fn allowed_versions(&self, _cache: &VersionsCache, _log: Log) -> Result<VersionRange> {
+    let expression = match project_toolchain()? {
+      Toolchain::Local => return Ok(VersionRange::any()),
+      Toolchain::Exact(version) => format!("={version}"),
+      Toolchain::Minimum(version) => format!(">={version}"),
     };
-    let Ok(go_version_req) = parse_go_mod(&go_mod_content) else {
-      return Ok(semver::VersionReq::STAR);
-    };
-    let version_req = semver::VersionReq::parse(go_version_req).map_err(|err| UserError::CannotParseSemverRange {
-      expression: go_version_req.to_string(),
-      reason: err.to_string(),
-    })?;
-    Ok(version_req)
+    VersionRange::parse(&expression)
+  }
+
+  fn pinned_version(&self) -> Result<Option<Version>> {
+    match project_toolchain()? {
+      Toolchain::Local => Ok(None),
+      Toolchain::Exact(version) | Toolchain::Minimum(version) => Ok(Some(version.into())),
+    }
   }
 
   fn tag_format(&self) -> TagFormat {
-    TagFormat::Prefix("go")
+    TagFormat::Prefix("go".into())
+  }
+}
+
+/// the Go toolchain that the current project uses, determined the same way the `go` command does it
+#[derive(Debug, PartialEq)]
+enum Toolchain {
+  /// the project uses whatever Go installation is available
+  Local,
+  /// the project uses exactly this Go version
+  Exact(String),
+  /// the project uses the available Go installation if it has at least this version, otherwise exactly this version
+  Minimum(String),
+}
+
+fn project_toolchain() -> Result<Toolchain> {
+  let gotoolchain = env::var("GOTOOLCHAIN").unwrap_or_default();
+  let declared = match read_go_work()? {
+    Some(go_work) => declared_version(&go_work),
+    None => filesystem::read_file("go.mod")?.and_then(|go_mod| declared_version(&go_mod)),
+  };
+  Ok(select_toolchain(&gotoolchain, declared))
+}
+
+/// provides the content of the workspace file that the `go` command would use
+fn read_go_work() -> Result<Option<String>> {
+  match env::var("GOWORK").unwrap_or_default().as_str() {
+    "off" => Ok(None),
+    "" => filesystem::read_file("go.work"),
+    path => Ok(fs::read_to_string(path).ok()),
+  }
+}
+
+/// provides the minimum Go toolchain that the given go.mod or go.work file requires
+fn declared_version(text: &str) -> Option<String> {
+  let go = parse_go_mod(text).ok().map(release_version);
+  let toolchain = parse_toolchain(text).ok().map(ToString::to_string);
+  match (go, toolchain) {
+    (Some(go), Some(toolchain)) => Some(newer(go, toolchain)),
+    (go, toolchain) => go.or(toolchain),
   }
 }
 
 fn parse_go_mod(text: &str) -> Result<&str> {
-  strings::first_capture(text, r"(?m)^go\s+(\d+\.\d+)\s*$")
+  strings::first_capture(text, r"(?m)^go\s+(\d+\.\d+(?:\.\d+)?)\s*(?://.*)?$")
+}
+
+fn parse_toolchain(text: &str) -> Result<&str> {
+  strings::first_capture(text, r"(?m)^toolchain\s+go(\d+\.\d+(?:\.\d+)?)\s*(?://.*)?$")
+}
+
+/// Starting with Go 1.21, the first release of a language version has a ".0" patch version.
+/// The `go` command switches to that release for go lines that only name the language version.
+fn release_version(language_version: &str) -> String {
+  let parts: Vec<&str> = language_version.split('.').collect();
+  match parts.as_slice() {
+    [major, minor] if *major == "1" && minor.parse::<u32>().is_ok_and(|minor| minor >= 21) => format!("{language_version}.0"),
+    _ => language_version.to_string(),
+  }
+}
+
+/// determines the Go toolchain to use for the given value of the GOTOOLCHAIN environment variable
+/// and the minimum version declared in the project files
+fn select_toolchain(gotoolchain: &str, declared: Option<String>) -> Toolchain {
+  let (name, switch) = match gotoolchain.split_once('+') {
+    Some((name, mode)) => (name, mode == "auto" || mode == "path"),
+    None if gotoolchain.is_empty() || gotoolchain == "auto" || gotoolchain == "path" => ("local", true),
+    None => (gotoolchain, false),
+  };
+  let default = name.strip_prefix("go").map(ToString::to_string);
+  if !switch {
+    return default.map_or(Toolchain::Local, Toolchain::Exact);
+  }
+  match (default, declared) {
+    (Some(default), Some(declared)) => Toolchain::Exact(newer(default, declared)),
+    (Some(default), None) => Toolchain::Exact(default),
+    (None, Some(declared)) => Toolchain::Minimum(declared),
+    (None, None) => Toolchain::Local,
+  }
+}
+
+/// provides the newer of the given Go versions
+fn newer(a: String, b: String) -> String {
+  let numbers = |version: &str| -> Vec<u32> { version.split('.').map(|part| part.parse().unwrap_or_default()).collect() };
+  if numbers(&b) > numbers(&a) { b } else { a }
 }
 
 #[cfg(test)]
@@ -115,7 +205,7 @@ mod tests {
     use crate::applications::go::Go;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -131,6 +221,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://go.dev/dl/go1.21.5.linux-arm64.tar.gz".into(),
+          checksum: Checksum::Listing {
+            url: "https://go.dev/dl/?mode=json&include=all".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "go/bin".into() },
         }],
       };
@@ -150,6 +243,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://go.dev/dl/go1.21.5.linux-amd64.tar.gz".into(),
+          checksum: Checksum::Listing {
+            url: "https://go.dev/dl/?mode=json&include=all".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "go/bin".into() },
         }],
       };
@@ -169,6 +265,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://go.dev/dl/go1.21.5.darwin-arm64.tar.gz".into(),
+          checksum: Checksum::Listing {
+            url: "https://go.dev/dl/?mode=json&include=all".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "go/bin".into() },
         }],
       };
@@ -188,6 +287,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://go.dev/dl/go1.21.5.darwin-amd64.tar.gz".into(),
+          checksum: Checksum::Listing {
+            url: "https://go.dev/dl/?mode=json&include=all".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "go/bin".into() },
         }],
       };
@@ -207,6 +309,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://go.dev/dl/go1.21.5.windows-arm64.zip".into(),
+          checksum: Checksum::Listing {
+            url: "https://go.dev/dl/?mode=json&include=all".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: r"go\bin".into() },
         }],
       };
@@ -226,6 +331,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://go.dev/dl/go1.21.5.windows-amd64.zip".into(),
+          checksum: Checksum::Listing {
+            url: "https://go.dev/dl/?mode=json&include=all".into(),
+          },
           bin_folder: BinFolder::Subfolder { path: r"go\bin".into() },
         }],
       };
@@ -275,4 +383,84 @@ require (
       assert_eq!(parse_go_mod(go_mod), Err(UserError::RegexDoesntMatch));
     }
   }
+
+  mod parse_toolchain {
+    use crate::applications::go::parse_toolchain;
+
+    #[test]
+    fn with_toolchain() {
+      let go_mod = "module example.com/app\n\ngo 1.22\n\ntoolchain go1.22.3\n";
+      assert_eq!(parse_toolchain(go_mod), Ok("1.22.3"));
+    }
+
+    #[test]
+    fn default_toolchain() {
+      let go_mod = "module example.com/app\n\ngo 1.22\n\ntoolchain default\n";
+      assert!(parse_toolchain(go_mod).is_err());
+    }
+  }
+
+  mod declared_version {
+    use crate::applications::go::declared_version;
+
+    #[test]
+    fn go_line_with_patch() {
+      assert_eq!(declared_version("go 1.22.3\n"), Some("1.22.3".into()));
+    }
+
+    #[test]
+    fn language_version() {
+      assert_eq!(declared_version("go 1.22\n"), Some("1.22.0".into()));
+      assert_eq!(declared_version("go 1.20\n"), Some("1.20".into()));
+    }
+
+    #[test]
+    fn newer_toolchain() {
+      assert_eq!(declared_version("go 1.21\ntoolchain go1.22.3\n"), Some("1.22.3".into()));
+    }
+
+    #[test]
+    fn older_toolchain() {
+      assert_eq!(declared_version("go 1.22.5\ntoolchain go1.22.3\n"), Some("1.22.5".into()));
+    }
+
+    #[test]
+    fn go_work() {
+      let go_work = "go 1.23.1\n\nuse (\n\t./api\n\t./web\n)\n";
+      assert_eq!(declared_version(go_work), Some("1.23.1".into()));
+    }
+
+    #[test]
+    fn nothing_declared() {
+      assert_eq!(declared_version("module example.com/app\n"), None);
+    }
+  }
+
+  mod select_toolchain {
+    use crate::applications::go::{Toolchain, select_toolchain};
+
+    #[test]
+    fn auto() {
+      assert_eq!(select_toolchain("", Some("1.22.3".into())), Toolchain::Minimum("1.22.3".into()));
+      assert_eq!(select_toolchain("auto", Some("1.22.3".into())), Toolchain::Minimum("1.22.3".into()));
+      assert_eq!(select_toolchain("local+auto", None), Toolchain::Local);
+    }
+
+    #[test]
+    fn local() {
+      assert_eq!(select_toolchain("local", Some("1.22.3".into())), Toolchain::Local);
+    }
+
+    #[test]
+    fn exact() {
+      assert_eq!(select_toolchain("go1.21.4", Some("1.22.3".into())), Toolchain::Exact("1.21.4".into()));
+    }
+
+    #[test]
+    fn default_with_switching() {
+      assert_eq!(select_toolchain("go1.21.4+auto", Some("1.22.3".into())), Toolchain::Exact("1.22.3".into()));
+      assert_eq!(select_toolchain("go1.23.0+path", Some("1.22.3".into())), Toolchain::Exact("1.23.0".into()));
+      assert_eq!(select_toolchain("go1.23.0+auto", None), Toolchain::Exact("1.23.0".into()));
+    }
+  }
 }
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::Method;
+use crate::installation::{Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Gofumpt {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -42,6 +46,7 @@ impl AppDefinition for Gofumpt {
       install_methods: vec![
         Method::DownloadExecutable {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/gofumpt_v{version}_{os}_{cpu}{ext}").into(),
+          checksum: Checksum::None,
         },
         Method::CompileGoSource {
           import_path: format!("mvdan.cc/gofumpt@{tag}"),
@@ -82,7 +87,7 @@ mod tests {
     use crate::applications::gofumpt::Gofumpt;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::Method;
+    use crate::installation::{Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -99,6 +104,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/mvdan/gofumpt/releases/download/v0.5.0/gofumpt_v0.5.0_linux_arm64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("mvdan.cc/gofumpt@v0.5.0"),
@@ -121,6 +127,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/mvdan/gofumpt/releases/download/v0.5.0/gofumpt_v0.5.0_linux_amd64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("mvdan.cc/gofumpt@v0.5.0"),
@@ -143,6 +150,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/mvdan/gofumpt/releases/download/v0.5.0/gofumpt_v0.5.0_darwin_arm64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("mvdan.cc/gofumpt@v0.5.0"),
@@ -165,6 +173,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/mvdan/gofumpt/releases/download/v0.5.0/gofumpt_v0.5.0_darwin_amd64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("mvdan.cc/gofumpt@v0.5.0"),
@@ -187,6 +196,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/mvdan/gofumpt/releases/download/v0.5.0/gofumpt_v0.5.0_windows_arm64.exe".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("mvdan.cc/gofumpt@v0.5.0"),
@@ -209,6 +219,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/mvdan/gofumpt/releases/download/v0.5.0/gofumpt_v0.5.0_windows_amd64.exe".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("mvdan.cc/gofumpt@v0.5.0"),
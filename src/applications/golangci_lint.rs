@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for GolangCiLint {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -42,6 +46,9 @@ impl AppDefinition for GolangCiLint {
     // install from source not recommended, see https://golangci-lint.run/usage/install/#install-from-source
     vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/golangci-lint-{version}-{os}-{cpu}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/golangci-lint-{version}-checksums.txt").into(),
+        },
         bin_folder: BinFolder::Subfolder { path: format!("golangci-lint-{version}-{os}-{cpu}").into()},
     }]}
   }
@@ -74,7 +81,7 @@ mod tests {
     use crate::applications::golangci_lint::GolangCiLint;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -89,6 +96,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-linux-arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "golangci-lint-1.55.2-linux-arm64".into(),
           },
@@ -109,6 +119,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-linux-amd64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "golangci-lint-1.55.2-linux-amd64".into(),
           },
@@ -129,6 +142,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-darwin-arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "golangci-lint-1.55.2-darwin-arm64".into(),
           },
@@ -149,6 +165,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-darwin-amd64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "golangci-lint-1.55.2-darwin-amd64".into(),
           },
@@ -169,6 +188,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-windows-arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "golangci-lint-1.55.2-windows-arm64".into(),
           },
@@ -189,6 +211,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-windows-amd64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/golangci/golangci-lint/releases/download/v1.55.2/golangci-lint-1.55.2-checksums.txt".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "golangci-lint-1.55.2-windows-amd64".into(),
           },
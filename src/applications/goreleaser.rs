@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for Goreleaser {
     "https://goreleaser.com"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "Linux",
@@ -40,6 +44,9 @@ impl AppDefinition for Goreleaser {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/goreleaser_{os}_{cpu}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/checksums.txt").into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     }
@@ -81,7 +88,7 @@ mod tests {
     use crate::applications::goreleaser::Goreleaser;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -96,6 +103,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/goreleaser_Linux_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -114,6 +124,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/goreleaser_Linux_x86_64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -132,6 +145,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/goreleaser_Darwin_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -150,6 +166,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/goreleaser_Darwin_x86_64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -168,6 +187,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/goreleaser_Windows_arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -186,6 +208,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/goreleaser_Windows_x86_64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/goreleaser/goreleaser/releases/download/v1.22.1/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Gum {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "Linux",
@@ -42,6 +46,9 @@ impl AppDefinition for Gum {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/gum_{version}_{os}_{cpu}.{ext}").into(),
+          checksum: Checksum::File {
+            url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/checksums.txt").into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: format!("gum_{version}_{os}_{cpu}").into(),
           },
@@ -84,7 +91,7 @@ mod tests {
     use crate::applications::gum::Gum;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -100,6 +107,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Linux_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Subfolder {
               path: "gum_0.17.0_Linux_arm64".into(),
             },
@@ -125,6 +135,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Linux_x86_64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Subfolder {
               path: "gum_0.17.0_Linux_x86_64".into(),
             },
@@ -150,6 +163,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Darwin_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Subfolder {
               path: "gum_0.17.0_Darwin_arm64".into(),
             },
@@ -175,6 +191,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Darwin_x86_64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Subfolder {
               path: "gum_0.17.0_Darwin_x86_64".into(),
             },
@@ -200,6 +219,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Windows_arm64.zip".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Subfolder {
               path: "gum_0.17.0_Windows_arm64".into(),
             },
@@ -225,6 +247,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Windows_x86_64.zip".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/charmbracelet/gum/releases/download/v0.17.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Subfolder {
               path: "gum_0.17.0_Windows_x86_64".into(),
             },
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::Method;
+use crate::installation::{Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for KeepSorted {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm64",
@@ -42,6 +46,7 @@ impl AppDefinition for KeepSorted {
       install_methods: vec![
         Method::DownloadExecutable {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/keep-sorted_{os}_{cpu}{ext}").into(),
+          checksum: Checksum::None,
         },
         Method::CompileGoSource {
           import_path: format!("github.com/{ORG}/{REPO}@{tag}"),
@@ -82,7 +87,7 @@ mod tests {
     use crate::applications::keep_sorted::KeepSorted;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::Method;
+    use crate::installation::{Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -99,6 +104,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/google/keep-sorted/releases/download/v0.9.1/keep-sorted_linux_arm64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/google/keep-sorted@v0.9.1"),
@@ -121,6 +127,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/google/keep-sorted/releases/download/v0.9.1/keep-sorted_linux_amd64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/google/keep-sorted@v0.9.1"),
@@ -143,6 +150,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/google/keep-sorted/releases/download/v0.9.1/keep-sorted_darwin_arm64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/google/keep-sorted@v0.9.1"),
@@ -165,6 +173,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/google/keep-sorted/releases/download/v0.9.1/keep-sorted_darwin_amd64".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/google/keep-sorted@v0.9.1"),
@@ -187,6 +196,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/google/keep-sorted/releases/download/v0.9.1/keep-sorted_windows_amd64.exe".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/google/keep-sorted@v0.9.1"),
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, executables, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Lefthook {
     formatcp!("https://lefthook.dev")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn executable_filename(&self) -> executables::ExecutableNameUnix {
     executables::ExecutableNameUnix::from("lefthook")
   }
@@ -42,6 +46,9 @@ impl AppDefinition for Lefthook {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/lefthook_{version}_{os}_{cpu}.gz").into(),
+          checksum: Checksum::File {
+            url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/lefthook_checksums.txt").into(),
+          },
           bin_folder: BinFolder::Root,
         },
         Method::CompileGoSource {
@@ -83,7 +90,7 @@ mod tests {
     use crate::applications::lefthook::Lefthook;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -100,6 +107,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_2.1.6_Linux_arm64.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -123,6 +133,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_2.1.6_Linux_x86_64.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -146,6 +159,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_2.1.6_MacOS_arm64.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -169,6 +185,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_2.1.6_MacOS_x86_64.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -192,6 +211,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_2.1.6_Windows_arm64.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -215,6 +237,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_2.1.6_Windows_x86_64.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/evilmartians/lefthook/releases/download/v2.1.6/lefthook_checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -22,10 +22,14 @@ impl AppDefinition for MarkdownLint {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, _version: &Version, _platform: Platform) -> RunMethod {
     RunMethod::NodeJS {
-      package: "markdownlint-cli",
-      script: "markdownlint",
+      package: "markdownlint-cli".into(),
+      script: "markdownlint".into(),
     }
   }
   fn installable_versions(&self, amount: usize, log: Log) -> Result<Vec<Version>> {
@@ -71,8 +75,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "markdownlint-cli",
-        script: "markdownlint",
+        package: "markdownlint-cli".into(),
+        script: "markdownlint".into(),
       };
       assert_eq!(have, want);
     }
@@ -87,8 +91,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "markdownlint-cli",
-        script: "markdownlint",
+        package: "markdownlint-cli".into(),
+        script: "markdownlint".into(),
       };
       assert_eq!(have, want);
     }
@@ -103,8 +107,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "markdownlint-cli",
-        script: "markdownlint",
+        package: "markdownlint-cli".into(),
+        script: "markdownlint".into(),
       };
       assert_eq!(have, want);
     }
@@ -119,8 +123,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "markdownlint-cli",
-        script: "markdownlint",
+        package: "markdownlint-cli".into(),
+        script: "markdownlint".into(),
       };
       assert_eq!(have, want);
     }
@@ -135,8 +139,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "markdownlint-cli",
-        script: "markdownlint",
+        package: "markdownlint-cli".into(),
+        script: "markdownlint".into(),
       };
       assert_eq!(have, want);
     }
@@ -151,8 +155,8 @@ mod tests {
         },
       );
       let want = RunMethod::NodeJS {
-        package: "markdownlint-cli",
-        script: "markdownlint",
+        package: "markdownlint-cli".into(),
+        script: "markdownlint".into(),
       };
       assert_eq!(have, want);
     }
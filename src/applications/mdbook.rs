@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,15 +23,20 @@ impl AppDefinition for MdBook {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     RunMethod::ThisApp {
       install_methods: vec![
         Method::DownloadArchive {
           url: archive_url(version, platform, &self.tag_format()).into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileRustCrate {
-          name: "mdbook",
+          name: "mdbook".into(),
           bin_folder: BinFolder::Subfolder { path: "bin".into() },
         },
       ],
@@ -91,7 +96,7 @@ mod tests {
     use crate::applications::mdbook::MdBook;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -107,10 +112,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rust-lang/mdBook/releases/download/v0.5.2/mdbook-v0.5.2-aarch64-unknown-linux-musl.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook",
+            name: "mdbook".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -131,10 +137,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rust-lang/mdBook/releases/download/v0.5.2/mdbook-v0.5.2-x86_64-unknown-linux-gnu.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook",
+            name: "mdbook".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -155,10 +162,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rust-lang/mdBook/releases/download/v0.5.2/mdbook-v0.5.2-aarch64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook",
+            name: "mdbook".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -179,10 +187,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rust-lang/mdBook/releases/download/v0.5.2/mdbook-v0.5.2-x86_64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook",
+            name: "mdbook".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -203,10 +212,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rust-lang/mdBook/releases/download/v0.5.2/mdbook-v0.5.2-aarch64-apple-darwin.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook",
+            name: "mdbook".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -227,10 +237,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/rust-lang/mdBook/releases/download/v0.5.2/mdbook-v0.5.2-x86_64-apple-darwin.tar.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook",
+            name: "mdbook".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
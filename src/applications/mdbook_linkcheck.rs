@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for MdBookLinkCheck {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "unknown-linux-gnu",
@@ -38,10 +42,11 @@ impl AppDefinition for MdBookLinkCheck {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/mdbook-linkcheck.{cpu}-{os}.zip").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileRustCrate {
-          name: "mdbook-linkcheck",
+          name: "mdbook-linkcheck".into(),
           bin_folder: BinFolder::Subfolder { path: "bin".into() },
         },
       ],
@@ -80,7 +85,7 @@ mod tests {
     use crate::applications::mdbook_linkcheck::MdBookLinkCheck;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -96,10 +101,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/Michael-F-Bryan/mdbook-linkcheck/releases/download/v0.7.8/mdbook-linkcheck.aarch64-unknown-linux-gnu.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook-linkcheck",
+            name: "mdbook-linkcheck".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -120,10 +126,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/Michael-F-Bryan/mdbook-linkcheck/releases/download/v0.7.8/mdbook-linkcheck.x86_64-unknown-linux-gnu.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook-linkcheck",
+            name: "mdbook-linkcheck".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -144,10 +151,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/Michael-F-Bryan/mdbook-linkcheck/releases/download/v0.7.8/mdbook-linkcheck.aarch64-apple-darwin.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook-linkcheck",
+            name: "mdbook-linkcheck".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -168,10 +176,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/Michael-F-Bryan/mdbook-linkcheck/releases/download/v0.7.8/mdbook-linkcheck.x86_64-apple-darwin.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook-linkcheck",
+            name: "mdbook-linkcheck".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -192,10 +201,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/Michael-F-Bryan/mdbook-linkcheck/releases/download/v0.7.8/mdbook-linkcheck.aarch64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook-linkcheck",
+            name: "mdbook-linkcheck".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -216,10 +226,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/Michael-F-Bryan/mdbook-linkcheck/releases/download/v0.7.8/mdbook-linkcheck.x86_64-pc-windows-msvc.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "mdbook-linkcheck",
+            name: "mdbook-linkcheck".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -1,12 +1,14 @@
 //! all applications that run-this-app can run
 
 mod actionlint;
+mod adhoc;
 mod alphavet;
 mod biome;
 mod bun;
 mod conc;
 mod contest;
 mod cucumber_sort;
+mod custom;
 mod deadcode;
 mod delete_empty_folders;
 mod deno;
@@ -55,18 +57,21 @@ mod ty;
 mod uv;
 mod yamlfmt;
 
-use crate::Log;
-use crate::configuration::{TagFormat, Version};
+use crate::configuration::{self, TagFormat, Version, VersionRange};
 use crate::error::{Result, UserError};
 use crate::executables::{Executable, ExecutableNameUnix, RunMethod};
 use crate::platform::Platform;
+use crate::yard::VersionsCache;
+use crate::{Log, filesystem};
 pub use actionlint::ActionLint;
+pub use adhoc::{GitHubApp, GoPackage, NpmPackage, RustCrate};
 pub use alphavet::Alphavet;
 pub use biome::Biome;
 pub use bun::Bun;
 pub use conc::Conc;
 pub use contest::Contest;
 pub use cucumber_sort::CucumberSort;
+pub use custom::{APPS_FILE_NAME, CustomApp};
 pub use deadcode::Deadcode;
 pub use delete_empty_folders::DeleteEmptyFolders;
 pub use deno::Deno;
@@ -108,6 +113,8 @@ pub use shellcheck::ShellCheck;
 pub use shfmt::Shfmt;
 pub use snyk::Snyk;
 pub use staticcheck::StaticCheck;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 pub use taplo::Taplo;
 pub use text_runner::TextRunner;
 pub use tikibase::Tikibase;
@@ -178,10 +185,38 @@ pub fn all() -> Apps {
   ])
 }
 
+/// all built-in applications plus the applications defined in the [apps file][APPS_FILE_NAME]
+/// and the ad-hoc applications requested in the config file of the current project
+pub fn load() -> Result<Apps> {
+  load_next_to(filesystem::find_file(configuration::FILE_NAME)?)
+}
+
+/// all built-in applications plus the applications defined in the [apps file][APPS_FILE_NAME]
+/// and the ad-hoc applications requested in the config file of the project in the given folder
+pub fn load_in(folder: &Path) -> Result<Apps> {
+  load_next_to(filesystem::find_file_in(folder, configuration::FILE_NAME)?)
+}
+
+/// all built-in applications plus the custom and ad-hoc applications of the given config file
+fn load_next_to(config_path: Option<PathBuf>) -> Result<Apps> {
+  let mut apps = all();
+  let Some(config_path) = config_path else {
+    return Ok(apps);
+  };
+  for app in custom::load_next_to(&config_path)? {
+    if apps.lookup(app.name()).is_ok() {
+      return Err(UserError::DuplicateAppName { name: app.name().to_string() });
+    }
+    apps.0.push(Box::new(app));
+  }
+  apps.add_adhoc_apps(configuration::File::app_names(&config_path)?);
+  Ok(apps)
+}
+
 /// all the information about an application that run-that-app can install
 ///
 /// You get a specific application finding it in the result of [`all`].
-pub trait AppDefinition: dyn_clone::DynClone {
+pub trait AppDefinition: dyn_clone::DynClone + Send + Sync {
   /// the name by which the user can select this application at the run-that-app CLI
   fn name(&self) -> ApplicationName;
 
@@ -199,7 +234,12 @@ pub trait AppDefinition: dyn_clone::DynClone {
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod;
 
   /// link to the (human-readable) homepage of the app
-  fn homepage(&self) -> &'static str;
+  fn homepage(&self) -> &str;
+
+  /// the GitHub organization and repository that publish the releases of this app, None if it isn't released on GitHub
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    None
+  }
 
   /// provides the versions of this application that can be installed
   ///
@@ -224,8 +264,21 @@ pub trait AppDefinition: dyn_clone::DynClone {
   /// which Go version to use to compile this codebase
   /// Similar version restrictions can exist in
   /// "package.json" for `NodeJS` or "Gemfile" for Ruby.
-  fn allowed_versions(&self) -> Result<semver::VersionReq> {
-    Ok(semver::VersionReq::STAR)
+  /// Apps that need to look up information on the internet to determine these restrictions
+  /// use the given cache for that.
+  fn allowed_versions(&self, _cache: &VersionsCache, _log: Log) -> Result<VersionRange> {
+    Ok(VersionRange::any())
+  }
+
+  /// Apps can override this method to provide the exact version
+  /// that config files in the working directory select.
+  /// Run-that-app uses this version if the config file
+  /// doesn't define a version for this app,
+  /// or as a fallback for `system@auto`.
+  ///
+  /// Example: the toolchain that "go.mod" or "go.work" declare.
+  fn pinned_version(&self) -> Result<Option<Version>> {
+    Ok(None)
   }
 }
 
@@ -263,12 +316,12 @@ impl std::fmt::Debug for dyn AppDefinition {
 ///
 /// You get get it by calling the [name][AppDefinition::name] method on an [application][AppDefinition].
 #[derive(Clone, Debug, PartialEq)]
-pub struct ApplicationName(&'static str);
+pub struct ApplicationName(Cow<'static, str>);
 
 impl ApplicationName {
   #[must_use]
   pub fn as_str(&self) -> &str {
-    self.0
+    &self.0
   }
 
   #[allow(clippy::len_without_is_empty)]
@@ -280,25 +333,31 @@ impl ApplicationName {
 
 impl From<&'static str> for ApplicationName {
   fn from(value: &'static str) -> Self {
-    ApplicationName(value)
+    ApplicationName(Cow::Borrowed(value))
+  }
+}
+
+impl From<String> for ApplicationName {
+  fn from(value: String) -> Self {
+    ApplicationName(Cow::Owned(value))
   }
 }
 
 impl std::fmt::Display for ApplicationName {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.write_str(self.0)
+    f.write_str(&self.0)
   }
 }
 
 impl AsRef<std::path::Path> for ApplicationName {
   fn as_ref(&self) -> &std::path::Path {
-    std::path::Path::new(&self.0)
+    std::path::Path::new(self.0.as_ref())
   }
 }
 
 impl AsRef<str> for ApplicationName {
   fn as_ref(&self) -> &str {
-    self.0
+    &self.0
   }
 }
 
@@ -337,9 +396,26 @@ impl Apps {
         return Ok(app.as_ref());
       }
     }
+    // tell the user what's wrong with names that look like ad-hoc apps
+    if let Some(Err(err)) = adhoc::create(name.as_ref()) {
+      return Err(err);
+    }
     Err(UserError::UnknownApp(name.as_ref().to_string()))
   }
 
+  /// adds the ad-hoc apps with the given names, like "github:org/repo" or "npm:package",
+  /// ignores names that are known already or don't describe a valid ad-hoc app
+  pub fn add_adhoc_apps<AS: AsRef<str>>(&mut self, names: impl IntoIterator<Item = AS>) {
+    for name in names {
+      if self.lookup(name.as_ref()).is_ok() {
+        continue;
+      }
+      if let Some(Ok(app)) = adhoc::create(name.as_ref()) {
+        self.0.push(app);
+      }
+    }
+  }
+
   /// provides the apps with the given names
   pub fn lookup_many<AS: AsRef<str>>(&self, names: &[AS]) -> Result<Vec<&dyn AppDefinition>> {
     names.iter().map(|name| self.lookup(name)).collect()
@@ -407,6 +483,49 @@ mod tests {
         };
         assert_eq!(err, UserError::UnknownApp(S("zonk")));
       }
+
+      #[test]
+      #[allow(clippy::panic)]
+      fn invalid_adhoc_app() {
+        let apps = Apps(vec![Box::new(shellcheck::ShellCheck {})]);
+        let Err(err) = apps.lookup("github:org") else {
+          panic!("expected an error here");
+        };
+        assert_eq!(
+          err,
+          UserError::InvalidAdhocAppName {
+            name: S("github:org"),
+            format: "github:owner/repo",
+          }
+        );
+      }
+    }
+
+    mod add_adhoc_apps {
+      use crate::applications::{Apps, shellcheck};
+
+      #[test]
+      fn adds_adhoc_apps() {
+        let mut apps = Apps(vec![Box::new(shellcheck::ShellCheck {})]);
+        apps.add_adhoc_apps(["github:org/tool", "npm:cowsay"]);
+        assert_eq!(apps.lookup("github:org/tool").unwrap().name(), "github:org/tool");
+        assert_eq!(apps.lookup("npm:cowsay").unwrap().name(), "npm:cowsay");
+      }
+
+      #[test]
+      fn adds_each_app_once() {
+        let mut apps = Apps(vec![Box::new(shellcheck::ShellCheck {})]);
+        apps.add_adhoc_apps(["github:org/tool", "github:org/tool"]);
+        apps.add_adhoc_apps(["github:org/tool"]);
+        assert_eq!(apps.iter().count(), 2);
+      }
+
+      #[test]
+      fn ignores_other_names() {
+        let mut apps = Apps(vec![Box::new(shellcheck::ShellCheck {})]);
+        apps.add_adhoc_apps(["shellcheck", "zonk", "github:org", "--verbose"]);
+        assert_eq!(apps.iter().count(), 1);
+      }
     }
   }
 }
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::{Result, UserError};
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_tags;
-use crate::installation::Method;
+use crate::installation::{Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for NodePrune {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn latest_installable_version(&self, log: Log) -> Result<Version> {
     let tags = github_tags::all(ORG, REPO, 1, &self.tag_format(), log)?;
     let Some(tag) = tags.into_iter().nth(0) else {
@@ -46,6 +50,7 @@ impl AppDefinition for NodePrune {
       install_methods: vec![
         Method::DownloadExecutable {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/node-prune_{version}_{os}_{cpu}.tar.gz").into(),
+          checksum: Checksum::None,
         },
         Method::CompileGoSource {
           import_path: format!("github.com/tj/node-prune@{tag}"),
@@ -79,7 +84,7 @@ mod tests {
     use crate::applications::node_prune::NodePrune;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::Method;
+    use crate::installation::{Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -96,6 +101,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/tj/node-prune/releases/download/v1.0.1/node-prune_1.0.1_linux_arm64.tar.gz".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/tj/node-prune@v1.0.1"),
@@ -118,6 +124,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/tj/node-prune/releases/download/v1.0.1/node-prune_1.0.1_linux_amd64.tar.gz".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/tj/node-prune@v1.0.1"),
@@ -140,6 +147,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/tj/node-prune/releases/download/v1.0.1/node-prune_1.0.1_darwin_arm64.tar.gz".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/tj/node-prune@v1.0.1"),
@@ -162,6 +170,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/tj/node-prune/releases/download/v1.0.1/node-prune_1.0.1_darwin_amd64.tar.gz".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/tj/node-prune@v1.0.1"),
@@ -184,6 +193,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/tj/node-prune/releases/download/v1.0.1/node-prune_1.0.1_windows_arm64.tar.gz".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/tj/node-prune@v1.0.1"),
@@ -206,6 +216,7 @@ mod tests {
         install_methods: vec![
           Method::DownloadExecutable {
             url: "https://github.com/tj/node-prune/releases/download/v1.0.1/node-prune_1.0.1_windows_amd64.tar.gz".into(),
+            checksum: Checksum::None,
           },
           Method::CompileGoSource {
             import_path: S("github.com/tj/node-prune@v1.0.1"),
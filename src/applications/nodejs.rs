@@ -1,11 +1,13 @@
 use super::{AnalyzeResult, AppDefinition, ApplicationName};
-use crate::configuration::{TagFormat, Version};
-use crate::error::Result;
+use crate::configuration::{TagFormat, Version, VersionRange};
+use crate::error::{Result, UserError};
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::hosting::nodejs_dist::LtsLine;
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
-use crate::{Log, strings, subshell};
+use crate::yard::VersionsCache;
+use crate::{Log, filesystem, strings, subshell};
 use std::path::MAIN_SEPARATOR;
 
 #[derive(Clone)]
@@ -23,6 +25,10 @@ impl AppDefinition for NodeJS {
     "https://nodejs.org"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = os_text(platform.os);
     let cpu = cpu_text(platform.cpu);
@@ -31,6 +37,9 @@ impl AppDefinition for NodeJS {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://nodejs.org/dist/v{version}/node-{tag}-{os}-{cpu}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://nodejs.org/dist/v{version}/SHASUMS256.txt").into(),
+        },
         bin_folder: BinFolder::RootOrSubfolders {
           options: vec![
             format!("node-v{version}-{os}-{cpu}").into(),
@@ -60,11 +69,119 @@ impl AppDefinition for NodeJS {
     }
   }
 
+  fn allowed_versions(&self, cache: &VersionsCache, log: Log) -> Result<VersionRange> {
+    // the LTS release lines change over time, look them up only if a version file uses them
+    let lts_lines = || cache.nodejs_lts_lines(log);
+    for version_file in [".nvmrc", ".node-version"] {
+      if let Some(text) = filesystem::read_file(version_file)?
+        && let Some(range) = parse_version_file(&text, &lts_lines)?
+      {
+        return Ok(range);
+      }
+    }
+    if let Some(text) = filesystem::read_file("package.json")?
+      && let Some(engines) = parse_package_json(&text)
+    {
+      return parse_npm_range(&engines);
+    }
+    Ok(VersionRange::any())
+  }
+
   fn tag_format(&self) -> TagFormat {
     TagFormat::PrefixV
   }
 }
 
+/// parses the Node.js version in the given content of an .nvmrc or .node-version file
+fn parse_version_file(text: &str, lts_lines: &dyn Fn() -> Result<Vec<LtsLine>>) -> Result<Option<VersionRange>> {
+  let Some(version) = text.lines().map(str::trim).find(|line| !line.is_empty() && !line.starts_with('#')) else {
+    return Ok(None);
+  };
+  parse_version_alias(version, lts_lines).map(Some)
+}
+
+/// parses the given Node.js version as understood by nvm,
+/// i.e. a full or partial version like "20", "v20.10", or "20.10.0", or an alias like "lts/*" or "lts/iron"
+fn parse_version_alias(version: &str, lts_lines: &dyn Fn() -> Result<Vec<LtsLine>>) -> Result<VersionRange> {
+  let version = version.to_ascii_lowercase();
+  if matches!(version.as_str(), "*" | "current" | "latest" | "node" | "stable" | "system") {
+    return Ok(VersionRange::any());
+  }
+  if let Some(lts) = version.strip_prefix("lts/") {
+    let Some(major) = lts_major(lts, &lts_lines()?) else {
+      return Err(UserError::CannotParseSemverRange {
+        expression: version.clone(),
+        reason: String::from("unknown LTS release line"),
+      });
+    };
+    return parse_semver_req(&format!("~{major}")).map(VersionRange::from);
+  }
+  let version = version.strip_prefix('v').unwrap_or(&version);
+  if version.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) {
+    // partial versions like "20" or "20.10" allow all versions of that release line
+    let operator = if version.matches('.').count() >= 2 { "=" } else { "~" };
+    return parse_semver_req(&format!("{operator}{version}")).map(VersionRange::from);
+  }
+  VersionRange::parse(version)
+}
+
+/// provides the major version of the given LTS release line, given as "*", "-1" for the one before the newest, or its codename
+fn lts_major(lts: &str, lines: &[LtsLine]) -> Option<u64> {
+  let line = match lts {
+    "*" => lines.first(),
+    _ => match lts.strip_prefix('-').map(str::parse::<usize>) {
+      Some(Ok(offset)) => lines.get(offset),
+      Some(Err(_)) => None,
+      None => lines.iter().find(|line| line.codename == lts),
+    },
+  };
+  line.map(|line| line.major)
+}
+
+/// provides the "engines.node" field of the given content of a package.json file
+fn parse_package_json(text: &str) -> Option<String> {
+  let package: serde_json::Value = serde_json::from_str(text).ok()?;
+  package["engines"]["node"].as_str().map(ToString::to_string)
+}
+
+/// parses the given version range in npm syntax
+fn parse_npm_range(range: &str) -> Result<VersionRange> {
+  let mut alternatives = vec![];
+  for alternative in range.split("||") {
+    alternatives.push(parse_npm_comparators(alternative.trim())?);
+  }
+  Ok(VersionRange::from(alternatives))
+}
+
+/// parses the given version range in npm syntax that contains no alternatives
+fn parse_npm_comparators(range: &str) -> Result<semver::VersionReq> {
+  if let Some((from, to)) = range.split_once(" - ") {
+    return parse_semver_req(&format!(">={}, <={}", from.trim(), to.trim()));
+  }
+  // npm separates comparators with spaces and allows spaces between operator and version
+  let mut comparators: Vec<String> = vec![];
+  let mut operator = String::new();
+  for token in range.split_whitespace() {
+    if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '~' | '^')) {
+      operator.push_str(token);
+    } else {
+      comparators.push(format!("{operator}{token}"));
+      operator.clear();
+    }
+  }
+  if comparators.is_empty() {
+    return Ok(semver::VersionReq::STAR);
+  }
+  parse_semver_req(&comparators.join(", "))
+}
+
+fn parse_semver_req(text: &str) -> Result<semver::VersionReq> {
+  semver::VersionReq::parse(text).map_err(|err| UserError::CannotParseSemverRange {
+    expression: text.to_string(),
+    reason: err.to_string(),
+  })
+}
+
 pub fn cpu_text(cpu: Cpu) -> &'static str {
   match cpu {
     Cpu::Arm64 => "arm64",
@@ -96,7 +213,7 @@ mod tests {
     use crate::applications::nodejs::NodeJS;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -112,6 +229,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://nodejs.org/dist/v20.10.0/node-v20.10.0-linux-arm64.tar.xz".into(),
+          checksum: Checksum::File {
+            url: "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::RootOrSubfolders {
             options: vec!["node-v20.10.0-linux-arm64".into(), "node-v20.10.0-linux-arm64/bin".into()],
           },
@@ -133,6 +253,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://nodejs.org/dist/v20.10.0/node-v20.10.0-linux-x64.tar.xz".into(),
+          checksum: Checksum::File {
+            url: "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::RootOrSubfolders {
             options: vec!["node-v20.10.0-linux-x64".into(), "node-v20.10.0-linux-x64/bin".into()],
           },
@@ -154,6 +277,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://nodejs.org/dist/v20.10.0/node-v20.10.0-darwin-arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::RootOrSubfolders {
             options: vec!["node-v20.10.0-darwin-arm64".into(), "node-v20.10.0-darwin-arm64/bin".into()],
           },
@@ -175,6 +301,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://nodejs.org/dist/v20.10.0/node-v20.10.0-darwin-x64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::RootOrSubfolders {
             options: vec!["node-v20.10.0-darwin-x64".into(), "node-v20.10.0-darwin-x64/bin".into()],
           },
@@ -196,6 +325,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://nodejs.org/dist/v20.10.0/node-v20.10.0-win-arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::RootOrSubfolders {
             options: vec!["node-v20.10.0-win-arm64".into(), r"node-v20.10.0-win-arm64\bin".into()],
           },
@@ -217,6 +349,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://nodejs.org/dist/v20.10.0/node-v20.10.0-win-x64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".into(),
+          },
           bin_folder: BinFolder::RootOrSubfolders {
             options: vec!["node-v20.10.0-win-x64".into(), r"node-v20.10.0-win-x64\bin".into()],
           },
@@ -225,4 +360,150 @@ mod tests {
       assert_eq!(have, want);
     }
   }
+
+  mod parse_version_alias {
+    use super::super::parse_version_alias;
+    use crate::error::Result;
+    use crate::hosting::nodejs_dist::LtsLine;
+
+    #[allow(clippy::unnecessary_wraps)] // must match the signature of the LTS lookup
+    fn lts_lines() -> Result<Vec<LtsLine>> {
+      Ok(vec![
+        LtsLine {
+          codename: "krypton".into(),
+          major: 24,
+        },
+        LtsLine {
+          codename: "jod".into(),
+          major: 22,
+        },
+        LtsLine {
+          codename: "iron".into(),
+          major: 20,
+        },
+        LtsLine {
+          codename: "hydrogen".into(),
+          major: 18,
+        },
+      ])
+    }
+
+    fn parse(text: &str) -> String {
+      parse_version_alias(text, &lts_lines).unwrap().to_string()
+    }
+
+    #[test]
+    fn full_version() {
+      assert_eq!(parse("v20.10.0"), "=20.10.0");
+    }
+
+    #[test]
+    fn partial_versions() {
+      assert_eq!(parse("20"), "~20");
+      assert_eq!(parse("v20.10"), "~20.10");
+    }
+
+    #[test]
+    fn lts() {
+      assert_eq!(parse("lts/*"), "~24");
+      assert_eq!(parse("lts/-1"), "~22");
+      assert_eq!(parse("lts/iron"), "~20");
+      assert_eq!(parse("LTS/Hydrogen"), "~18");
+    }
+
+    #[test]
+    fn unknown_lts() {
+      assert!(parse_version_alias("lts/zonk", &lts_lines).is_err());
+      assert!(parse_version_alias("lts/-20", &lts_lines).is_err());
+    }
+
+    #[test]
+    fn latest() {
+      assert_eq!(parse("node"), "*");
+      assert_eq!(parse("stable"), "*");
+    }
+
+    #[test]
+    fn range() {
+      assert_eq!(parse(">=18"), ">=18");
+    }
+
+    #[test]
+    fn no_lts_lookup_for_versions() {
+      let unavailable = || -> Result<Vec<LtsLine>> { Err(crate::error::UserError::NotOnline) };
+      assert_eq!(parse_version_alias("20", &unavailable).unwrap().to_string(), "~20");
+    }
+  }
+
+  mod parse_version_file {
+    use super::super::parse_version_file;
+    use crate::error::Result;
+    use crate::hosting::nodejs_dist::LtsLine;
+
+    #[allow(clippy::unnecessary_wraps)] // must match the signature of the LTS lookup
+    fn lts_lines() -> Result<Vec<LtsLine>> {
+      Ok(vec![])
+    }
+
+    #[test]
+    fn with_comments_and_whitespace() {
+      let have = parse_version_file("# the Node version\n\n  20.10\n", &lts_lines).unwrap().unwrap();
+      assert_eq!(have.to_string(), "~20.10");
+    }
+
+    #[test]
+    fn empty() {
+      assert_eq!(parse_version_file("\n", &lts_lines).unwrap(), None);
+    }
+  }
+
+  mod parse_npm_range {
+    use super::super::parse_npm_range;
+
+    fn parse(text: &str) -> String {
+      parse_npm_range(text).unwrap().to_string()
+    }
+
+    #[test]
+    fn caret() {
+      assert_eq!(parse("^20.10.0"), "^20.10.0");
+    }
+
+    #[test]
+    fn space_separated() {
+      assert_eq!(parse(">= 18 <21"), ">=18, <21");
+    }
+
+    #[test]
+    fn hyphen() {
+      assert_eq!(parse("18 - 20"), ">=18, <=20");
+    }
+
+    #[test]
+    fn alternatives() {
+      assert_eq!(parse("^18 || ^20"), "^18 || ^20");
+      assert_eq!(parse(">=16 <17 || >= 20"), ">=16, <17 || >=20");
+    }
+
+    #[test]
+    fn wildcard() {
+      assert_eq!(parse("20.x"), "20.*");
+      assert_eq!(parse(""), "*");
+    }
+  }
+
+  mod parse_package_json {
+    use super::super::parse_package_json;
+
+    #[test]
+    fn engines() {
+      let give = r#"{ "name": "app", "engines": { "node": ">=20", "npm": ">=10" } }"#;
+      assert_eq!(parse_package_json(give).as_deref(), Some(">=20"));
+    }
+
+    #[test]
+    fn no_engines() {
+      assert_eq!(parse_package_json(r#"{ "name": "app" }"#), None);
+    }
+  }
 }
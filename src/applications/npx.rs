@@ -1,9 +1,10 @@
-use super::nodejs::NodeJS;
+use super::nodejs::{self, NodeJS};
 use super::{AnalyzeResult, AppDefinition, ApplicationName};
-use crate::configuration::{TagFormat, Version};
+use crate::configuration::{TagFormat, Version, VersionRange};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::platform::{Os, Platform};
+use crate::yard::VersionsCache;
 use crate::{Log, subshell};
 
 #[derive(Clone)]
@@ -45,6 +46,16 @@ impl AppDefinition for Npx {
     Ok(AnalyzeResult::IdentifiedButUnknownVersion)
   }
 
+  fn allowed_versions(&self, cache: &VersionsCache, log: Log) -> Result<VersionRange> {
+    // npx is versioned together with NodeJS, so the NodeJS version restrictions of the project apply
+    app_to_install().allowed_versions(cache, log)
+  }
+
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    // npm ships with NodeJS, so its changes are part of the NodeJS release notes
+    Some((nodejs::ORG, nodejs::REPO))
+  }
+
   fn tag_format(&self) -> TagFormat {
     app_to_install().tag_format()
   }
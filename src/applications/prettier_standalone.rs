@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, ExecutableNameUnix, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, subshell};
 use const_format::formatcp;
@@ -27,6 +27,10 @@ impl AppDefinition for PrettierStandalone {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm64",
@@ -41,6 +45,7 @@ impl AppDefinition for PrettierStandalone {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/prettier-{os}-{cpu}-{version}.tar.gz").into(),
+        checksum: Checksum::None,
         bin_folder: BinFolder::Root,
       }],
     }
@@ -76,7 +81,7 @@ mod tests {
     use crate::applications::prettier_standalone::PrettierStandalone;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -91,6 +96,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/markelliot/prettier-standalone/releases/download/0.24.0/prettier-linux-arm64-0.24.0.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -109,6 +115,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/markelliot/prettier-standalone/releases/download/0.24.0/prettier-linux-amd64-0.24.0.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -127,6 +134,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/markelliot/prettier-standalone/releases/download/0.24.0/prettier-darwin-arm64-0.24.0.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -145,6 +153,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/markelliot/prettier-standalone/releases/download/0.24.0/prettier-darwin-amd64-0.24.0.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for Pyrefly {
     "https://pyrefly.org"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "arm64",
@@ -40,6 +44,7 @@ impl AppDefinition for Pyrefly {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/pyrefly-{os}-{cpu}.{ext}").into(),
+        checksum: Checksum::None,
         bin_folder: BinFolder::Root,
       }],
     }
@@ -77,7 +82,7 @@ mod tests {
     use crate::applications::pyrefly::Pyrefly;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -92,6 +97,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/facebook/pyrefly/releases/download/0.57.1/pyrefly-linux-arm64.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -110,6 +116,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/facebook/pyrefly/releases/download/0.57.1/pyrefly-linux-x86_64.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -128,6 +135,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/facebook/pyrefly/releases/download/0.57.1/pyrefly-macos-arm64.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -146,6 +154,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/facebook/pyrefly/releases/download/0.57.1/pyrefly-macos-x86_64.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -164,6 +173,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/facebook/pyrefly/releases/download/0.57.1/pyrefly-windows-arm64.zip".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -182,6 +192,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/facebook/pyrefly/releases/download/0.57.1/pyrefly-windows-x86_64.zip".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
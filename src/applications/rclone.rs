@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for Rclone {
     "https://rclone.org"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -36,6 +40,9 @@ impl AppDefinition for Rclone {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/rclone-v{version}-{os}-{cpu}.zip").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/SHA256SUMS").into(),
+        },
         bin_folder: BinFolder::Subfolder {
           path: format!("rclone-v{version}-{os}-{cpu}").into(),
         },
@@ -76,7 +83,7 @@ mod tests {
     use crate::applications::rclone::Rclone;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -91,6 +98,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rclone/rclone/releases/download/v1.72.1/rclone-v1.72.1-linux-arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/rclone/rclone/releases/download/v1.72.1/SHA256SUMS".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "rclone-v1.72.1-linux-arm64".into(),
           },
@@ -111,6 +121,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rclone/rclone/releases/download/v1.72.1/rclone-v1.72.1-linux-amd64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/rclone/rclone/releases/download/v1.72.1/SHA256SUMS".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "rclone-v1.72.1-linux-amd64".into(),
           },
@@ -131,6 +144,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rclone/rclone/releases/download/v1.72.1/rclone-v1.72.1-osx-arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/rclone/rclone/releases/download/v1.72.1/SHA256SUMS".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "rclone-v1.72.1-osx-arm64".into(),
           },
@@ -151,6 +167,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rclone/rclone/releases/download/v1.72.1/rclone-v1.72.1-osx-amd64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/rclone/rclone/releases/download/v1.72.1/SHA256SUMS".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "rclone-v1.72.1-osx-amd64".into(),
           },
@@ -171,6 +190,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rclone/rclone/releases/download/v1.72.1/rclone-v1.72.1-windows-arm64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/rclone/rclone/releases/download/v1.72.1/SHA256SUMS".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "rclone-v1.72.1-windows-arm64".into(),
           },
@@ -191,6 +213,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rclone/rclone/releases/download/v1.72.1/rclone-v1.72.1-windows-amd64.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/rclone/rclone/releases/download/v1.72.1/SHA256SUMS".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "rclone-v1.72.1-windows-amd64".into(),
           },
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, executables, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for RipGrep {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn executable_filename(&self) -> executables::ExecutableNameUnix {
     executables::ExecutableNameUnix::from("rg")
   }
@@ -46,6 +50,9 @@ impl AppDefinition for RipGrep {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ripgrep-{version}-{cpu}-{os}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ripgrep-{version}-{cpu}-{os}.{ext}.sha256").into(),
+        },
         bin_folder: BinFolder::Subfolder {
           path: format!("ripgrep-{version}-{cpu}-{os}").into(),
         },
@@ -85,7 +92,7 @@ mod tests {
     use crate::applications::ripgrep::RipGrep;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -100,6 +107,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-unknown-linux-gnu.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ripgrep-14.1.1-aarch64-unknown-linux-gnu".into(),
           },
@@ -120,6 +130,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-unknown-linux-musl.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-unknown-linux-musl.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ripgrep-14.1.1-x86_64-unknown-linux-musl".into(),
           },
@@ -140,6 +153,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-apple-darwin.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ripgrep-14.1.1-aarch64-apple-darwin".into(),
           },
@@ -160,6 +176,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-apple-darwin.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ripgrep-14.1.1-x86_64-apple-darwin".into(),
           },
@@ -180,6 +199,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-pc-windows-msvc.zip.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ripgrep-14.1.1-aarch64-pc-windows-msvc".into(),
           },
@@ -200,6 +222,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-pc-windows-msvc.zip.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ripgrep-14.1.1-x86_64-pc-windows-msvc".into(),
           },
@@ -1,10 +1,11 @@
 use super::{AnalyzeResult, AppDefinition, ApplicationName};
-use crate::configuration::{TagFormat, Version};
+use crate::configuration::{TagFormat, Version, VersionRange, pyproject_required_version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
+use crate::yard::VersionsCache;
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
 
@@ -23,6 +24,10 @@ impl AppDefinition for Ruff {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -41,6 +46,9 @@ impl AppDefinition for Ruff {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ruff-{cpu}-{os}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ruff-{cpu}-{os}.{ext}.sha256").into(),
+        },
         bin_folder: BinFolder::Subfolder {
           path: format!("ruff-{cpu}-{os}").into(),
         },
@@ -67,6 +75,10 @@ impl AppDefinition for Ruff {
     }
   }
 
+  fn allowed_versions(&self, _cache: &VersionsCache, _log: Log) -> Result<VersionRange> {
+    pyproject_required_version("ruff", &[".ruff.toml", "ruff.toml"]).map(VersionRange::from)
+  }
+
   fn tag_format(&self) -> TagFormat {
     TagFormat::Plain
   }
@@ -80,7 +92,7 @@ mod tests {
     use crate::applications::ruff::Ruff;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -95,6 +107,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-aarch64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-aarch64-unknown-linux-gnu.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ruff-aarch64-unknown-linux-gnu".into(),
           },
@@ -115,6 +130,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-x86_64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-x86_64-unknown-linux-gnu.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ruff-x86_64-unknown-linux-gnu".into(),
           },
@@ -135,6 +153,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-aarch64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-aarch64-apple-darwin.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ruff-aarch64-apple-darwin".into(),
           },
@@ -155,6 +176,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-x86_64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-x86_64-apple-darwin.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ruff-x86_64-apple-darwin".into(),
           },
@@ -175,6 +199,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-aarch64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-aarch64-pc-windows-msvc.zip.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ruff-aarch64-pc-windows-msvc".into(),
           },
@@ -195,6 +222,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-x86_64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ruff/releases/download/0.15.5/ruff-x86_64-pc-windows-msvc.zip.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ruff-x86_64-pc-windows-msvc".into(),
           },
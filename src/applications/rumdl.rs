@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Rumdl {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -41,6 +45,7 @@ impl AppDefinition for Rumdl {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/rumdl-{tag}-{cpu}-{os}.{ext}").into(),
+        checksum: Checksum::None,
         bin_folder: BinFolder::Root,
       }],
     }
@@ -78,7 +83,7 @@ mod tests {
     use crate::applications::rumdl::Rumdl;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -93,6 +98,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rvben/rumdl/releases/download/v0.1.58/rumdl-v0.1.58-aarch64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -111,6 +117,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rvben/rumdl/releases/download/v0.1.58/rumdl-v0.1.58-x86_64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -129,6 +136,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rvben/rumdl/releases/download/v0.1.58/rumdl-v0.1.58-aarch64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -147,6 +155,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rvben/rumdl/releases/download/v0.1.58/rumdl-v0.1.58-x86_64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -165,6 +174,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rvben/rumdl/releases/download/v0.1.58/rumdl-v0.1.58-aarch64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -183,6 +193,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/rvben/rumdl/releases/download/v0.1.58/rumdl-v0.1.58-x86_64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         }],
       };
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Scc {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "Linux",
@@ -38,6 +42,9 @@ impl AppDefinition for Scc {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/scc_{os}_{cpu}.tar.gz").into(),
+          checksum: Checksum::File {
+            url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/checksums.txt").into(),
+          },
           bin_folder: BinFolder::Root,
         },
         Method::CompileGoSource {
@@ -79,7 +86,7 @@ mod tests {
     use crate::applications::scc::Scc;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -96,6 +103,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/boyter/scc/releases/download/v3.2.0/scc_Linux_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/boyter/scc/releases/download/v3.2.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -119,6 +129,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/boyter/scc/releases/download/v3.2.0/scc_Linux_x86_64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/boyter/scc/releases/download/v3.2.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -142,6 +155,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/boyter/scc/releases/download/v3.2.0/scc_Darwin_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/boyter/scc/releases/download/v3.2.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -165,6 +181,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/boyter/scc/releases/download/v3.2.0/scc_Darwin_x86_64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/boyter/scc/releases/download/v3.2.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -188,6 +207,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/boyter/scc/releases/download/v3.2.0/scc_Windows_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/boyter/scc/releases/download/v3.2.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -211,6 +233,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/boyter/scc/releases/download/v3.2.0/scc_Windows_x86_64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/boyter/scc/releases/download/v3.2.0/checksums.txt".into(),
+            },
             bin_folder: BinFolder::Root,
           },
           Method::CompileGoSource {
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for ShellCheck {
     "https://www.shellcheck.net"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -40,6 +44,7 @@ impl AppDefinition for ShellCheck {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/shellcheck-{tag}.{os}.{cpu}.{ext}").into(),
+        checksum: Checksum::None,
         bin_folder: BinFolder::Subfolder {
           path: format!("shellcheck-v{version}").into(),
         },
@@ -79,7 +84,7 @@ mod tests {
     use crate::applications::shellcheck::ShellCheck;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -94,6 +99,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.linux.aarch64.tar.xz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: "shellcheck-v0.10.0".into(),
           },
@@ -114,6 +120,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.linux.x86_64.tar.xz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: "shellcheck-v0.10.0".into(),
           },
@@ -134,6 +141,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.darwin.aarch64.tar.xz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: "shellcheck-v0.10.0".into(),
           },
@@ -154,6 +162,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.darwin.x86_64.tar.xz".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: "shellcheck-v0.10.0".into(),
           },
@@ -174,6 +183,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.windows.aarch64.zip".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: "shellcheck-v0.10.0".into(),
           },
@@ -194,6 +204,7 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.windows.x86_64.zip".into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Subfolder {
             path: "shellcheck-v0.10.0".into(),
           },
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::Method;
+use crate::installation::{Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Snyk {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -41,6 +45,9 @@ impl AppDefinition for Snyk {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadExecutable {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/snyk-{os}{cpu}{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/snyk-{os}{cpu}{ext}.sha256").into(),
+        },
       }],
     }
   }
@@ -77,7 +84,7 @@ mod tests {
     use crate::applications::snyk::Snyk;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::Method;
+    use crate::installation::{Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -92,6 +99,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-linux-arm64".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-linux-arm64.sha256".into(),
+          },
         }],
       };
       assert_eq!(have, want);
@@ -109,6 +119,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-linux".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-linux.sha256".into(),
+          },
         }],
       };
       assert_eq!(have, want);
@@ -126,6 +139,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-macos-arm64".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-macos-arm64.sha256".into(),
+          },
         }],
       };
       assert_eq!(have, want);
@@ -143,6 +159,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-macos".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-macos.sha256".into(),
+          },
         }],
       };
       assert_eq!(have, want);
@@ -160,6 +179,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-win-arm64.exe".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-win-arm64.exe.sha256".into(),
+          },
         }],
       };
       assert_eq!(have, want);
@@ -177,6 +199,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadExecutable {
           url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-win.exe".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/snyk/cli/releases/download/v1.1304.1/snyk-win.exe.sha256".into(),
+          },
         }],
       };
       assert_eq!(have, want);
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, subshell};
 
@@ -22,6 +22,10 @@ impl AppDefinition for StaticCheck {
     "https://staticcheck.dev"
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -37,6 +41,9 @@ impl AppDefinition for StaticCheck {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/staticcheck_{os}_{cpu}.tar.gz").into(),
+          checksum: Checksum::File {
+            url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/staticcheck_{os}_{cpu}.tar.gz.sha256").into(),
+          },
           bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
         },
         Method::CompileGoSource {
@@ -75,7 +82,7 @@ mod tests {
     use crate::applications::staticcheck::StaticCheck;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
     use big_s::S;
 
@@ -92,6 +99,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_linux_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_linux_arm64.tar.gz.sha256".into(),
+            },
             bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
           },
           Method::CompileGoSource {
@@ -115,6 +125,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_linux_amd64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_linux_amd64.tar.gz.sha256".into(),
+            },
             bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
           },
           Method::CompileGoSource {
@@ -138,6 +151,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_darwin_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_darwin_arm64.tar.gz.sha256".into(),
+            },
             bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
           },
           Method::CompileGoSource {
@@ -161,6 +177,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_darwin_amd64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_darwin_amd64.tar.gz.sha256".into(),
+            },
             bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
           },
           Method::CompileGoSource {
@@ -184,6 +203,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_windows_arm64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_windows_arm64.tar.gz.sha256".into(),
+            },
             bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
           },
           Method::CompileGoSource {
@@ -207,6 +229,9 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_windows_amd64.tar.gz".into(),
+            checksum: Checksum::File {
+              url: "https://github.com/dominikh/go-tools/releases/download/3.7.0/staticcheck_windows_amd64.tar.gz.sha256".into(),
+            },
             bin_folder: BinFolder::Subfolder { path: "staticcheck".into() },
           },
           Method::CompileGoSource {
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Taplo {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "linux",
@@ -42,10 +46,11 @@ impl AppDefinition for Taplo {
       install_methods: vec![
         Method::DownloadArchive {
           url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/taplo-{os}-{cpu}.{ext}").into(),
+          checksum: Checksum::None,
           bin_folder: BinFolder::Root,
         },
         Method::CompileRustCrate {
-          name: "taplo-cli",
+          name: "taplo-cli".into(),
           bin_folder: BinFolder::Subfolder { path: "bin".into() },
         },
       ],
@@ -84,7 +89,7 @@ mod tests {
     use crate::applications::taplo::Taplo;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -100,10 +105,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/tamasfe/taplo/releases/download/0.10.0/taplo-linux-aarch64.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "taplo-cli",
+            name: "taplo-cli".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -124,10 +130,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/tamasfe/taplo/releases/download/0.10.0/taplo-linux-x86_64.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "taplo-cli",
+            name: "taplo-cli".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -148,10 +155,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/tamasfe/taplo/releases/download/0.10.0/taplo-darwin-aarch64.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "taplo-cli",
+            name: "taplo-cli".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -172,10 +180,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/tamasfe/taplo/releases/download/0.10.0/taplo-darwin-x86_64.gz".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "taplo-cli",
+            name: "taplo-cli".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -196,10 +205,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/tamasfe/taplo/releases/download/0.10.0/taplo-windows-aarch64.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "taplo-cli",
+            name: "taplo-cli".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -220,10 +230,11 @@ mod tests {
         install_methods: vec![
           Method::DownloadArchive {
             url: "https://github.com/tamasfe/taplo/releases/download/0.10.0/taplo-windows-x86_64.zip".into(),
+            checksum: Checksum::None,
             bin_folder: BinFolder::Root,
           },
           Method::CompileRustCrate {
-            name: "taplo-cli",
+            name: "taplo-cli".into(),
             bin_folder: BinFolder::Subfolder { path: "bin".into() },
           },
         ],
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Ty {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let cpu = match platform.cpu {
       Cpu::Arm64 => "aarch64",
@@ -41,6 +45,9 @@ impl AppDefinition for Ty {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ty-{cpu}-{os}.{ext}").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/ty-{cpu}-{os}.{ext}.sha256").into(),
+        },
         bin_folder: BinFolder::Subfolder {
           path: format!("ty-{cpu}-{os}").into(),
         },
@@ -80,7 +87,7 @@ mod tests {
     use crate::applications::ty::Ty;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -95,6 +102,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-aarch64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-aarch64-unknown-linux-gnu.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ty-aarch64-unknown-linux-gnu".into(),
           },
@@ -115,6 +125,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-x86_64-unknown-linux-gnu.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-x86_64-unknown-linux-gnu.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ty-x86_64-unknown-linux-gnu".into(),
           },
@@ -135,6 +148,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-aarch64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-aarch64-apple-darwin.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ty-aarch64-apple-darwin".into(),
           },
@@ -155,6 +171,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-x86_64-apple-darwin.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-x86_64-apple-darwin.tar.gz.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ty-x86_64-apple-darwin".into(),
           },
@@ -175,6 +194,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-aarch64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-aarch64-pc-windows-msvc.zip.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ty-aarch64-pc-windows-msvc".into(),
           },
@@ -195,6 +217,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-x86_64-pc-windows-msvc.zip".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/astral-sh/ty/releases/download/0.0.23/ty-x86_64-pc-windows-msvc.zip.sha256".into(),
+          },
           bin_folder: BinFolder::Subfolder {
             path: "ty-x86_64-pc-windows-msvc".into(),
           },
@@ -3,7 +3,7 @@ use crate::configuration::{TagFormat, Version};
 use crate::error::Result;
 use crate::executables::{Executable, RunMethod};
 use crate::hosting::github_releases;
-use crate::installation::{BinFolder, Method};
+use crate::installation::{BinFolder, Checksum, Method};
 use crate::platform::{Cpu, Os, Platform};
 use crate::{Log, strings, subshell};
 use const_format::formatcp;
@@ -23,6 +23,10 @@ impl AppDefinition for Yamlfmt {
     formatcp!("https://github.com/{ORG}/{REPO}")
   }
 
+  fn github_repo(&self) -> Option<(&str, &str)> {
+    Some((ORG, REPO))
+  }
+
   fn run_method(&self, version: &Version, platform: Platform) -> RunMethod {
     let os = match platform.os {
       Os::Linux => "Linux",
@@ -37,6 +41,9 @@ impl AppDefinition for Yamlfmt {
     RunMethod::ThisApp {
       install_methods: vec![Method::DownloadArchive {
         url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/yamlfmt_{version}_{os}_{cpu}.tar.gz").into(),
+        checksum: Checksum::File {
+          url: format!("https://github.com/{ORG}/{REPO}/releases/download/{tag}/checksums.txt").into(),
+        },
         bin_folder: BinFolder::Root,
       }],
     }
@@ -75,7 +82,7 @@ mod tests {
     use crate::applications::yamlfmt::Yamlfmt;
     use crate::configuration::Version;
     use crate::executables::RunMethod;
-    use crate::installation::{BinFolder, Method};
+    use crate::installation::{BinFolder, Checksum, Method};
     use crate::platform::{Cpu, Os, Platform};
 
     #[test]
@@ -90,6 +97,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/yamlfmt_0.21.0_Linux_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -108,6 +118,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/yamlfmt_0.21.0_Linux_x86_64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -126,6 +139,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/yamlfmt_0.21.0_Darwin_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -144,6 +160,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/yamlfmt_0.21.0_Darwin_x86_64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -162,6 +181,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/yamlfmt_0.21.0_Windows_arm64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -180,6 +202,9 @@ mod tests {
       let want = RunMethod::ThisApp {
         install_methods: vec![Method::DownloadArchive {
           url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/yamlfmt_0.21.0_Windows_x86_64.tar.gz".into(),
+          checksum: Checksum::File {
+            url: "https://github.com/google/yamlfmt/releases/download/v0.21.0/checksums.txt".into(),
+          },
           bin_folder: BinFolder::Root,
         }],
       };
@@ -30,6 +30,11 @@ pub fn lookup(filepath: &str, data: Vec<u8>) -> Option<Box<dyn Archive>> {
   }
 }
 
+/// indicates whether the file at the given path is an archive that run-that-app can extract
+pub fn is_archive(filepath: &str) -> bool {
+  lookup(filepath, vec![]).is_some()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,375 @@
+//! This module implements bundles: archives containing the downloaded artifacts of applications,
+//! for installing them on machines without internet access.
+
+use crate::applications::{ApplicationName, Apps};
+use crate::configuration::Version;
+use crate::download::Url;
+use crate::error::{Result, UserError};
+use crate::installation::{Method, sha256};
+use crate::platform::Platform;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use tempfile::TempDir;
+
+/// name of the file inside a bundle that describes the bundled artifacts
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// the folder inside a bundle that contains the artifacts
+const ARTIFACTS_FOLDER: &str = "artifacts";
+
+/// the downloaded artifacts of applications
+pub enum Bundle {
+  /// collects the artifacts that installations download, to pack them into a bundle file
+  Recording {
+    /// the folder that stores the recorded artifacts until they get packed
+    folder: PathBuf,
+    entries: Mutex<Vec<BundleEntry>>,
+  },
+  /// provides the artifacts of a bundle file instead of downloading them
+  Imported {
+    /// the temporary folder containing the extracted artifacts, deleted when the bundle is dropped
+    folder: TempDir,
+    entries: Vec<BundleEntry>,
+  },
+}
+
+/// an artifact in a bundle, with the information needed to install it
+#[derive(Debug, PartialEq)]
+pub struct BundleEntry {
+  pub app_name: ApplicationName,
+  pub version: Version,
+  /// the platform this artifact is for, e.g. "linux/intel64"
+  pub platform: String,
+  /// the installation method that downloaded this artifact, as provided by [`Method::lock_name`]
+  pub method: String,
+  /// the location installed from, as recorded in the lockfile
+  pub source: String,
+  /// the URL this artifact got downloaded from
+  pub url: String,
+  /// lowercase hex-encoded SHA-256 digest of the artifact
+  pub hash: String,
+  /// the file on disk containing the artifact
+  pub file: PathBuf,
+}
+
+impl BundleEntry {
+  /// indicates whether this entry contains the artifact for the given app version and installation method
+  fn matches(&self, app_name: &ApplicationName, version: &Version, platform: Platform, method: &Method) -> bool {
+    &self.app_name == app_name
+      && &self.version == version
+      && self.platform == platform.to_string()
+      && self.method == method.lock_name()
+      && method.has_source(&self.source)
+  }
+}
+
+impl Bundle {
+  // CONSTRUCTORS
+
+  /// provides a bundle that stores the artifacts it records in the given folder
+  pub fn recording(folder: PathBuf) -> Result<Bundle> {
+    fs::create_dir_all(&folder).map_err(|err| UserError::CannotCreateFolder {
+      folder: folder.clone(),
+      reason: err.to_string(),
+    })?;
+    Ok(Bundle::Recording {
+      folder,
+      entries: Mutex::new(vec![]),
+    })
+  }
+
+  /// loads the bundle file at the given path, extracting its artifacts into a temporary folder
+  pub fn read(path: &Path, apps: &Apps) -> Result<Bundle> {
+    let file = fs::File::open(path).map_err(|err| invalid(path, &err.to_string()))?;
+    let folder = tempfile::tempdir().map_err(|err| UserError::CannotCreateTempDir { err: err.to_string() })?;
+    // decompress while reading instead of loading the whole archive into memory
+    let mut archive = tar::Archive::new(GzDecoder::new(BufReader::new(file)));
+    let mut manifest: Option<String> = None;
+    let entries = archive.entries().map_err(|err| invalid(path, &err.to_string()))?;
+    for entry in entries {
+      let mut entry = entry.map_err(|err| invalid(path, &err.to_string()))?;
+      let name = entry.path().map_err(|err| invalid(path, &err.to_string()))?.to_string_lossy().to_string();
+      if name == MANIFEST_FILE_NAME {
+        let mut text = String::new();
+        entry.read_to_string(&mut text).map_err(|err| invalid(path, &err.to_string()))?;
+        manifest = Some(text);
+        continue;
+      }
+      // ignore files that the manifest cannot refer to
+      let Some(artifact_file) = extracted_path(folder.path(), &name) else {
+        continue;
+      };
+      let mut output = fs::File::create(&artifact_file).map_err(|err| UserError::CannotCreateFile {
+        filename: artifact_file.clone(),
+        err: err.to_string(),
+      })?;
+      io::copy(&mut entry, &mut output).map_err(|err| invalid(path, &err.to_string()))?;
+    }
+    let Some(manifest) = manifest else {
+      return Err(invalid(path, &format!("{MANIFEST_FILE_NAME} not found")));
+    };
+    let entries = parse_manifest(&manifest, folder.path(), apps).map_err(|reason| invalid(path, &reason))?;
+    Ok(Bundle::Imported { folder, entries })
+  }
+
+  // METHODS
+
+  /// provides the artifacts in this bundle
+  pub fn entries(&mut self) -> &[BundleEntry] {
+    match self {
+      Bundle::Recording { folder: _, entries } => entries.get_mut().unwrap_or_else(PoisonError::into_inner),
+      Bundle::Imported { folder: _, entries } => entries,
+    }
+  }
+
+  /// indicates whether installations should use the artifacts in this bundle instead of downloading them
+  pub fn is_imported(&self) -> bool {
+    matches!(self, Bundle::Imported { .. })
+  }
+
+  /// provides the imported artifact for the given app version and installation method
+  pub fn lookup(&self, app_name: &ApplicationName, version: &Version, platform: Platform, method: &Method) -> Option<&BundleEntry> {
+    match self {
+      Bundle::Recording { .. } => None,
+      Bundle::Imported { folder: _, entries } => entries.iter().find(|entry| entry.matches(app_name, version, platform, method)),
+    }
+  }
+
+  /// adds the given downloaded artifact to this bundle if it is recording
+  pub fn record(&self, app_name: &ApplicationName, version: &Version, platform: Platform, method: &Method, url: &Url, data: &[u8]) -> Result<()> {
+    let Bundle::Recording { folder, entries } = self else {
+      return Ok(());
+    };
+    let hash = sha256(data);
+    let file = folder.join(&hash);
+    fs::write(&file, data).map_err(|err| UserError::CannotCreateFile {
+      filename: file.clone(),
+      err: err.to_string(),
+    })?;
+    entries.lock().unwrap_or_else(PoisonError::into_inner).push(BundleEntry {
+      app_name: app_name.clone(),
+      version: version.clone(),
+      platform: platform.to_string(),
+      method: method.lock_name().to_string(),
+      source: url.to_string(),
+      url: url.to_string(),
+      hash,
+      file,
+    });
+    Ok(())
+  }
+
+  /// saves this bundle into a file at the given path
+  pub fn write(&mut self, path: &Path) -> Result<()> {
+    let cannot_create = |err: io::Error| UserError::CannotCreateFile {
+      filename: path.to_path_buf(),
+      err: err.to_string(),
+    };
+    let entries = self.entries();
+    let file = fs::File::create(path).map_err(cannot_create)?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    let manifest = render_manifest(entries);
+    append(&mut builder, MANIFEST_FILE_NAME, manifest.len() as u64, manifest.as_bytes()).map_err(cannot_create)?;
+    let mut written: Vec<&str> = vec![];
+    for entry in entries {
+      // several apps can share the same artifact
+      if written.contains(&entry.hash.as_str()) {
+        continue;
+      }
+      let artifact = fs::File::open(&entry.file).map_err(cannot_create)?;
+      let size = artifact.metadata().map_err(cannot_create)?.len();
+      append(&mut builder, &artifact_path(&entry.hash), size, artifact).map_err(cannot_create)?;
+      written.push(&entry.hash);
+    }
+    builder.into_inner().and_then(GzEncoder::finish).map_err(cannot_create)?;
+    Ok(())
+  }
+}
+
+/// adds a file with the given name, size, and content to the given tar archive
+fn append<W: io::Write>(builder: &mut tar::Builder<W>, name: &str, size: u64, data: impl Read) -> io::Result<()> {
+  let mut header = tar::Header::new_gnu();
+  header.set_size(size);
+  header.set_mode(0o644);
+  header.set_cksum();
+  builder.append_data(&mut header, name, data)
+}
+
+/// location of the artifact with the given hash inside a bundle
+fn artifact_path(hash: &str) -> String {
+  format!("{ARTIFACTS_FOLDER}/{hash}")
+}
+
+/// provides the location in the given folder to extract the artifact with the given location inside a bundle to,
+/// None if the given location isn't a valid artifact location
+fn extracted_path(folder: &Path, name: &str) -> Option<PathBuf> {
+  let hash = name.strip_prefix(ARTIFACTS_FOLDER)?.strip_prefix('/')?;
+  if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_alphanumeric()) {
+    return None;
+  }
+  Some(folder.join(hash))
+}
+
+/// provides the lowercase hex-encoded SHA-256 digest of the given file, without loading it into memory
+fn file_sha256(path: &Path) -> io::Result<String> {
+  let mut file = fs::File::open(path)?;
+  let mut hasher = Sha256::new();
+  io::copy(&mut file, &mut hasher)?;
+  Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn invalid(path: &Path, reason: &str) -> UserError {
+  UserError::InvalidBundle {
+    path: path.to_path_buf(),
+    reason: reason.to_string(),
+  }
+}
+
+/// describes the given artifacts in the manifest format
+fn render_manifest(entries: &[BundleEntry]) -> String {
+  let artifacts: Vec<Value> = entries
+    .iter()
+    .map(|entry| {
+      json!({
+        "app": entry.app_name.as_str(),
+        "version": entry.version.as_str(),
+        "platform": entry.platform,
+        "method": entry.method,
+        "source": entry.source,
+        "url": entry.url,
+        "sha256": entry.hash,
+        "file": artifact_path(&entry.hash),
+      })
+    })
+    .collect();
+  json!({ "artifacts": artifacts }).to_string()
+}
+
+/// parses the given manifest and verifies the artifacts extracted into the given folder against it
+fn parse_manifest(text: &str, folder: &Path, apps: &Apps) -> std::result::Result<Vec<BundleEntry>, String> {
+  let manifest: Value = serde_json::from_str(text).map_err(|err| format!("{MANIFEST_FILE_NAME} is not valid JSON: {err}"))?;
+  let Some(artifacts) = manifest.get("artifacts").and_then(Value::as_array) else {
+    return Err(format!("{MANIFEST_FILE_NAME} contains no artifacts"));
+  };
+  let mut result = vec![];
+  for artifact in artifacts {
+    let field = |name: &str| {
+      artifact
+        .get(name)
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+        .ok_or_else(|| format!("artifact without {name} in {MANIFEST_FILE_NAME}"))
+    };
+    let name = field("file")?;
+    let hash = field("sha256")?;
+    let Some(file) = extracted_path(folder, &name).filter(|file| file.is_file()) else {
+      return Err(format!("{name} is missing"));
+    };
+    let actual = file_sha256(&file).map_err(|err| format!("cannot read {name}: {err}"))?;
+    if actual != hash {
+      return Err(format!("{name} has SHA-256 {actual} instead of {hash}"));
+    }
+    let app_name = field("app")?;
+    let app = apps.lookup(&app_name).map_err(|_| format!("unknown app: {app_name}"))?;
+    result.push(BundleEntry {
+      app_name: app.name(),
+      version: Version::from(field("version")?),
+      platform: field("platform")?,
+      method: field("method")?,
+      source: field("source")?,
+      url: field("url")?,
+      hash,
+      file,
+    });
+  }
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod write_read {
+    use super::super::Bundle;
+    use crate::applications;
+    use crate::download::Url;
+    use crate::installation::{BinFolder, Checksum, Method, sha256};
+    use crate::platform::{Cpu, Os, Platform};
+    use std::fs;
+
+    #[test]
+    fn roundtrip() {
+      let apps = applications::all();
+      let shellcheck = apps.lookup("shellcheck").unwrap();
+      let tempdir = tempfile::tempdir().unwrap();
+      let platform = Platform {
+        os: Os::Linux,
+        cpu: Cpu::Arm64,
+      };
+      let method = Method::DownloadArchive {
+        url: "https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.linux.aarch64.tar.xz".into(),
+        checksum: Checksum::None,
+        bin_folder: BinFolder::Root,
+      };
+      let url = Url::from("https://github.com/koalaman/shellcheck/releases/download/v0.10.0/shellcheck-v0.10.0.linux.aarch64.tar.xz");
+      let mut bundle = Bundle::recording(tempdir.path().join("staging")).unwrap();
+      bundle
+        .record(&shellcheck.name(), &"0.10.0".into(), platform, &method, &url, b"archive content")
+        .unwrap();
+      let path = tempdir.path().join("bundle.tar.gz");
+      bundle.write(&path).unwrap();
+      let mut have = Bundle::read(&path, &apps).unwrap();
+      assert!(have.is_imported());
+      let entries = have.entries();
+      assert_eq!(entries.len(), 1);
+      let entry = entries.first().unwrap();
+      assert_eq!(entry.app_name, shellcheck.name());
+      assert_eq!(entry.version.as_str(), "0.10.0");
+      assert_eq!(entry.platform, "linux/arm64");
+      assert_eq!(entry.method, "download-archive");
+      assert_eq!(entry.url, url.to_string());
+      assert_eq!(entry.hash, sha256(b"archive content"));
+      assert_eq!(fs::read(&entry.file).unwrap(), b"archive content");
+    }
+  }
+
+  mod parse_manifest {
+    use super::super::parse_manifest;
+    use crate::applications;
+    use std::fs;
+
+    #[test]
+    fn hash_mismatch() {
+      let apps = applications::all();
+      let tempdir = tempfile::tempdir().unwrap();
+      fs::write(tempdir.path().join("0000"), b"tampered").unwrap();
+      let manifest = r#"{"artifacts":[{"app":"shellcheck","version":"0.10.0","platform":"linux/arm64","method":"download-archive","source":"s","url":"u","sha256":"0000","file":"artifacts/0000"}]}"#;
+      let have = parse_manifest(manifest, tempdir.path(), &apps);
+      assert!(have.unwrap_err().starts_with("artifacts/0000 has SHA-256"));
+    }
+
+    #[test]
+    fn missing_file() {
+      let apps = applications::all();
+      let tempdir = tempfile::tempdir().unwrap();
+      let manifest = r#"{"artifacts":[{"app":"shellcheck","version":"0.10.0","platform":"linux/arm64","method":"download-archive","source":"s","url":"u","sha256":"0000","file":"artifacts/0000"}]}"#;
+      let have = parse_manifest(manifest, tempdir.path(), &apps);
+      assert_eq!(have, Err("artifacts/0000 is missing".to_string()));
+    }
+
+    #[test]
+    fn file_outside_artifacts() {
+      let apps = applications::all();
+      let tempdir = tempfile::tempdir().unwrap();
+      let manifest = r#"{"artifacts":[{"app":"shellcheck","version":"0.10.0","platform":"linux/arm64","method":"download-archive","source":"s","url":"u","sha256":"0000","file":"../0000"}]}"#;
+      let have = parse_manifest(manifest, tempdir.path(), &apps);
+      assert_eq!(have, Err("../0000 is missing".to_string()));
+    }
+  }
+}
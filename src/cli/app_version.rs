@@ -26,13 +26,33 @@ impl PartialEq for AppVersion<'_> {
 
 impl<'a> AppVersion<'a> {
   pub fn parse<S: AsRef<str>>(token: S, apps: &'a Apps) -> Result<Self> {
-    let (app_name, version) = token.as_ref().split_once('@').unwrap_or((token.as_ref(), ""));
+    let (app_name, version) = split(token.as_ref());
     let app = apps.lookup(app_name)?;
     let version = if version.is_empty() { None } else { Some(Version::from(version)) };
     Ok(AppVersion { app, version })
   }
 }
 
+/// provides the name of the app that the given CLI argument might request,
+/// e.g. "github:org/repo" for "github:org/repo@1.2.3" or "--include=github:org/repo"
+pub fn app_name(arg: &str) -> &str {
+  let token = match arg.split_once('=') {
+    Some((key, value)) if key.starts_with("--") => value,
+    _ => arg,
+  };
+  split(token).0
+}
+
+/// splits the given "app@version" token into the app name and the (possibly empty) version
+fn split(token: &str) -> (&str, &str) {
+  // names of scoped npm packages like "npm:@scope/package" start with "@"
+  let name_start = token.find(":@").map_or(0, |pos| pos + 2);
+  match token[name_start..].find('@') {
+    Some(pos) => (&token[..name_start + pos], &token[name_start + pos + 1..]),
+    None => (token, ""),
+  }
+}
+
 #[cfg(test)]
 mod tests {
   mod parse {
@@ -78,5 +98,49 @@ mod tests {
       });
       pretty::assert_eq!(have, want);
     }
+
+    #[test]
+    fn scoped_npm_package() {
+      let give = "npm:@biomejs/biome@1.9.4";
+      let mut apps = applications::all();
+      apps.add_adhoc_apps(["npm:@biomejs/biome"]);
+      let have = AppVersion::parse(give, &apps).unwrap();
+      assert_eq!(have.app.name(), "npm:@biomejs/biome");
+      assert_eq!(have.version, Some(Version::from("1.9.4")));
+    }
+
+    #[test]
+    fn scoped_npm_package_without_version() {
+      let give = "npm:@biomejs/biome";
+      let mut apps = applications::all();
+      apps.add_adhoc_apps(["npm:@biomejs/biome"]);
+      let have = AppVersion::parse(give, &apps).unwrap();
+      assert_eq!(have.app.name(), "npm:@biomejs/biome");
+      assert_eq!(have.version, None);
+    }
+  }
+
+  mod app_name {
+    use super::super::app_name;
+
+    #[test]
+    fn with_version() {
+      assert_eq!(app_name("github:org/repo@v1.2.3"), "github:org/repo");
+    }
+
+    #[test]
+    fn scoped_npm_package() {
+      assert_eq!(app_name("npm:@biomejs/biome@1.9.4"), "npm:@biomejs/biome");
+    }
+
+    #[test]
+    fn flag_value() {
+      assert_eq!(app_name("--include=go:golang.org/x/tools/cmd/stringer"), "go:golang.org/x/tools/cmd/stringer");
+    }
+
+    #[test]
+    fn flag() {
+      assert_eq!(app_name("--verbose"), "--verbose");
+    }
   }
 }
@@ -1,20 +1,32 @@
-use crate::commands::{AddArgs, AvailableArgs, InstallArgs, RunArgs, TestArgs, UpdateArgs, VersionsArgs, WhichArgs};
+use crate::commands::{
+  AddArgs, AvailableArgs, BundleArgs, EnvArgs, GcArgs, ImportArgs, InstallAllArgs, InstallArgs, InstalledArgs, OutdatedArgs, RunArgs, ShimsArgs, TestArgs,
+  UpdateArgs, VersionsArgs, WhichArgs,
+};
 
 /// the main commands that run-this-app can execute
 #[derive(Debug, PartialEq)]
 pub enum Cli {
   Add(AddArgs),
+  AppsJson,
   AppsLong,
   AppsShort,
   Available(AvailableArgs),
+  Bundle(BundleArgs),
   DisplayHelp,
+  Env(EnvArgs),
+  Gc(GcArgs),
+  Import(ImportArgs),
   Install(InstallArgs),
-  InstallAll,
+  InstallAll(InstallAllArgs),
+  Installed(InstalledArgs),
+  Outdated(OutdatedArgs),
   Reinstall(InstallArgs),
   RunApp(RunArgs),
+  Shims(ShimsArgs),
   Test(TestArgs),
   Update(UpdateArgs),
   Version,
   Versions(VersionsArgs),
   Which(WhichArgs),
+  WhichConfig,
 }
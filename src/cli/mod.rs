@@ -6,6 +6,7 @@ mod exit;
 mod parse;
 
 use app_version::AppVersion;
+pub use app_version::app_name;
 pub use command::Cli;
 pub use exit::exit;
 pub use parse::parse;
@@ -1,7 +1,13 @@
 use super::{AppVersion, Cli};
 use crate::applications::{ApplicationName, Apps};
-use crate::commands::{AddArgs, AvailableArgs, InstallArgs, RunArgs, TestArgs, UpdateArgs, VersionsArgs, WhichArgs};
+use crate::commands::{
+  AddArgs, AvailableArgs, BundleArgs, EnvArgs, GcArgs, ImportArgs, InstallAllArgs, InstallArgs, InstalledArgs, OutdatedArgs, ReleaseNotesOutput, RunArgs,
+  Shell, ShimsArgs, TestArgs, UpdateArgs, UpdatePolicy, VersionsArgs, WhichArgs,
+};
 use crate::error::{Result, UserError};
+use crate::platform::Platform;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 
 #[allow(clippy::too_many_lines)]
 pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli> {
@@ -12,60 +18,182 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
   let mut from_source = false;
   let mut include_apps: Vec<ApplicationName> = vec![];
   let mut which = false;
+  let mut which_config = false;
   let mut add = false;
+  let mut apps_long = false;
+  let mut apps_short = false;
+  let mut import = false;
   let mut install = false;
+  let mut install_all = false;
   let mut reinstall = false;
   let mut test = false;
   let mut indicate_available = false;
   let mut update = false;
+  let mut update_apps: Vec<ApplicationName> = vec![];
+  let mut update_policy: Option<UpdatePolicy> = None;
   let mut optional = false;
+  let mut offline = false;
+  let mut refresh = false;
+  let mut release_notes: Option<ReleaseNotesOutput> = None;
   let mut versions: Option<usize> = None;
+  let mut jobs: Option<NonZeroUsize> = None;
+  let mut bundle = false;
+  let mut bundle_files: Vec<PathBuf> = vec![];
+  let mut env = false;
+  let mut env_shell: Option<Shell> = None;
+  let mut gc = false;
+  let mut gc_dirs: Vec<PathBuf> = vec![];
+  let mut unused_days: Option<u64> = None;
+  let mut dry_run = false;
+  let mut installed = false;
+  let mut outdated = false;
+  let mut shims = false;
+  let mut shims_dirs: Vec<PathBuf> = vec![];
+  let mut json = false;
+  let mut platform: Option<Platform> = None;
+  let mut to: Option<PathBuf> = None;
+  // whether the previous argument was "--jobs", i.e. this argument contains its value
+  let mut jobs_value_follows = false;
+  // whether the previous argument was "--platform", i.e. this argument contains its value
+  let mut platform_value_follows = false;
+  // whether the previous argument was "--to", i.e. this argument contains its value
+  let mut to_value_follows = false;
   for arg in cli_args {
+    if jobs_value_follows {
+      jobs = Some(parse_jobs(&arg)?);
+      jobs_value_follows = false;
+      continue;
+    }
+    if platform_value_follows {
+      platform = Some(Platform::parse(&arg)?);
+      platform_value_follows = false;
+      continue;
+    }
+    if to_value_follows {
+      to = Some(PathBuf::from(arg));
+      to_value_follows = false;
+      continue;
+    }
     if app_version.is_none() {
       if &arg == "--add" {
         add = true;
         continue;
       }
       if &arg == "--apps" {
-        return Ok(Cli::AppsLong);
+        apps_long = true;
+        continue;
       }
       if &arg == "-a" {
-        return Ok(Cli::AppsShort);
+        apps_short = true;
+        continue;
       }
       if &arg == "--available" {
         indicate_available = true;
         continue;
       }
+      if &arg == "--bundle" {
+        bundle = true;
+        continue;
+      }
       if &arg == "--from-source" {
         from_source = true;
         continue;
       }
+      if &arg == "--gc" {
+        gc = true;
+        continue;
+      }
       if &arg == "--help" || &arg == "-h" {
         return Ok(Cli::DisplayHelp);
       }
+      if &arg == "--dry-run" {
+        dry_run = true;
+        continue;
+      }
+      if &arg == "--env" {
+        env = true;
+        continue;
+      }
       if &arg == "--error-on-output" {
         error_on_output = true;
         continue;
       }
+      if &arg == "--import" {
+        import = true;
+        continue;
+      }
       if &arg == "--install" {
         install = true;
         continue;
       }
       if &arg == "--install-all" {
-        return Ok(Cli::InstallAll);
+        install_all = true;
+        continue;
+      }
+      if &arg == "--installed" {
+        installed = true;
+        continue;
+      }
+      if &arg == "--jobs" {
+        jobs_value_follows = true;
+        continue;
+      }
+      if &arg == "--json" {
+        json = true;
+        continue;
+      }
+      if &arg == "--major" {
+        set_update_policy(&mut update_policy, UpdatePolicy::Major)?;
+        continue;
+      }
+      if &arg == "--minor" {
+        set_update_policy(&mut update_policy, UpdatePolicy::Minor)?;
+        continue;
+      }
+      if &arg == "--offline" {
+        offline = true;
+        continue;
+      }
+      if &arg == "--outdated" {
+        outdated = true;
+        continue;
       }
       if &arg == "--optional" {
         optional = true;
         continue;
       }
+      if &arg == "--patch" {
+        set_update_policy(&mut update_policy, UpdatePolicy::Patch)?;
+        continue;
+      }
+      if &arg == "--platform" {
+        platform_value_follows = true;
+        continue;
+      }
+      if &arg == "--refresh" {
+        refresh = true;
+        continue;
+      }
+      if &arg == "--release-notes" {
+        release_notes = Some(ReleaseNotesOutput::Print);
+        continue;
+      }
       if &arg == "--reinstall" {
         reinstall = true;
         continue;
       }
+      if &arg == "--shims" {
+        shims = true;
+        continue;
+      }
       if &arg == "--test" {
         test = true;
         continue;
       }
+      if &arg == "--to" {
+        to_value_follows = true;
+        continue;
+      }
       if &arg == "--update" {
         update = true;
         continue;
@@ -85,6 +213,10 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
         which = true;
         continue;
       }
+      if &arg == "--which-config" {
+        which_config = true;
+        continue;
+      }
       if arg.starts_with('-') {
         let (key, value) = arg.split_once('=').unwrap_or((&arg, ""));
         if key == "--include" {
@@ -92,6 +224,26 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
           include_apps.push(app.name());
           continue;
         }
+        if key == "--jobs" {
+          jobs = Some(parse_jobs(value)?);
+          continue;
+        }
+        if key == "--platform" {
+          platform = Some(Platform::parse(value)?);
+          continue;
+        }
+        if key == "--release-notes" {
+          release_notes = Some(ReleaseNotesOutput::File(PathBuf::from(value)));
+          continue;
+        }
+        if key == "--to" {
+          to = Some(PathBuf::from(value));
+          continue;
+        }
+        if key == "--unused-days" {
+          unused_days = Some(value.parse().map_err(|_| UserError::InvalidNumber)?);
+          continue;
+        }
         if key == "--versions" {
           versions = Some(value.parse().map_err(|_| UserError::InvalidNumber)?);
           continue;
@@ -99,20 +251,141 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
         return Err(UserError::UnknownCliOption(arg));
       }
     }
+    if bundle || import {
+      bundle_files.push(PathBuf::from(arg));
+      continue;
+    }
+    if env {
+      if env_shell.is_some() {
+        return Err(UserError::UnknownCliOption(arg));
+      }
+      env_shell = Some(Shell::parse(&arg)?);
+      continue;
+    }
+    if gc {
+      gc_dirs.push(PathBuf::from(arg));
+      continue;
+    }
+    if shims {
+      shims_dirs.push(PathBuf::from(arg));
+      continue;
+    }
+    if update {
+      update_apps.push(apps.lookup(&arg)?.name());
+      continue;
+    }
     if app_version.is_none() {
       app_version = Some(AppVersion::parse(arg, apps)?);
     } else {
       app_args.push(arg);
     }
   }
-  if multiple_true(&[which, indicate_available, install, reinstall, test, update, versions.is_some()]) {
+  if jobs_value_follows {
+    return Err(UserError::InvalidNumber);
+  }
+  if platform_value_follows {
+    return Err(UserError::UnknownPlatform(String::new()));
+  }
+  if to_value_follows {
+    return Err(UserError::MissingFolder);
+  }
+  if apps_long || apps_short {
+    if json {
+      return Ok(Cli::AppsJson);
+    }
+    return Ok(if apps_long { Cli::AppsLong } else { Cli::AppsShort });
+  }
+  if multiple_true(&[
+    which,
+    indicate_available,
+    bundle,
+    env,
+    import,
+    install,
+    install_all,
+    reinstall,
+    test,
+    update,
+    versions.is_some(),
+    gc,
+    installed,
+    outdated,
+    shims,
+    which_config,
+  ]) {
     return Err(UserError::MultipleCommandsGiven);
   }
+  if json && !(which || indicate_available || installed || outdated || update || test || versions.is_some()) {
+    return Err(UserError::JsonNotSupported);
+  }
+  if bundle || import {
+    let Ok([file]) = <[PathBuf; 1]>::try_from(bundle_files) else {
+      return Err(UserError::BundleNeedsOneFile);
+    };
+    if bundle {
+      return Ok(Cli::Bundle(BundleArgs { file, platform, verbose }));
+    }
+    return Ok(Cli::Import(ImportArgs { file, platform, verbose }));
+  }
+  if env {
+    return Ok(Cli::Env(EnvArgs {
+      shell: env_shell,
+      offline,
+      verbose,
+    }));
+  }
+  if gc {
+    return Ok(Cli::Gc(GcArgs {
+      project_dirs: gc_dirs,
+      unused_days,
+      dry_run,
+      verbose,
+    }));
+  }
+  if shims {
+    let Ok([folder]) = <[PathBuf; 1]>::try_from(shims_dirs) else {
+      return Err(UserError::ShimsNeedOneFolder);
+    };
+    return Ok(Cli::Shims(ShimsArgs { folder, verbose }));
+  }
+  if which_config {
+    return Ok(Cli::WhichConfig);
+  }
+  if installed {
+    return Ok(Cli::Installed(InstalledArgs { json }));
+  }
+  if outdated {
+    return Ok(Cli::Outdated(OutdatedArgs {
+      json,
+      offline,
+      refresh,
+      verbose,
+    }));
+  }
+  if install_all {
+    return Ok(Cli::InstallAll(InstallAllArgs {
+      jobs,
+      offline,
+      platform,
+      to,
+      verbose,
+    }));
+  }
   if update {
-    return Ok(Cli::Update(UpdateArgs { verbose }));
+    return Ok(Cli::Update(UpdateArgs {
+      apps: update_apps,
+      dry_run,
+      json,
+      offline,
+      policy: update_policy.unwrap_or_default(),
+      refresh,
+      release_notes,
+      verbose,
+    }));
   }
   if test {
     return Ok(Cli::Test(TestArgs {
+      json,
       optional,
       start_at_app: app_version.map(|av| av.app.name()),
       verbose,
@@ -121,10 +394,21 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
   if let Some(AppVersion { app, version }) = app_version {
     let app_name = app.name();
     if add {
-      return Ok(Cli::Add(AddArgs { app_name, verbose }));
+      return Ok(Cli::Add(AddArgs {
+        app_name,
+        offline,
+        refresh,
+        verbose,
+      }));
     }
     if indicate_available {
-      return Ok(Cli::Available(AvailableArgs { app_name, optional, verbose }));
+      return Ok(Cli::Available(AvailableArgs {
+        app_name,
+        json,
+        optional,
+        offline,
+        verbose,
+      }));
     }
     if install {
       return Ok(Cli::Install(InstallArgs {
@@ -133,6 +417,9 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
         from_source,
         include_apps,
         optional,
+        offline,
+        platform,
+        to,
         verbose,
       }));
     }
@@ -143,19 +430,31 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
         from_source,
         include_apps,
         optional,
+        offline,
+        platform,
+        to,
         verbose,
       }));
     }
     if which {
       return Ok(Cli::Which(WhichArgs {
         app_name,
+        json,
         optional,
         version,
+        offline,
         verbose,
       }));
     }
     if let Some(amount) = versions {
-      return Ok(Cli::Versions(VersionsArgs { app_name, amount, verbose }));
+      return Ok(Cli::Versions(VersionsArgs {
+        app_name,
+        amount,
+        json,
+        offline,
+        refresh,
+        verbose,
+      }));
     }
     return Ok(Cli::RunApp(RunArgs {
       app_name,
@@ -165,6 +464,7 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
       from_source,
       include_apps,
       optional,
+      offline,
       verbose,
       cwd: None,
     }));
@@ -175,101 +475,755 @@ pub fn parse(cli_args: impl Iterator<Item = String>, apps: &Apps) -> Result<Cli>
   Ok(Cli::DisplayHelp)
 }
 
-/// indicates whether the given values contain two or more true values
-fn multiple_true(values: &[bool]) -> bool {
-  values.iter().filter(|&&value| value).count() >= 2
-}
+/// parses the number of parallel installations given via "--jobs"
+fn parse_jobs(value: &str) -> Result<NonZeroUsize> {
+  value.parse().map_err(|_| UserError::InvalidNumber)
+}
+
+/// stores the given update policy, only one of them is allowed
+fn set_update_policy(update_policy: &mut Option<UpdatePolicy>, value: UpdatePolicy) -> Result<()> {
+  if update_policy.is_some_and(|existing| existing != value) {
+    return Err(UserError::MultipleUpdatePolicies);
+  }
+  *update_policy = Some(value);
+  Ok(())
+}
+
+/// indicates whether the given values contain two or more true values
+fn multiple_true(values: &[bool]) -> bool {
+  values.iter().filter(|&&value| value).count() >= 2
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse {
+    use crate::applications;
+    use crate::cli::{Cli, parse};
+
+    #[test]
+    fn no_arguments() {
+      let apps = applications::all();
+      let args = vec![].into_iter();
+      let have = parse(args, &apps);
+      let want = Ok(Cli::DisplayHelp);
+      pretty::assert_eq!(have, want);
+    }
+
+    mod rta_arguments {
+      use crate::applications;
+      use crate::cli::{Cli, parse};
+      use crate::commands::RunArgs;
+      use crate::configuration::Version;
+      use crate::error::UserError;
+      use big_s::S;
+
+      mod apps {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use big_s::S;
+
+        #[test]
+        fn long() {
+          let apps = applications::all();
+          let args = vec![S("--apps")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Ok(Cli::AppsLong));
+        }
+
+        #[test]
+        fn short() {
+          let apps = applications::all();
+          let args = vec![S("-a")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Ok(Cli::AppsShort));
+        }
+
+        #[test]
+        fn json_after() {
+          let apps = applications::all();
+          let args = vec![S("--apps"), S("--json")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Ok(Cli::AppsJson));
+        }
+
+        #[test]
+        fn json_before() {
+          let apps = applications::all();
+          let args = vec![S("--json"), S("-a")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Ok(Cli::AppsJson));
+        }
+      }
+
+      mod available {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::AvailableArgs;
+        use crate::error::UserError;
+        use big_s::S;
+
+        #[test]
+        fn with_app() {
+          let apps = applications::all();
+          let shellcheck = apps.lookup("shellcheck").unwrap();
+          let args = vec![S("--available"), S("shellcheck")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Available(AvailableArgs {
+            app_name: shellcheck.name(),
+            json: false,
+            optional: false,
+            offline: false,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn with_all_options() {
+          let apps = applications::all();
+          let shellcheck = apps.lookup("shellcheck").unwrap();
+          let args = vec![S("--available"), S("--verbose"), S("shellcheck")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Available(AvailableArgs {
+            app_name: shellcheck.name(),
+            json: false,
+            optional: false,
+            offline: false,
+            verbose: true,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn without_app() {
+          let apps = applications::all();
+          let args = vec![S("--available")].into_iter();
+          let have = parse(args, &apps);
+          let want = Err(UserError::MissingApplication);
+          pretty::assert_eq!(have, want);
+        }
+      }
+
+      mod error_on_output {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::RunArgs;
+        use crate::error::UserError;
+        use big_s::S;
+
+        #[test]
+        fn normal() {
+          let apps = applications::all();
+          let actionlint = apps.lookup("actionlint").unwrap();
+          let args = vec![S("--error-on-output"), S("actionlint")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::RunApp(RunArgs {
+            app_name: actionlint.name(),
+            version: None,
+            app_args: vec![],
+            error_on_output: true,
+            from_source: false,
+            include_apps: vec![],
+            optional: false,
+            offline: false,
+            verbose: false,
+            cwd: None,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn missing_app() {
+          let apps = applications::all();
+          let args = vec![S("--error-on-output")].into_iter();
+          let have = parse(args, &apps);
+          let want = Err(UserError::MissingApplication);
+          pretty::assert_eq!(have, want);
+        }
+      }
+
+      mod install {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::InstallArgs;
+        use crate::error::UserError;
+        use big_s::S;
+        use std::path::PathBuf;
+
+        #[test]
+        fn normal() {
+          let apps = applications::all();
+          let actionlint = apps.lookup("actionlint").unwrap();
+          let args = vec![S("--install"), S("actionlint")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Install(InstallArgs {
+            app_name: actionlint.name(),
+            version: None,
+            from_source: false,
+            include_apps: vec![],
+            optional: false,
+            offline: false,
+            platform: None,
+            to: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn to() {
+          let apps = applications::all();
+          let shellcheck = apps.lookup("shellcheck").unwrap();
+          let args = vec![S("--install"), S("--to=bin"), S("shellcheck")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Install(InstallArgs {
+            app_name: shellcheck.name(),
+            version: None,
+            from_source: false,
+            include_apps: vec![],
+            optional: false,
+            offline: false,
+            platform: None,
+            to: Some(PathBuf::from("bin")),
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn missing_app() {
+          let apps = applications::all();
+          let args = vec![S("--install")].into_iter();
+          let have = parse(args, &apps);
+          let want = Err(UserError::MissingApplication);
+          pretty::assert_eq!(have, want);
+        }
+      }
+
+      mod from_source {
+        use crate::cli::parse;
+        use crate::commands::RunArgs;
+        use crate::{Cli, applications};
+        use big_s::S;
+
+        #[test]
+        fn flag() {
+          let apps = applications::all();
+          let args = vec![S("--from-source"), S("actionlint")].into_iter();
+          let have = parse(args, &apps);
+          let actionlint = apps.lookup("actionlint").unwrap();
+          let want = Ok(Cli::RunApp(RunArgs {
+            app_name: actionlint.name(),
+            version: None,
+            app_args: vec![],
+            error_on_output: false,
+            from_source: true,
+            include_apps: vec![],
+            optional: false,
+            offline: false,
+            verbose: false,
+            cwd: None,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+      }
+
+      mod outdated {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::OutdatedArgs;
+        use big_s::S;
+
+        #[test]
+        fn json() {
+          let apps = applications::all();
+          let args = vec![S("--outdated"), S("--json")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Outdated(OutdatedArgs {
+            json: true,
+            offline: false,
+            refresh: false,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+      }
+
+      mod update {
+        use crate::cli::{Cli, parse};
+        use crate::commands::{ReleaseNotesOutput, UpdateArgs, UpdatePolicy};
+        use crate::{UserError, applications};
+        use big_s::S;
+        use std::path::PathBuf;
+
+        #[test]
+        fn policy_apps_and_dry_run() {
+          let apps = applications::all();
+          let args = vec![S("--update"), S("--minor"), S("--dry-run"), S("actionlint"), S("shellcheck")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Update(UpdateArgs {
+            apps: vec![apps.lookup("actionlint").unwrap().name(), apps.lookup("shellcheck").unwrap().name()],
+            dry_run: true,
+            json: false,
+            offline: false,
+            policy: UpdatePolicy::Minor,
+            refresh: false,
+            release_notes: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn release_notes() {
+          let apps = applications::all();
+          let args = vec![S("--update"), S("--release-notes")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Update(UpdateArgs {
+            apps: vec![],
+            dry_run: false,
+            json: false,
+            offline: false,
+            policy: UpdatePolicy::Major,
+            refresh: false,
+            release_notes: Some(ReleaseNotesOutput::Print),
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn release_notes_file() {
+          let apps = applications::all();
+          let args = vec![S("--update"), S("--release-notes=CHANGES.md")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Update(UpdateArgs {
+            apps: vec![],
+            dry_run: false,
+            json: false,
+            offline: false,
+            policy: UpdatePolicy::Major,
+            refresh: false,
+            release_notes: Some(ReleaseNotesOutput::File(PathBuf::from("CHANGES.md"))),
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn multiple_policies() {
+          let apps = applications::all();
+          let args = vec![S("--update"), S("--patch"), S("--major")].into_iter();
+          let have = parse(args, &apps);
+          assert_eq!(have, Err(UserError::MultipleUpdatePolicies));
+        }
+
+        #[test]
+        fn unknown_app() {
+          let apps = applications::all();
+          let args = vec![S("--update"), S("zonk")].into_iter();
+          let have = parse(args, &apps);
+          assert!(have.is_err());
+        }
+      }
+
+      mod bundle {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::{BundleArgs, ImportArgs};
+        use crate::error::UserError;
+        use crate::platform::{Cpu, Os, Platform};
+        use big_s::S;
+        use std::path::PathBuf;
+
+        #[test]
+        fn bundle() {
+          let apps = applications::all();
+          let args = vec![S("--bundle"), S("--platform=linux/arm64"), S("tools.tar.gz")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Bundle(BundleArgs {
+            file: PathBuf::from("tools.tar.gz"),
+            platform: Some(Platform {
+              os: Os::Linux,
+              cpu: Cpu::Arm64,
+            }),
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn import() {
+          let apps = applications::all();
+          let args = vec![S("--import"), S("tools.tar.gz")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Import(ImportArgs {
+            file: PathBuf::from("tools.tar.gz"),
+            platform: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn missing_file() {
+          let apps = applications::all();
+          let args = vec![S("--bundle")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::BundleNeedsOneFile));
+        }
+
+        #[test]
+        fn bundle_and_import() {
+          let apps = applications::all();
+          let args = vec![S("--bundle"), S("--import"), S("tools.tar.gz")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::MultipleCommandsGiven));
+        }
+      }
+
+      mod env {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::{EnvArgs, Shell};
+        use crate::error::UserError;
+        use big_s::S;
+
+        #[test]
+        fn shell() {
+          let apps = applications::all();
+          let args = vec![S("--env"), S("fish")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Env(EnvArgs {
+            shell: Some(Shell::Fish),
+            offline: false,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn no_shell() {
+          let apps = applications::all();
+          let args = vec![S("--env"), S("--offline")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Env(EnvArgs {
+            shell: None,
+            offline: true,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn unknown_shell() {
+          let apps = applications::all();
+          let args = vec![S("--env"), S("tcsh")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::UnknownShell(S("tcsh"))));
+        }
+      }
+
+      mod gc {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::GcArgs;
+        use crate::error::UserError;
+        use big_s::S;
+        use std::path::PathBuf;
+
+        #[test]
+        fn project_dirs() {
+          let apps = applications::all();
+          let args = vec![S("--gc"), S("--dry-run"), S("../project-1"), S("/home/user/project-2")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Gc(GcArgs {
+            project_dirs: vec![PathBuf::from("../project-1"), PathBuf::from("/home/user/project-2")],
+            unused_days: None,
+            dry_run: true,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn unused_days() {
+          let apps = applications::all();
+          let args = vec![S("--gc"), S("--unused-days=30")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Gc(GcArgs {
+            project_dirs: vec![],
+            unused_days: Some(30),
+            dry_run: false,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn invalid_unused_days() {
+          let apps = applications::all();
+          let args = vec![S("--gc"), S("--unused-days=month")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::InvalidNumber));
+        }
 
-#[cfg(test)]
-mod tests {
+        #[test]
+        fn with_other_command() {
+          let apps = applications::all();
+          let args = vec![S("--gc"), S("--update")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::MultipleCommandsGiven));
+        }
+      }
 
-  mod parse {
-    use crate::applications;
-    use crate::cli::{Cli, parse};
+      mod shims {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::ShimsArgs;
+        use crate::error::UserError;
+        use big_s::S;
+        use std::path::PathBuf;
 
-    #[test]
-    fn no_arguments() {
-      let apps = applications::all();
-      let args = vec![].into_iter();
-      let have = parse(args, &apps);
-      let want = Ok(Cli::DisplayHelp);
-      pretty::assert_eq!(have, want);
-    }
+        #[test]
+        fn folder() {
+          let apps = applications::all();
+          let args = vec![S("--shims"), S(".bin")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Shims(ShimsArgs {
+            folder: PathBuf::from(".bin"),
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
 
-    mod rta_arguments {
-      use crate::applications;
-      use crate::cli::{Cli, parse};
-      use crate::commands::RunArgs;
-      use crate::configuration::Version;
-      use crate::error::UserError;
-      use big_s::S;
+        #[test]
+        fn no_folder() {
+          let apps = applications::all();
+          let args = vec![S("--shims")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::ShimsNeedOneFolder));
+        }
 
-      mod available {
+        #[test]
+        fn multiple_folders() {
+          let apps = applications::all();
+          let args = vec![S("--shims"), S(".bin"), S("bin")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::ShimsNeedOneFolder));
+        }
+      }
+
+      mod installed {
         use crate::applications;
         use crate::cli::{Cli, parse};
-        use crate::commands::AvailableArgs;
+        use crate::commands::InstalledArgs;
+        use big_s::S;
+
+        #[test]
+        fn normal() {
+          let apps = applications::all();
+          let args = vec![S("--installed")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Installed(InstalledArgs { json: false }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn json() {
+          let apps = applications::all();
+          let args = vec![S("--installed"), S("--json")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Installed(InstalledArgs { json: true }));
+          pretty::assert_eq!(have, want);
+        }
+      }
+
+      mod install_all {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::commands::InstallAllArgs;
         use crate::error::UserError;
+        use crate::platform::{Cpu, Os, Platform};
         use big_s::S;
+        use std::num::NonZeroUsize;
+        use std::path::PathBuf;
 
         #[test]
-        fn with_app() {
+        fn normal() {
           let apps = applications::all();
-          let shellcheck = apps.lookup("shellcheck").unwrap();
-          let args = vec![S("--available"), S("shellcheck")].into_iter();
+          let args = vec![S("--install-all")].into_iter();
           let have = parse(args, &apps);
-          let want = Ok(Cli::Available(AvailableArgs {
-            app_name: shellcheck.name(),
-            optional: false,
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: None,
+            offline: false,
+            platform: None,
+            to: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn jobs() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--jobs"), S("4")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: NonZeroUsize::new(4),
+            offline: false,
+            platform: None,
+            to: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn jobs_with_equals() {
+          let apps = applications::all();
+          let args = vec![S("--jobs=2"), S("--install-all")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: NonZeroUsize::new(2),
+            offline: false,
+            platform: None,
+            to: None,
             verbose: false,
           }));
           pretty::assert_eq!(have, want);
         }
 
+        #[test]
+        fn jobs_zero() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--jobs=0")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::InvalidNumber));
+        }
+
+        #[test]
+        fn jobs_missing_value() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--jobs")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::InvalidNumber));
+        }
+
         #[test]
         fn with_all_options() {
           let apps = applications::all();
-          let shellcheck = apps.lookup("shellcheck").unwrap();
-          let args = vec![S("--available"), S("--verbose"), S("shellcheck")].into_iter();
+          let args = vec![S("--install-all"), S("--jobs=3"), S("--offline"), S("--verbose")].into_iter();
           let have = parse(args, &apps);
-          let want = Ok(Cli::Available(AvailableArgs {
-            app_name: shellcheck.name(),
-            optional: false,
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: NonZeroUsize::new(3),
+            offline: true,
+            platform: None,
+            to: None,
             verbose: true,
           }));
           pretty::assert_eq!(have, want);
         }
 
         #[test]
-        fn without_app() {
+        fn to() {
           let apps = applications::all();
-          let args = vec![S("--available")].into_iter();
+          let args = vec![S("--install-all"), S("--to"), S("bin")].into_iter();
           let have = parse(args, &apps);
-          let want = Err(UserError::MissingApplication);
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: None,
+            offline: false,
+            platform: None,
+            to: Some(PathBuf::from("bin")),
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn platform() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--platform"), S("linux/arm64")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: None,
+            offline: false,
+            platform: Some(Platform {
+              os: Os::Linux,
+              cpu: Cpu::Arm64,
+            }),
+            to: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn platform_with_equals() {
+          let apps = applications::all();
+          let args = vec![S("--platform=windows/intel64"), S("--install-all")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::InstallAll(InstallAllArgs {
+            jobs: None,
+            offline: false,
+            platform: Some(Platform {
+              os: Os::Windows,
+              cpu: Cpu::Intel64,
+            }),
+            to: None,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
+        #[test]
+        fn unknown_platform() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--platform=linux/mips")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::UnknownPlatform(S("linux/mips"))));
+        }
+
+        #[test]
+        fn to_missing_value() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--to")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::MissingFolder));
+        }
+
+        #[test]
+        fn with_other_command() {
+          let apps = applications::all();
+          let args = vec![S("--install-all"), S("--update")].into_iter();
+          let have = parse(args, &apps);
+          let want = Err(UserError::MultipleCommandsGiven);
           pretty::assert_eq!(have, want);
         }
       }
 
-      mod error_on_output {
+      mod offline {
         use crate::applications;
         use crate::cli::{Cli, parse};
-        use crate::commands::RunArgs;
-        use crate::error::UserError;
+        use crate::commands::{InstallArgs, RunArgs};
         use big_s::S;
 
         #[test]
-        fn normal() {
+        fn run() {
           let apps = applications::all();
-          let actionlint = apps.lookup("actionlint").unwrap();
-          let args = vec![S("--error-on-output"), S("actionlint")].into_iter();
+          let args = vec![S("--offline"), S("actionlint"), S("--offline")].into_iter();
           let have = parse(args, &apps);
+          let actionlint = apps.lookup("actionlint").unwrap();
           let want = Ok(Cli::RunApp(RunArgs {
             app_name: actionlint.name(),
             version: None,
-            app_args: vec![],
-            error_on_output: true,
+            app_args: vec![S("--offline")],
+            error_on_output: false,
             from_source: false,
             include_apps: vec![],
             optional: false,
+            offline: true,
             verbose: false,
             cwd: None,
           }));
@@ -277,71 +1231,78 @@ mod tests {
         }
 
         #[test]
-        fn missing_app() {
+        fn install() {
           let apps = applications::all();
-          let args = vec![S("--error-on-output")].into_iter();
+          let args = vec![S("--install"), S("--offline"), S("actionlint")].into_iter();
           let have = parse(args, &apps);
-          let want = Err(UserError::MissingApplication);
+          let actionlint = apps.lookup("actionlint").unwrap();
+          let want = Ok(Cli::Install(InstallArgs {
+            app_name: actionlint.name(),
+            version: None,
+            from_source: false,
+            include_apps: vec![],
+            optional: false,
+            offline: true,
+            platform: None,
+            to: None,
+            verbose: false,
+          }));
           pretty::assert_eq!(have, want);
         }
       }
 
-      mod install {
+      mod refresh {
         use crate::applications;
         use crate::cli::{Cli, parse};
-        use crate::commands::InstallArgs;
-        use crate::error::UserError;
+        use crate::commands::{AddArgs, UpdateArgs, UpdatePolicy, VersionsArgs};
         use big_s::S;
 
         #[test]
-        fn normal() {
+        fn add() {
           let apps = applications::all();
-          let actionlint = apps.lookup("actionlint").unwrap();
-          let args = vec![S("--install"), S("actionlint")].into_iter();
+          let args = vec![S("--add"), S("--refresh"), S("actionlint")].into_iter();
           let have = parse(args, &apps);
-          let want = Ok(Cli::Install(InstallArgs {
+          let actionlint = apps.lookup("actionlint").unwrap();
+          let want = Ok(Cli::Add(AddArgs {
             app_name: actionlint.name(),
-            version: None,
-            from_source: false,
-            include_apps: vec![],
-            optional: false,
+            offline: false,
+            refresh: true,
             verbose: false,
           }));
           pretty::assert_eq!(have, want);
         }
 
         #[test]
-        fn missing_app() {
+        fn update() {
           let apps = applications::all();
-          let args = vec![S("--install")].into_iter();
+          let args = vec![S("--update"), S("--refresh")].into_iter();
           let have = parse(args, &apps);
-          let want = Err(UserError::MissingApplication);
+          let want = Ok(Cli::Update(UpdateArgs {
+            apps: vec![],
+            dry_run: false,
+            json: false,
+            offline: false,
+            policy: UpdatePolicy::Major,
+            refresh: true,
+            release_notes: None,
+            verbose: false,
+          }));
           pretty::assert_eq!(have, want);
         }
-      }
-
-      mod from_source {
-        use crate::cli::parse;
-        use crate::commands::RunArgs;
-        use crate::{Cli, applications};
-        use big_s::S;
 
         #[test]
-        fn flag() {
+        fn versions() {
           let apps = applications::all();
-          let args = vec![S("--from-source"), S("actionlint")].into_iter();
+          let args = vec![S("--refresh"), S("--versions=3"), S("actionlint")].into_iter();
           let have = parse(args, &apps);
           let actionlint = apps.lookup("actionlint").unwrap();
-          let want = Ok(Cli::RunApp(RunArgs {
+          let want = Ok(Cli::Versions(VersionsArgs {
             app_name: actionlint.name(),
-            version: None,
-            app_args: vec![],
-            error_on_output: false,
-            from_source: true,
-            include_apps: vec![],
-            optional: false,
+            amount: 3,
+            json: false,
+            offline: false,
+            refresh: true,
             verbose: false,
-            cwd: None,
           }));
           pretty::assert_eq!(have, want);
         }
@@ -366,6 +1327,9 @@ mod tests {
             from_source: false,
             include_apps: vec![],
             optional: false,
+            offline: false,
+            platform: None,
+            to: None,
             verbose: false,
           }));
           pretty::assert_eq!(have, want);
@@ -393,6 +1357,7 @@ mod tests {
           let args = vec![S("--test")].into_iter();
           let have = parse(args, &apps);
           let want = Ok(Cli::Test(TestArgs {
+            json: false,
             optional: false,
             start_at_app: None,
             verbose: false,
@@ -406,6 +1371,7 @@ mod tests {
           let args = vec![S("--test"), S("--verbose")].into_iter();
           let have = parse(args, &apps);
           let want = Ok(Cli::Test(TestArgs {
+            json: false,
             optional: false,
             start_at_app: None,
             verbose: true,
@@ -420,6 +1386,7 @@ mod tests {
           let args = vec![S("--test"), S("actionlint")].into_iter();
           let have = parse(args, &apps);
           let want = Ok(Cli::Test(TestArgs {
+            json: false,
             optional: false,
             start_at_app: Some(actionlint.name()),
             verbose: false,
@@ -434,6 +1401,7 @@ mod tests {
           let args = vec![S("--test"), S("--verbose"), S("actionlint")].into_iter();
           let have = parse(args, &apps);
           let want = Ok(Cli::Test(TestArgs {
+            json: false,
             optional: false,
             start_at_app: Some(actionlint.name()),
             verbose: true,
@@ -488,6 +1456,7 @@ mod tests {
             from_source: false,
             include_apps: vec![gh.name()],
             optional: false,
+            offline: false,
             verbose: false,
             cwd: None,
           }));
@@ -526,6 +1495,7 @@ mod tests {
             from_source: false,
             include_apps: vec![],
             optional: false,
+            offline: false,
             verbose: true,
             cwd: None,
           }));
@@ -546,6 +1516,7 @@ mod tests {
             from_source: false,
             include_apps: vec![],
             optional: false,
+            offline: false,
             verbose: true,
             cwd: None,
           }));
@@ -585,6 +1556,7 @@ mod tests {
           from_source: false,
           include_apps: vec![],
           optional: true,
+          offline: false,
           verbose: false,
           cwd: None,
         }));
@@ -630,6 +1602,9 @@ mod tests {
           let want = Ok(Cli::Versions(VersionsArgs {
             app_name: actionlint.name(),
             amount: 10,
+            json: false,
+            offline: false,
+            refresh: false,
             verbose: false,
           }));
           pretty::assert_eq!(have, want);
@@ -644,6 +1619,9 @@ mod tests {
           let want = Ok(Cli::Versions(VersionsArgs {
             app_name: actionlint.name(),
             amount: 20,
+            json: false,
+            offline: false,
+            refresh: false,
             verbose: false,
           }));
           pretty::assert_eq!(have, want);
@@ -674,8 +1652,10 @@ mod tests {
           let have = parse(args, &apps);
           let want = Ok(Cli::Which(WhichArgs {
             app_name: shellcheck.name(),
+            json: false,
             optional: false,
             version: None,
+            offline: false,
             verbose: false,
           }));
           pretty::assert_eq!(have, want);
@@ -689,13 +1669,32 @@ mod tests {
           let have = parse(args, &apps);
           let want = Ok(Cli::Which(WhichArgs {
             app_name: shellcheck.name(),
+            json: false,
             optional: false,
             version: None,
+            offline: false,
             verbose: true,
           }));
           pretty::assert_eq!(have, want);
         }
 
+        #[test]
+        fn json() {
+          let apps = applications::all();
+          let shellcheck = apps.lookup("shellcheck").unwrap();
+          let args = vec![S("--json"), S("--which"), S("shellcheck")].into_iter();
+          let have = parse(args, &apps);
+          let want = Ok(Cli::Which(WhichArgs {
+            app_name: shellcheck.name(),
+            json: true,
+            optional: false,
+            version: None,
+            offline: false,
+            verbose: false,
+          }));
+          pretty::assert_eq!(have, want);
+        }
+
         #[test]
         fn without_app() {
           let apps = applications::all();
@@ -705,6 +1704,52 @@ mod tests {
           pretty::assert_eq!(have, want);
         }
       }
+
+      mod which_config {
+        use crate::applications;
+        use crate::cli::{Cli, parse};
+        use crate::error::UserError;
+        use big_s::S;
+
+        #[test]
+        fn normal() {
+          let apps = applications::all();
+          let args = vec![S("--which-config")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Ok(Cli::WhichConfig));
+        }
+
+        #[test]
+        fn with_other_command() {
+          let apps = applications::all();
+          let args = vec![S("--which-config"), S("--installed")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::MultipleCommandsGiven));
+        }
+      }
+
+      mod json {
+        use crate::applications;
+        use crate::cli::parse;
+        use crate::error::UserError;
+        use big_s::S;
+
+        #[test]
+        fn non_informational_command() {
+          let apps = applications::all();
+          let args = vec![S("--json"), S("--install-all")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::JsonNotSupported));
+        }
+
+        #[test]
+        fn running_an_app() {
+          let apps = applications::all();
+          let args = vec![S("--json"), S("shellcheck"), S("--version")].into_iter();
+          let have = parse(args, &apps);
+          pretty::assert_eq!(have, Err(UserError::JsonNotSupported));
+        }
+      }
     }
 
     mod application_arguments {
@@ -728,6 +1773,7 @@ mod tests {
           from_source: false,
           include_apps: vec![],
           optional: false,
+          offline: false,
           verbose: false,
           cwd: None,
         }));
@@ -748,6 +1794,7 @@ mod tests {
           from_source: false,
           include_apps: vec![],
           optional: false,
+          offline: false,
           verbose: false,
           cwd: None,
         }));
@@ -776,6 +1823,7 @@ mod tests {
           from_source: false,
           include_apps: vec![],
           optional: false,
+          offline: false,
           verbose: true,
           cwd: None,
         }));
@@ -796,6 +1844,7 @@ mod tests {
           from_source: false,
           include_apps: vec![],
           optional: false,
+          offline: false,
           verbose: false,
           cwd: None,
         }));
@@ -1,18 +1,25 @@
 use crate::applications::{ApplicationName, Apps};
 use crate::error::Result;
-use crate::{configuration, logging};
+use crate::yard::{self, VersionsCache, Yard};
+use crate::{configuration, context, logging};
+use std::path::PathBuf;
 use std::process::ExitCode;
 
 pub fn add(args: AddArgs, apps: &Apps) -> Result<ExitCode> {
   let log = logging::new(args.verbose);
   let app = apps.lookup(args.app_name)?;
-  let version = app.latest_installable_version(log)?;
-  if let Some(config_file) = configuration::File::read(apps)? {
+  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let cache = VersionsCache::new(&yard, args.refresh, context::offline(args.offline))?;
+  let version = cache.latest_installable_version(app, log)?;
+  let path = if let Some(config_file) = configuration::File::read(apps)? {
+    let path = config_file.path.clone().unwrap_or_else(|| PathBuf::from(configuration::FILE_NAME));
     config_file.add(app.name(), version.clone())?;
+    path
   } else {
     configuration::File::create(&app.name(), &version.clone())?;
-  }
-  eprintln!("added {}@{} to {}", app.name(), version, configuration::FILE_NAME);
+    PathBuf::from(configuration::FILE_NAME)
+  };
+  eprintln!("added {}@{} to {}", app.name(), version, path.display());
   Ok(ExitCode::SUCCESS)
 }
 
@@ -20,5 +27,8 @@ pub fn add(args: AddArgs, apps: &Apps) -> Result<ExitCode> {
 #[derive(Debug, PartialEq)]
 pub struct AddArgs {
   pub app_name: ApplicationName,
+  pub offline: bool,
+  /// whether to ignore the cached versions
+  pub refresh: bool,
   pub verbose: bool,
 }
@@ -1,6 +1,20 @@
 use crate::applications::Apps;
+use serde_json::{Value, json};
 use std::process::ExitCode;
 
+#[must_use]
+pub(crate) fn json(apps: &Apps) -> ExitCode {
+  let mut entries = vec![];
+  for app in apps {
+    entries.push(json!({
+      "app": app.name().as_str(),
+      "homepage": app.homepage(),
+    }));
+  }
+  println!("{}", Value::Array(entries));
+  ExitCode::SUCCESS
+}
+
 #[must_use]
 pub(crate) fn long(apps: &Apps) -> ExitCode {
   let width = apps.longest_name_length() + 1;
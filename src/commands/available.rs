@@ -1,22 +1,38 @@
 use crate::applications::{ApplicationName, Apps};
-use crate::context::RuntimeContext;
+use crate::commands::which::executable_json;
+use crate::context::{self, RuntimeContext};
 use crate::error::Result;
 use crate::executables::{LoadOrInstallAppAndCarrierArgs, LoadOrInstallAppOutcome, load_or_install_app_and_carrier};
 use crate::yard::Yard;
 use crate::{configuration, logging, platform, yard};
 use std::process::ExitCode;
+use std::sync::Mutex;
 
-pub fn available(AvailableArgs { app_name, optional, verbose }: AvailableArgs, apps: &Apps) -> Result<ExitCode> {
+pub fn available(
+  AvailableArgs {
+    app_name,
+    json,
+    optional,
+    offline,
+    verbose,
+  }: AvailableArgs,
+  apps: &Apps,
+) -> Result<ExitCode> {
   let app = apps.lookup(app_name)?;
   let log = logging::new(verbose);
   let platform = platform::detect(log)?;
   let yard = Yard::load_or_create(&yard::production_location()?)?;
   let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
   let ctx = RuntimeContext {
     platform,
     yard: &yard,
     config_file: &config_file,
+    lock_file: &lock_file,
     log,
+    offline: context::offline(offline),
+    cross_platform: false,
+    bundle: None,
   };
   match load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
     app,
@@ -26,15 +42,30 @@ pub fn available(AvailableArgs { app_name, optional, verbose }: AvailableArgs, a
     ctx: &ctx,
     apps,
   })? {
-    LoadOrInstallAppOutcome::Loaded { executable: _ } => Ok(ExitCode::SUCCESS),
-    LoadOrInstallAppOutcome::NotInstallable { app: _ } => Ok(ExitCode::FAILURE),
+    LoadOrInstallAppOutcome::Loaded { executable } => {
+      ctx.lock_file_mut().save(&config_file)?;
+      if json {
+        println!("{}", executable_json(app, Some(&executable), &ctx)?);
+      }
+      Ok(ExitCode::SUCCESS)
+    }
+    LoadOrInstallAppOutcome::NotInstallable { app: _ } => {
+      if json {
+        println!("{}", executable_json(app, None, &ctx)?);
+      }
+      Ok(ExitCode::FAILURE)
+    }
   }
 }
 
 /// named arguments for the [`available`] command
 #[derive(Debug, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct AvailableArgs {
   pub app_name: ApplicationName,
+  /// whether to print machine-readable output
+  pub json: bool,
   pub optional: bool,
+  pub offline: bool,
   pub verbose: bool,
 }
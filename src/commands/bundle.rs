@@ -0,0 +1,64 @@
+use crate::applications::Apps;
+use crate::bundle::Bundle;
+use crate::context::{self, RuntimeContext};
+use crate::error::{Result, UserError};
+use crate::executables::load_or_install_apps;
+use crate::platform::Platform;
+use crate::yard::Yard;
+use crate::{configuration, logging, platform};
+use colored::Colorize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+/// packs the downloaded artifacts of all configured apps into the given bundle file
+pub fn bundle(args: &BundleArgs, apps: &Apps) -> Result<ExitCode> {
+  let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
+  let log = logging::new(args.verbose);
+  let host = platform::detect(log)?;
+  let platform = platform::target(args.platform, host)?;
+  // install into an empty yard, so that all artifacts get downloaded
+  let tempdir = tempfile::tempdir().map_err(|err| UserError::CannotCreateTempDir { err: err.to_string() })?;
+  let yard = Yard::create(tempdir.path())?;
+  // keep the downloaded artifacts in the staging folder of the yard until they get packed
+  let mut bundle = Bundle::recording(yard.staging_folder().join("bundle"))?;
+  let ctx = RuntimeContext {
+    platform,
+    yard: &yard,
+    config_file: &config_file,
+    lock_file: &lock_file,
+    log,
+    offline: context::offline(false),
+    cross_platform: platform != host,
+    bundle: Some(&bundle),
+  };
+  let app_names: Vec<_> = config_file.all_apps().into_iter().map(|app| &app.app_name).collect();
+  load_or_install_apps(apps.lookup_many(&app_names)?, apps, true, &ctx)?;
+  ctx.lock_file_mut().save(&config_file)?;
+  bundle.write(&args.file)?;
+  let entries = bundle.entries();
+  for entry in entries {
+    println!("bundled {}@{} for {}", entry.app_name, entry.version, entry.platform);
+  }
+  // bundles contain only downloaded artifacts, apps that compile from source or need NodeJS don't make it in
+  for app_name in app_names {
+    if !entries.iter().any(|entry| &entry.app_name == app_name) {
+      eprintln!(
+        "{}",
+        format!("warning: {app_name} has no downloadable executable for {platform}, not bundled").yellow()
+      );
+    }
+  }
+  Ok(ExitCode::SUCCESS)
+}
+
+/// named arguments for the [`bundle`] command
+#[derive(Debug, PartialEq)]
+pub struct BundleArgs {
+  /// the bundle file to create
+  pub file: PathBuf,
+  /// the platform to bundle the apps for, None for the platform in the environment or of this machine
+  pub platform: Option<Platform>,
+  pub verbose: bool,
+}
@@ -0,0 +1,84 @@
+//! copies installed executables into a folder outside of the yard
+
+use crate::applications::{AppDefinition, NodeJS};
+use crate::configuration::Version;
+use crate::error::{Result, UserError};
+use crate::executables::{Executable, RunMethod};
+use crate::filesystem;
+use crate::platform::Platform;
+use std::fs;
+use std::path::Path;
+
+/// Verifies that the given app consists of executables that can run outside of the yard.
+/// Call this before installing, so that users don't wait for installations that cannot be copied.
+pub(super) fn ensure_copyable(app: &dyn AppDefinition, platform: Platform) -> Result<()> {
+  match app.run_method(&Version::from("*"), platform) {
+    RunMethod::ThisApp { install_methods: _ }
+    | RunMethod::OtherAppOtherExecutable {
+      carrier: _,
+      executable_name: _,
+    } => Ok(()),
+    RunMethod::OtherAppShellScript { carrier, script_name: _ } => Err(UserError::CannotCopyApp {
+      app: app.name(),
+      carrier: carrier.name(),
+    }),
+    RunMethod::NodeJS { package: _, script: _ } => Err(UserError::CannotCopyApp {
+      app: app.name(),
+      carrier: NodeJS {}.name(),
+    }),
+  }
+}
+
+/// copies the given executable of the given app and the app's additional executables into the given folder
+pub(super) fn copy_executables(app: &dyn AppDefinition, executable: &Executable, folder: &Path, platform: Platform) -> Result<()> {
+  fs::create_dir_all(folder).map_err(|err| UserError::CannotCreateFolder {
+    folder: folder.to_path_buf(),
+    reason: err.to_string(),
+  })?;
+  let mut sources = vec![executable.as_path().to_path_buf()];
+  for additional in app.additional_executables() {
+    sources.push(executable.parent_path().join(additional.platform_path(platform.os).as_ref()));
+  }
+  for source in sources {
+    let Some(filename) = source.file_name() else {
+      continue;
+    };
+    let target = folder.join(filename);
+    filesystem::link_or_copy_executable(&source, &target)?;
+    println!("{}", target.display());
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod ensure_copyable {
+    use super::super::ensure_copyable;
+    use crate::applications;
+    use crate::platform::{Cpu, Os, Platform};
+
+    const PLATFORM: Platform = Platform {
+      os: Os::Linux,
+      cpu: Cpu::Intel64,
+    };
+
+    #[test]
+    fn binary() {
+      let apps = applications::all();
+      assert!(ensure_copyable(apps.lookup("shellcheck").unwrap(), PLATFORM).is_ok());
+    }
+
+    #[test]
+    fn nodejs_package() {
+      let apps = applications::all();
+      assert!(ensure_copyable(apps.lookup("prettier").unwrap(), PLATFORM).is_err());
+    }
+
+    #[test]
+    fn shell_script() {
+      let apps = applications::all();
+      assert!(ensure_copyable(apps.lookup("npm").unwrap(), PLATFORM).is_err());
+    }
+  }
+}
@@ -0,0 +1,234 @@
+use crate::applications::Apps;
+use crate::context::{self, RuntimeContext};
+use crate::error::{Result, UserError};
+use crate::executables::{Executable, LoadOrInstallAppAndCarrierArgs, LoadOrInstallAppOutcome, load_or_install_app_and_carrier};
+use crate::yard::{self, Yard};
+use crate::{configuration, logging, platform, subshell};
+use serde_json::json;
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+/// installs all configured apps and prints shell code that adds them to the PATH
+pub fn env(args: &EnvArgs, apps: &Apps) -> Result<ExitCode> {
+  let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
+  let log = logging::new(args.verbose);
+  let platform = platform::detect(log)?;
+  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let ctx = RuntimeContext {
+    platform,
+    yard: &yard,
+    config_file: &config_file,
+    lock_file: &lock_file,
+    log,
+    offline: context::offline(args.offline),
+    cross_platform: false,
+    bundle: None,
+  };
+  let app_names: Vec<_> = config_file.all_apps().into_iter().map(|app| &app.app_name).collect();
+  let mut executables = vec![];
+  let mut exit_code = ExitCode::SUCCESS;
+  for app in apps.lookup_many(&app_names)? {
+    match load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
+      app,
+      cli_version: None,
+      optional: true,
+      from_source: false,
+      ctx: &ctx,
+      apps,
+    })? {
+      LoadOrInstallAppOutcome::Loaded { executable } => executables.push(executable),
+      LoadOrInstallAppOutcome::NotInstallable { app } => {
+        // the PATH still gets the other apps, so report this one separately
+        eprintln!("{app} is not available for this platform, not adding it to the PATH");
+        exit_code = ExitCode::FAILURE;
+      }
+    }
+  }
+  ctx.lock_file_mut().save(&config_file)?;
+  let folders = yard_folders(&executables, &yard.root);
+  let shell = match args.shell {
+    Some(shell) => shell,
+    None => Shell::from_env(),
+  };
+  println!("{}", render(shell, &folders));
+  Ok(exit_code)
+}
+
+/// named arguments for the [`env`] command
+#[derive(Debug, PartialEq)]
+pub struct EnvArgs {
+  /// the shell to print code for, None to use the shell from the SHELL environment variable
+  pub shell: Option<Shell>,
+  pub offline: bool,
+  pub verbose: bool,
+}
+
+/// provides the folders containing the given executables that are inside the given yard
+fn yard_folders<'a>(executables: &'a [Executable], yard_root: &Path) -> Vec<&'a Path> {
+  let mut result: Vec<&Path> = vec![];
+  for executable in executables {
+    let folder = executable.parent_path();
+    // globally installed apps are already in the PATH, adding their folders would put system folders in front of the user's PATH
+    if folder.starts_with(yard_root) && !result.contains(&folder) {
+      result.push(folder);
+    }
+  }
+  result
+}
+
+/// the shells that `--env` can print code for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shell {
+  Bash,
+  Fish,
+  Json,
+  Zsh,
+}
+
+impl Shell {
+  pub fn parse(name: &str) -> Result<Shell> {
+    match name {
+      "bash" | "sh" => Ok(Shell::Bash),
+      "fish" => Ok(Shell::Fish),
+      "json" => Ok(Shell::Json),
+      "zsh" => Ok(Shell::Zsh),
+      _ => Err(UserError::UnknownShell(name.to_string())),
+    }
+  }
+
+  /// provides the shell that the user runs, defaulting to Bash
+  fn from_env() -> Shell {
+    env::var("SHELL")
+      .ok()
+      .and_then(|path| Path::new(&path).file_name().and_then(|name| Shell::parse(&name.to_string_lossy()).ok()))
+      .unwrap_or(Shell::Bash)
+  }
+}
+
+/// provides the code that adds the given folders to the front of the PATH in the given shell
+fn render(shell: Shell, folders: &[&Path]) -> String {
+  let folder_names: Vec<String> = folders.iter().map(|folder| folder.to_string_lossy().to_string()).collect();
+  match shell {
+    Shell::Bash | Shell::Zsh => {
+      if folder_names.is_empty() {
+        return String::new();
+      }
+      format!("export PATH={}:\"$PATH\"", posix_quote(&folder_names.join(":")))
+    }
+    Shell::Fish => {
+      if folder_names.is_empty() {
+        return String::new();
+      }
+      let quoted: Vec<String> = folder_names.iter().map(|name| fish_quote(name)).collect();
+      format!("set -gx PATH {} $PATH", quoted.join(" "))
+    }
+    Shell::Json => json!({
+      "prepend": folder_names,
+      "PATH": subshell::path_expressions(folders).to_string_lossy(),
+    })
+    .to_string(),
+  }
+}
+
+/// quotes the given text for POSIX shells
+pub(super) fn posix_quote(text: &str) -> String {
+  format!("'{}'", text.replace('\'', r"'\''"))
+}
+
+/// quotes the given text for the Fish shell
+fn fish_quote(text: &str) -> String {
+  format!("'{}'", text.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod render {
+    use super::super::{Shell, render};
+    use std::path::Path;
+
+    fn folders() -> Vec<&'static Path> {
+      vec![
+        Path::new("/home/user/.run-that-app/apps/actionlint/1.7.7"),
+        Path::new("/home/user/.run-that-app/apps/shellcheck/0.10.0/shellcheck-v0.10.0"),
+      ]
+    }
+
+    #[test]
+    fn bash() {
+      let have = render(Shell::Bash, &folders());
+      let want = r#"export PATH='/home/user/.run-that-app/apps/actionlint/1.7.7:/home/user/.run-that-app/apps/shellcheck/0.10.0/shellcheck-v0.10.0':"$PATH""#;
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn fish() {
+      let have = render(Shell::Fish, &folders());
+      let want = "set -gx PATH '/home/user/.run-that-app/apps/actionlint/1.7.7' '/home/user/.run-that-app/apps/shellcheck/0.10.0/shellcheck-v0.10.0' $PATH";
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn json() {
+      let have: serde_json::Value = serde_json::from_str(&render(Shell::Json, &folders())).unwrap();
+      assert_eq!(
+        have["prepend"],
+        serde_json::json!([
+          "/home/user/.run-that-app/apps/actionlint/1.7.7",
+          "/home/user/.run-that-app/apps/shellcheck/0.10.0/shellcheck-v0.10.0"
+        ])
+      );
+      assert!(have["PATH"].as_str().unwrap().starts_with("/home/user/.run-that-app/apps/actionlint/1.7.7"));
+    }
+
+    #[test]
+    fn no_folders() {
+      assert_eq!(render(Shell::Zsh, &[]), "");
+    }
+  }
+
+  mod yard_folders {
+    use super::super::yard_folders;
+    use crate::executables::Executable;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn skips_global_installations() {
+      let executables = vec![
+        Executable::Binary(PathBuf::from("/home/user/.run-that-app/apps/shfmt@3.7.0/shfmt")),
+        Executable::Binary(PathBuf::from("/usr/bin/shellcheck")),
+        Executable::Binary(PathBuf::from("/home/user/.run-that-app/apps/shfmt@3.7.0/other")),
+      ];
+      let have = yard_folders(&executables, Path::new("/home/user/.run-that-app"));
+      assert_eq!(have, vec![Path::new("/home/user/.run-that-app/apps/shfmt@3.7.0")]);
+    }
+  }
+
+  mod posix_quote {
+    use super::super::posix_quote;
+
+    #[test]
+    fn single_quote() {
+      assert_eq!(posix_quote("/home/o'brien/apps"), r"'/home/o'\''brien/apps'");
+    }
+  }
+
+  mod shell_parse {
+    use super::super::Shell;
+    use crate::error::UserError;
+
+    #[test]
+    fn known() {
+      assert_eq!(Shell::parse("zsh"), Ok(Shell::Zsh));
+      assert_eq!(Shell::parse("fish"), Ok(Shell::Fish));
+    }
+
+    #[test]
+    fn unknown() {
+      assert_eq!(Shell::parse("tcsh"), Err(UserError::UnknownShell("tcsh".into())));
+    }
+  }
+}
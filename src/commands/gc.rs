@@ -0,0 +1,293 @@
+use crate::configuration::{self, RequestedVersion};
+use crate::error::{Result, UserError};
+use crate::filesystem::{folder_size, format_size, subfolders};
+use crate::yard::{self, Yard, app_version};
+use crate::{applications, logging};
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+/// deletes app versions and temporary files in the yard that are no longer needed
+pub fn gc(args: &GcArgs) -> Result<ExitCode> {
+  if args.project_dirs.is_empty() && args.unused_days.is_none() {
+    return Err(UserError::GcWithoutCriteria);
+  }
+  let log = logging::new(args.verbose);
+  let Some(yard) = Yard::load(&yard::production_location()?)? else {
+    println!("nothing to delete");
+    return Ok(ExitCode::SUCCESS);
+  };
+  let referenced = if args.project_dirs.is_empty() {
+    None
+  } else {
+    Some(referenced_app_folders(&args.project_dirs)?)
+  };
+  let garbage = find_garbage(&yard, referenced.as_deref(), args.unused_days, SystemTime::now())?;
+  let mut freed = 0;
+  for item in garbage {
+    if args.dry_run {
+      println!("would delete {} ({}, {})", item.path.display(), format_size(item.size), item.reason);
+      freed += item.size;
+      continue;
+    }
+    // don't delete folders that another run-that-app process is currently using
+    match yard.with_lock_if_free(&item.folder_name, log, || delete_folder(&item.path))? {
+      Some(()) => {
+        println!("deleted {} ({}, {})", item.path.display(), format_size(item.size), item.reason);
+        freed += item.size;
+      }
+      None => println!("skipped {} because it is in use", item.path.display()),
+    }
+  }
+  if args.dry_run {
+    println!("would free {}", format_size(freed));
+  } else {
+    println!("freed {}", format_size(freed));
+  }
+  Ok(ExitCode::SUCCESS)
+}
+
+/// named arguments for the [`gc`] command
+#[derive(Debug, PartialEq)]
+pub struct GcArgs {
+  /// delete all app versions that the config files in none of these folders reference
+  pub project_dirs: Vec<PathBuf>,
+  /// delete all app versions that weren't used for this many days
+  pub unused_days: Option<u64>,
+  /// only list what would be deleted
+  pub dry_run: bool,
+  pub verbose: bool,
+}
+
+/// a folder in the yard that is no longer needed
+#[derive(Debug, PartialEq)]
+struct Garbage {
+  /// name of the folder, which is also the name of its lock
+  folder_name: String,
+  path: PathBuf,
+  reason: Reason,
+  /// disk space used by this folder, in bytes
+  size: u64,
+}
+
+/// why a folder in the yard is no longer needed
+#[derive(Debug, PartialEq)]
+enum Reason {
+  /// the app version is marked as not installable, deleting it allows trying to install it again
+  NotInstallable,
+  /// no config file in the given project folders references this app version
+  NotReferenced,
+  /// a leftover from an installation that didn't finish
+  Staging,
+  /// the app version wasn't used for the given number of days
+  Unused { days: u64 },
+}
+
+impl Display for Reason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Reason::NotInstallable => f.write_str("marked as not installable"),
+      Reason::NotReferenced => f.write_str("not referenced by the given config files"),
+      Reason::Staging => f.write_str("unfinished installation"),
+      Reason::Unused { days } => write!(f, "unused for {days} days"),
+    }
+  }
+}
+
+/// provides the names of the app folders for all app versions that the config files in the given folders reference
+fn referenced_app_folders(project_dirs: &[PathBuf]) -> Result<Vec<String>> {
+  let mut result = vec![];
+  for project_dir in project_dirs {
+    // each project can define its own custom apps
+    let apps = applications::load_in(project_dir)?;
+    let Some(config_file) = configuration::File::read_in(project_dir, &apps)? else {
+      return Err(UserError::NoConfigFileInFolder { folder: project_dir.clone() });
+    };
+    for app in config_file.all_apps() {
+      for requested_version in &app.versions {
+        if let RequestedVersion::Yard(version) = requested_version {
+          result.push(app_version(&app.app_name, version));
+        }
+      }
+    }
+  }
+  Ok(result)
+}
+
+/// provides the folders in the given yard that are no longer needed
+///
+/// This works with the folder names only, so that it also handles app versions
+/// that this run of run-that-app cannot resolve, like ad-hoc apps or apps defined in other projects.
+fn find_garbage(yard: &Yard, referenced: Option<&[String]>, unused_days: Option<u64>, now: SystemTime) -> Result<Vec<Garbage>> {
+  let mut result = vec![];
+  for (folder_name, path) in subfolders(&yard.apps_folder())? {
+    if yard::parse_app_version(&folder_name).is_none() {
+      continue;
+    }
+    let reason = if yard::is_folder_not_installable(&path) {
+      Some(Reason::NotInstallable)
+    } else if referenced.is_some_and(|referenced| !referenced.contains(&folder_name)) {
+      Some(Reason::NotReferenced)
+    } else if let Some(days) = unused_days
+      // app versions that were never used count as used when they got installed
+      && yard::folder_last_used(&path)
+        .or_else(|| fs::metadata(&path).and_then(|metadata| metadata.modified()).ok())
+        .is_none_or(|last_used| now.duration_since(last_used).unwrap_or_default() >= Duration::from_hours(days * 24))
+    {
+      Some(Reason::Unused { days })
+    } else {
+      None
+    };
+    if let Some(reason) = reason {
+      result.push(Garbage {
+        size: folder_size(&path),
+        folder_name,
+        path,
+        reason,
+      });
+    }
+  }
+  for (folder_name, path) in subfolders(&yard.staging_folder())? {
+    result.push(Garbage {
+      size: folder_size(&path),
+      folder_name,
+      path,
+      reason: Reason::Staging,
+    });
+  }
+  Ok(result)
+}
+
+fn delete_folder(path: &Path) -> Result<()> {
+  match fs::remove_dir_all(path) {
+    Ok(()) => Ok(()),
+    Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+    Err(err) => Err(UserError::CannotDeleteFolder {
+      folder: path.to_path_buf(),
+      err: err.to_string(),
+    }),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod referenced_app_folders {
+    use super::super::referenced_app_folders;
+    use std::fs;
+
+    #[test]
+    fn custom_apps_of_other_project() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let project = tempdir.path();
+      fs::write(project.join("run-that-app"), "mytool 1.2.3\nshellcheck 0.10.0\n").unwrap();
+      fs::write(
+        project.join("run-that-app.apps.json"),
+        r#"[{ "name": "mytool", "github": "acme/mytool", "url": "https://acme.com/mytool-{version}" }]"#,
+      )
+      .unwrap();
+      let have = referenced_app_folders(&[project.to_path_buf()]).unwrap();
+      assert_eq!(have, vec!["mytool@1.2.3", "shellcheck@0.10.0"]);
+    }
+  }
+
+  mod find_garbage {
+    use super::super::{Garbage, Reason, find_garbage};
+    use crate::yard::Yard;
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+
+    /// creates an app folder containing an executable with the given size
+    fn create_app(yard: &Yard, folder_name: &str, size: usize) {
+      let folder = yard.apps_folder().join(folder_name);
+      fs::create_dir_all(&folder).unwrap();
+      fs::write(folder.join("executable"), vec![0; size]).unwrap();
+    }
+
+    #[test]
+    fn not_referenced() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      create_app(&yard, "shellcheck@0.9.0", 10);
+      create_app(&yard, "shellcheck@0.10.0", 20);
+      let referenced = vec!["shellcheck@0.10.0".to_string()];
+      let have = find_garbage(&yard, Some(&referenced), None, SystemTime::now()).unwrap();
+      let want = vec![Garbage {
+        folder_name: "shellcheck@0.9.0".into(),
+        path: yard.apps_folder().join("shellcheck@0.9.0"),
+        reason: Reason::NotReferenced,
+        size: 10,
+      }];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn unused() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      create_app(&yard, "shellcheck@0.9.0", 10);
+      create_app(&yard, "shfmt@3.7.0", 10);
+      yard.mark_used(&"shfmt".into(), &"3.7.0".into());
+      let in_ten_days = SystemTime::now() + Duration::from_hours(10 * 24);
+      let have = find_garbage(&yard, None, Some(30), in_ten_days).unwrap();
+      assert!(have.is_empty());
+      let in_forty_days = SystemTime::now() + Duration::from_hours(40 * 24);
+      let have = find_garbage(&yard, None, Some(30), in_forty_days).unwrap();
+      let have: Vec<&str> = have.iter().map(|garbage| garbage.folder_name.as_str()).collect();
+      assert_eq!(have, vec!["shellcheck@0.9.0", "shfmt@3.7.0"]);
+    }
+
+    #[test]
+    fn not_installable_and_staging() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      yard.mark_not_installable(&"shellcheck".into(), &"0.9.0".into()).unwrap();
+      create_app(&yard, "shfmt@3.7.0", 10);
+      let staging = yard.create_staging_folder(&"actionlint".into(), &"1.7.7".into()).unwrap();
+      fs::write(staging.join("archive.tar.gz"), vec![0; 5]).unwrap();
+      let have = find_garbage(&yard, None, None, SystemTime::now()).unwrap();
+      let want = vec![
+        Garbage {
+          folder_name: "shellcheck@0.9.0".into(),
+          path: yard.apps_folder().join("shellcheck@0.9.0"),
+          reason: Reason::NotInstallable,
+          size: 0,
+        },
+        Garbage {
+          folder_name: "actionlint@1.7.7".into(),
+          path: yard.staging_folder().join("actionlint@1.7.7"),
+          reason: Reason::Staging,
+          size: 5,
+        },
+      ];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn unknown_app() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      create_app(&yard, "zonk@1.0.0", 3);
+      create_app(&yard, "github%3Aacme%2Fmytool@1.2.3", 4);
+      // unknown apps alone are no reason to delete them
+      let have = find_garbage(&yard, None, Some(30), SystemTime::now()).unwrap();
+      assert!(have.is_empty());
+      // but the given criteria apply to them
+      let in_forty_days = SystemTime::now() + Duration::from_hours(40 * 24);
+      let have = find_garbage(&yard, None, Some(30), in_forty_days).unwrap();
+      let have: Vec<&str> = have.iter().map(|garbage| garbage.folder_name.as_str()).collect();
+      assert_eq!(have, vec!["github%3Aacme%2Fmytool@1.2.3", "zonk@1.0.0"]);
+    }
+
+    #[test]
+    fn empty_yard() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let have = find_garbage(&yard, Some(&[]), Some(0), SystemTime::now()).unwrap();
+      assert!(have.is_empty());
+    }
+  }
+}
@@ -19,21 +19,41 @@ pub fn print_options() {
 --add <app>                 add the given application to the configuration file
 --apps, -a                  display all installable applications
 --available <app>           signal via exit code whether the given application is available on this platform
+--bundle <file>             pack the downloads of all configured applications into the given file for installing them offline
+--dry-run                   with --gc: only list what would be deleted, with --update: only display the changes to the config file
+--env [<shell>]             install all configured applications and print code that adds them to the PATH of bash, fish, zsh, or json
 --error-on-output           treat all output of the executed app as an error
 --from-source               force installation from source, even if precompiled binaries are available
+--gc [<folder>...]          delete app versions that the config files in the given project folders don't reference
 --help, -h                  display this help screen
+--import <file>             install the applications in the given file created by --bundle, without accessing the network
 --include=<app>             include the given application in the PATH
 --install <app>             install the given application without running it
 --install-all               install all applications listed in the configuration file
+--installed                 list the installed applications with their disk usage and when they were last used
+--jobs <n>                  with --install-all: how many apps to install at the same time (default: number of CPUs, always 1 with --verbose)
+--json                      with --apps, --available, --installed, --outdated, --test, --update, --versions, or --which: print the output as JSON
+--major                     with --update: update to the newest version (default)
+--minor                     with --update: update only within the current major version
+--offline                   use only installed applications and cached versions, never access the network (also via RTA_OFFLINE=1)
 --optional                  if an app is not available for the current platform, do nothing
+--outdated                  list the configured applications with newer versions, exits with an error if there are any
+--patch                     with --update: update only within the current minor version
+--platform=<os>/<cpu>       with --bundle, --import, --install, --install-all, or --reinstall: install for the given platform, like linux/arm64, into a separate folder (also via RTA_PLATFORM)
+--refresh                   with --add, --outdated, --update, or --versions: look up versions on the internet even if cached versions exist
+--release-notes[=<file>]    with --update: display the release notes of the updated applications or write them into the given Markdown file
 --reinstall <app>           delete and then install the given application again
---update                    updates the versions in run-that-app to the latest available
+--shims <folder>            create scripts in the given folder that run the configured applications via run-that-app
+--to=<folder>               with --install or --install-all: copy the installed executables into the given folder
+--unused-days=<n>           with --gc: also delete app versions that weren't used for the given number of days
+--update [<app>...]         updates the versions of the given or all apps in run-that-app to the latest stable versions that the project allows
 --which <app>               displays the path to the installed executable of the given application
 --verbose, -v               display more details
 --version, -V               displays the version of run-that-app
 --versions <app>            displays the 10 most recent available versions of the given app
 --versions=<number> <app>   displays the given number of most recent available versions of the given app
 --which <app>               displays the path to the installed executable of the given application
+--which-config              displays the paths of the config files that apply to the current folder
 ",
   );
 }
@@ -0,0 +1,65 @@
+use crate::applications::Apps;
+use crate::bundle::Bundle;
+use crate::context::RuntimeContext;
+use crate::error::{Result, UserError};
+use crate::installation::{self, Outcome};
+use crate::platform::Platform;
+use crate::yard::{self, Yard};
+use crate::{configuration, logging, platform};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+/// installs the apps in the given bundle file into the yard
+pub fn import(args: &ImportArgs, apps: &Apps) -> Result<ExitCode> {
+  let mut bundle = Bundle::read(&args.file, apps)?;
+  let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
+  let log = logging::new(args.verbose);
+  let host = platform::detect(log)?;
+  let platform = platform::target(args.platform, host)?;
+  let yard = Yard::load_or_create(&yard::location(platform, host)?)?;
+  let platform_name = platform.to_string();
+  let app_versions: Vec<_> = bundle
+    .entries()
+    .iter()
+    .filter(|entry| entry.platform == platform_name)
+    .map(|entry| (entry.app_name.clone(), entry.version.clone()))
+    .collect();
+  if app_versions.is_empty() {
+    return Err(UserError::InvalidBundle {
+      path: args.file.clone(),
+      reason: format!("contains no applications for {platform}"),
+    });
+  }
+  let ctx = RuntimeContext {
+    platform,
+    yard: &yard,
+    config_file: &config_file,
+    lock_file: &lock_file,
+    log,
+    // the bundle provides all artifacts, so this doesn't access the network
+    offline: false,
+    cross_platform: platform != host,
+    bundle: Some(&bundle),
+  };
+  for (app_name, version) in app_versions {
+    let app = apps.lookup(&app_name)?;
+    match installation::app_version(app, &version, false, false, &ctx, apps)? {
+      Outcome::Installed => println!("imported {app_name}@{version}"),
+      Outcome::NotInstalled { app } => return Err(UserError::NotInBundle { app, version }),
+    }
+  }
+  ctx.lock_file_mut().save(&config_file)?;
+  Ok(ExitCode::SUCCESS)
+}
+
+/// named arguments for the [`import`] command
+#[derive(Debug, PartialEq)]
+pub struct ImportArgs {
+  /// the bundle file to import
+  pub file: PathBuf,
+  /// the platform to import the apps for, None for the platform in the environment or of this machine
+  pub platform: Option<Platform>,
+  pub verbose: bool,
+}
@@ -1,11 +1,15 @@
+use super::copy_executables::{copy_executables, ensure_copyable};
 use crate::applications::{ApplicationName, Apps};
 use crate::configuration::{self, Version};
-use crate::context::RuntimeContext;
+use crate::context::{self, RuntimeContext};
 use crate::error::{Result, UserError};
 use crate::executables::{LoadOrInstallAppAndCarrierArgs, LoadOrInstallAppOutcome, load_or_install_app_and_carrier, load_or_install_apps};
+use crate::platform::Platform;
 use crate::yard::Yard;
 use crate::{logging, platform, yard};
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Mutex;
 
 pub fn install(
   InstallArgs {
@@ -14,40 +18,59 @@ pub fn install(
     from_source,
     include_apps,
     optional,
+    offline,
+    platform,
+    to,
     verbose,
   }: InstallArgs,
   apps: &Apps,
 ) -> Result<ExitCode> {
   let app_to_install = apps.lookup(&app_name)?;
   let log = logging::new(verbose);
-  let platform = platform::detect(log)?;
-  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let host = platform::detect(log)?;
+  let platform = platform::target(platform, host)?;
+  let yard = Yard::load_or_create(&yard::location(platform, host)?)?;
   let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
   let ctx = RuntimeContext {
     platform,
     yard: &yard,
     config_file: &config_file,
+    lock_file: &lock_file,
     log,
+    offline: context::offline(offline),
+    cross_platform: platform != host,
+    bundle: None,
   };
+  if to.is_some() {
+    ensure_copyable(app_to_install, platform)?;
+  }
   // install the included apps
   let include_apps = apps.lookup_many(&include_apps)?;
   load_or_install_apps(include_apps, apps, optional, &ctx)?;
   // install the main app
-  match load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
+  let outcome = load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
     app: app_to_install,
     cli_version: version.as_ref(),
     optional,
     from_source,
     ctx: &ctx,
     apps,
-  })? {
-    LoadOrInstallAppOutcome::Loaded { executable: _ } => Ok(ExitCode::SUCCESS),
+  })?;
+  ctx.lock_file_mut().save(&config_file)?;
+  match outcome {
+    LoadOrInstallAppOutcome::Loaded { executable } => {
+      if let Some(folder) = to {
+        copy_executables(app_to_install, &executable, &folder, platform)?;
+      }
+      Ok(ExitCode::SUCCESS)
+    }
     LoadOrInstallAppOutcome::NotInstallable { app: _ } if optional => Ok(ExitCode::SUCCESS),
     LoadOrInstallAppOutcome::NotInstallable { app } => Err(UserError::UnsupportedPlatform { app }),
   }
 }
 
-/// named arguments for the [`install`], [`install_all`][super::install_all::install_all], and [`reinstall`][super::reinstall::reinstall] commands
+/// named arguments for the [`install`] and [`reinstall`][super::reinstall::reinstall] commands
 #[derive(Debug, PartialEq)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct InstallArgs {
@@ -66,5 +89,14 @@ pub struct InstallArgs {
   /// whether it's okay to not install the app if it cannot be installed
   pub optional: bool,
 
+  /// whether to use only already installed applications, without accessing the network
+  pub offline: bool,
+
+  /// the platform to install the app for, None for the platform in the environment or of this machine
+  pub platform: Option<Platform>,
+
+  /// folder to copy the installed executables into
+  pub to: Option<PathBuf>,
+
   pub verbose: bool,
 }
@@ -1,24 +1,205 @@
-use crate::applications::{ApplicationName, Apps};
-use crate::context::RuntimeContext;
+use super::copy_executables::{copy_executables, ensure_copyable};
+use crate::applications::{AppDefinition, ApplicationName, Apps, Go, NodeJS};
+use crate::context::{self, RuntimeContext};
 use crate::error::Result;
-use crate::executables::load_or_install_apps;
+use crate::executables::{LoadOrInstallAppAndCarrierArgs, LoadOrInstallAppOutcome, RunMethod, load_or_install_app_and_carrier, load_or_install_apps};
+use crate::installation::Method;
+use crate::platform::Platform;
 use crate::yard::{self, Yard};
-use crate::{configuration, logging, platform};
+use crate::{Version, configuration, logging, platform};
+use colored::Colorize;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::thread;
 
-pub fn install_all(apps: &Apps) -> Result<ExitCode> {
+pub fn install_all(args: &InstallAllArgs, apps: &Apps) -> Result<ExitCode> {
   let config_file = configuration::File::load(apps)?;
-  let log = logging::new(false);
-  let platform = platform::detect(log)?;
-  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
+  let log = logging::new(args.verbose);
+  let host = platform::detect(log)?;
+  let platform = platform::target(args.platform, host)?;
+  let yard = Yard::load_or_create(&yard::location(platform, host)?)?;
+  // verbose output is only readable when installing one app at a time
+  let jobs = if args.verbose {
+    if args.jobs.is_some_and(|jobs| jobs.get() > 1) {
+      eprintln!("{}", "--verbose installs one application at a time, ignoring --jobs".yellow());
+    }
+    1
+  } else {
+    args.jobs.unwrap_or_else(default_jobs).get()
+  };
   let ctx = RuntimeContext {
     platform,
     yard: &yard,
     config_file: &config_file,
-    log,
+    lock_file: &lock_file,
+    log: if jobs > 1 { logging::concurrent_log } else { log },
+    offline: context::offline(args.offline),
+    cross_platform: platform != host,
+    bundle: None,
   };
-  let app_names_to_install: Vec<&ApplicationName> = config_file.apps.iter().map(|app| &app.app_name).collect();
+  let app_names_to_install: Vec<&ApplicationName> = config_file.all_apps().into_iter().map(|app| &app.app_name).collect();
   let apps_to_install = apps.lookup_many(&app_names_to_install)?;
-  let _ = load_or_install_apps(apps_to_install, apps, true, &ctx)?;
+  if args.to.is_some() {
+    for app in &apps_to_install {
+      ensure_copyable(*app, platform)?;
+    }
+  }
+  // install the apps that other apps need to run or install first
+  let carrier_names = carriers(&apps_to_install, platform);
+  let (carriers, others): (Vec<&dyn AppDefinition>, Vec<&dyn AppDefinition>) = apps_to_install.into_iter().partition(|app| carrier_names.contains(&app.name()));
+  let _ = load_or_install_apps(carriers.clone(), apps, true, &ctx)?;
+  install_concurrently(others.clone(), jobs, apps, &ctx)?;
+  ctx.lock_file_mut().save(&config_file)?;
+  if let Some(folder) = &args.to {
+    for app in carriers.into_iter().chain(others) {
+      // the app is installed at this point, so this only loads it
+      let outcome = load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
+        app,
+        cli_version: None,
+        optional: true,
+        from_source: false,
+        ctx: &ctx,
+        apps,
+      })?;
+      if let LoadOrInstallAppOutcome::Loaded { executable } = outcome {
+        copy_executables(app, &executable, folder, platform)?;
+      }
+    }
+  }
   Ok(ExitCode::SUCCESS)
 }
+
+/// named arguments for the [`install_all`] command
+#[derive(Debug, PartialEq)]
+pub struct InstallAllArgs {
+  /// how many apps to install at the same time, None to determine this automatically
+  pub jobs: Option<NonZeroUsize>,
+
+  /// whether to use only already installed applications, without accessing the network
+  pub offline: bool,
+
+  /// the platform to install the apps for, None for the platform in the environment or of this machine
+  pub platform: Option<Platform>,
+
+  /// folder to copy the installed executables into
+  pub to: Option<PathBuf>,
+
+  pub verbose: bool,
+}
+
+/// provides the names of the apps that the given apps need to run or install
+fn carriers(apps: &[&dyn AppDefinition], platform: Platform) -> Vec<ApplicationName> {
+  let mut result = vec![];
+  for app in apps {
+    let carrier = match app.run_method(&Version::from("*"), platform) {
+      RunMethod::ThisApp { install_methods } => install_methods
+        .iter()
+        .any(|method| matches!(method, Method::CompileGoSource { import_path: _ }))
+        .then(|| Go {}.name()),
+      RunMethod::OtherAppOtherExecutable { carrier, executable_name: _ } | RunMethod::OtherAppShellScript { carrier, script_name: _ } => Some(carrier.name()),
+      RunMethod::NodeJS { package: _, script: _ } => Some(NodeJS {}.name()),
+    };
+    if let Some(carrier) = carrier
+      && !result.contains(&carrier)
+    {
+      result.push(carrier);
+    }
+  }
+  result
+}
+
+/// installs the given apps using the given number of parallel workers
+fn install_concurrently(apps_to_install: Vec<&dyn AppDefinition>, jobs: usize, apps: &Apps, ctx: &RuntimeContext) -> Result<()> {
+  let jobs = jobs.min(apps_to_install.len());
+  let queue = Mutex::new(apps_to_install.into_iter());
+  let failed = AtomicBool::new(false);
+  thread::scope(|scope| {
+    let workers: Vec<_> = (0..jobs)
+      .map(|_| {
+        scope.spawn(|| {
+          while !failed.load(Ordering::Relaxed) {
+            let Some(app) = queue.lock().unwrap_or_else(PoisonError::into_inner).next() else {
+              break;
+            };
+            logging::start_concurrent_job(&app.name());
+            if let Err(err) = load_or_install_apps(vec![app], apps, true, ctx) {
+              // let the other workers finish their current installation but not start new ones
+              failed.store(true, Ordering::Relaxed);
+              return Err(err);
+            }
+          }
+          Ok(())
+        })
+      })
+      .collect();
+    let mut result = Ok(());
+    for worker in workers {
+      match worker.join() {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => {
+          if result.is_ok() {
+            result = Err(err);
+          }
+        }
+        Err(panic) => std::panic::resume_unwind(panic),
+      }
+    }
+    result
+  })
+}
+
+/// how many apps to install at the same time if the user doesn't specify this
+fn default_jobs() -> NonZeroUsize {
+  thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod carriers {
+    use super::super::carriers;
+    use crate::applications::{self, AppDefinition, ApplicationName};
+    use crate::platform::{Cpu, Os, Platform};
+
+    const PLATFORM: Platform = Platform {
+      os: Os::Linux,
+      cpu: Cpu::Intel64,
+    };
+
+    #[test]
+    fn nodejs_package() {
+      let apps = applications::all();
+      let give: Vec<&dyn AppDefinition> = vec![apps.lookup("dprint").unwrap(), apps.lookup("prettier").unwrap()];
+      let have = carriers(&give, PLATFORM);
+      assert_eq!(have, vec![ApplicationName::from("node")]);
+    }
+
+    #[test]
+    fn carrier_app() {
+      let apps = applications::all();
+      let give: Vec<&dyn AppDefinition> = vec![apps.lookup("npm").unwrap(), apps.lookup("npx").unwrap()];
+      let have = carriers(&give, PLATFORM);
+      assert_eq!(have, vec![ApplicationName::from("node")]);
+    }
+
+    #[test]
+    fn go_source() {
+      let apps = applications::all();
+      let give: Vec<&dyn AppDefinition> = vec![apps.lookup("actionlint").unwrap(), apps.lookup("gofumpt").unwrap()];
+      let have = carriers(&give, PLATFORM);
+      assert_eq!(have, vec![ApplicationName::from("go")]);
+    }
+
+    #[test]
+    fn no_carriers() {
+      let apps = applications::all();
+      let give: Vec<&dyn AppDefinition> = vec![apps.lookup("dprint").unwrap()];
+      let have = carriers(&give, PLATFORM);
+      assert!(have.is_empty());
+    }
+  }
+}
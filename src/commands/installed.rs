@@ -0,0 +1,229 @@
+use crate::applications::{ApplicationName, Apps};
+use crate::configuration::Version;
+use crate::error::Result;
+use crate::filesystem::{folder_size, format_size, subfolders};
+use crate::yard::{self, Yard};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// lists the app versions in the yard
+pub fn installed(args: &InstalledArgs, apps: &Apps) -> Result<ExitCode> {
+  let entries = match Yard::load(&yard::production_location()?)? {
+    Some(yard) => load_entries(&yard, apps)?,
+    None => vec![],
+  };
+  if args.json {
+    println!("{}", to_json(&entries));
+    return Ok(ExitCode::SUCCESS);
+  }
+  let now = SystemTime::now();
+  let name_width = entries.iter().map(|entry| entry.app_name.len()).max().unwrap_or_default();
+  let version_width = entries.iter().map(|entry| entry.version.as_str().len()).max().unwrap_or_default();
+  for entry in &entries {
+    let status = if entry.not_installable {
+      String::from("not installable")
+    } else {
+      match entry.last_used {
+        Some(last_used) => format!("last used {}", format_age(now, last_used)),
+        None => String::from("never used"),
+      }
+    };
+    println!(
+      "{:<name_width$}  {:<version_width$}  {:>9}  {status}",
+      entry.app_name.as_str(),
+      entry.version.as_str(),
+      format_size(entry.size)
+    );
+  }
+  Ok(ExitCode::SUCCESS)
+}
+
+/// named arguments for the [`installed`] command
+#[derive(Debug, PartialEq)]
+pub struct InstalledArgs {
+  /// whether to print machine-readable output
+  pub json: bool,
+}
+
+/// an app version in the yard
+#[derive(Debug, PartialEq)]
+struct Entry {
+  app_name: ApplicationName,
+  version: Version,
+  path: PathBuf,
+  /// disk space used by this app version, in bytes
+  size: u64,
+  /// whether this entry only marks the app version as not installable on this platform
+  not_installable: bool,
+  /// when this app version was last used, None if it was never used
+  last_used: Option<SystemTime>,
+}
+
+/// provides the app versions in the given yard, sorted by app name and version
+fn load_entries(yard: &Yard, apps: &Apps) -> Result<Vec<Entry>> {
+  let mut result = vec![];
+  for (folder_name, path) in subfolders(&yard.apps_folder())? {
+    let Some((app_name, version)) = yard::parse_app_version(&folder_name) else {
+      continue;
+    };
+    // ignore leftovers of apps that this version of run-that-app doesn't know, "rta --gc" deletes them
+    let Ok(app) = apps.lookup(&app_name) else {
+      continue;
+    };
+    let app_name = app.name();
+    result.push(Entry {
+      size: folder_size(&path),
+      not_installable: yard.is_not_installable(&app_name, &version),
+      last_used: yard.last_used(&app_name, &version),
+      app_name,
+      version,
+      path,
+    });
+  }
+  result.sort_by(|a, b| (a.app_name.as_str(), &a.version).cmp(&(b.app_name.as_str(), &b.version)));
+  Ok(result)
+}
+
+fn to_json(entries: &[Entry]) -> Value {
+  let entries: Vec<Value> = entries
+    .iter()
+    .map(|entry| {
+      json!({
+        "app": entry.app_name.as_str(),
+        "version": entry.version.as_str(),
+        "path": entry.path.to_string_lossy(),
+        "size": entry.size,
+        "not_installable": entry.not_installable,
+        "last_used": entry.last_used.and_then(|time| time.duration_since(UNIX_EPOCH).ok()).map(|duration| duration.as_secs()),
+      })
+    })
+    .collect();
+  Value::Array(entries)
+}
+
+/// describes for humans how long ago the given time was
+fn format_age(now: SystemTime, then: SystemTime) -> String {
+  let seconds = now.duration_since(then).unwrap_or_default().as_secs();
+  let (amount, unit) = match seconds {
+    0..60 => return String::from("just now"),
+    60..3600 => (seconds / 60, "minute"),
+    3600..86400 => (seconds / 3600, "hour"),
+    _ => (seconds / 86400, "day"),
+  };
+  if amount == 1 {
+    format!("1 {unit} ago")
+  } else {
+    format!("{amount} {unit}s ago")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod load_entries {
+    use super::super::{Entry, load_entries, to_json};
+    use crate::applications;
+    use crate::yard::Yard;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn normal() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let apps = applications::all();
+      let shfmt_folder = yard.create_app_folder(&"shfmt".into(), &"3.7.0".into()).unwrap();
+      fs::write(shfmt_folder.join("shfmt"), vec![0; 10]).unwrap();
+      yard.mark_not_installable(&"shellcheck".into(), &"0.9.0".into()).unwrap();
+      fs::create_dir_all(yard.apps_folder().join("zonk@1.0.0")).unwrap();
+      let have = load_entries(&yard, &apps).unwrap();
+      let want = vec![
+        Entry {
+          app_name: "shellcheck".into(),
+          version: "0.9.0".into(),
+          path: yard.apps_folder().join("shellcheck@0.9.0"),
+          size: 0,
+          not_installable: true,
+          last_used: None,
+        },
+        Entry {
+          app_name: "shfmt".into(),
+          version: "3.7.0".into(),
+          path: shfmt_folder.clone(),
+          size: 10,
+          not_installable: false,
+          last_used: None,
+        },
+      ];
+      pretty::assert_eq!(have, want);
+      let have = to_json(&have);
+      let want = json!([
+        {
+          "app": "shellcheck",
+          "version": "0.9.0",
+          "path": yard.apps_folder().join("shellcheck@0.9.0").to_string_lossy(),
+          "size": 0,
+          "not_installable": true,
+          "last_used": null,
+        },
+        {
+          "app": "shfmt",
+          "version": "3.7.0",
+          "path": shfmt_folder.to_string_lossy(),
+          "size": 10,
+          "not_installable": false,
+          "last_used": null,
+        },
+      ]);
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn used() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let apps = applications::all();
+      yard.create_app_folder(&"shfmt".into(), &"3.7.0".into()).unwrap();
+      yard.mark_used(&"shfmt".into(), &"3.7.0".into());
+      let have = load_entries(&yard, &apps).unwrap();
+      assert!(have[0].last_used.is_some());
+    }
+  }
+
+  mod format_age {
+    use super::super::format_age;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn just_now() {
+      let now = SystemTime::now();
+      assert_eq!(format_age(now, now - Duration::from_secs(5)), "just now");
+    }
+
+    #[test]
+    fn minutes() {
+      let now = SystemTime::now();
+      assert_eq!(format_age(now, now - Duration::from_mins(5)), "5 minutes ago");
+    }
+
+    #[test]
+    fn one_hour() {
+      let now = SystemTime::now();
+      assert_eq!(format_age(now, now - Duration::from_mins(90)), "1 hour ago");
+    }
+
+    #[test]
+    fn days() {
+      let now = SystemTime::now();
+      assert_eq!(format_age(now, now - Duration::from_hours(24 * 12)), "12 days ago");
+    }
+
+    #[test]
+    fn future() {
+      let now = SystemTime::now();
+      assert_eq!(format_age(now, now + Duration::from_mins(5)), "just now");
+    }
+  }
+}
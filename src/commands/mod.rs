@@ -3,26 +3,45 @@
 mod add;
 pub(crate) mod applications;
 mod available;
+mod bundle;
+mod copy_executables;
+mod env;
+mod gc;
 mod help;
+mod import;
 mod install;
 mod install_all;
+mod installed;
+mod outdated;
 mod reinstall;
+mod release_notes;
 mod run;
+mod shims;
 mod test;
 mod update;
 mod version;
 mod versions;
 mod which;
+mod which_config;
 
 pub use add::{AddArgs, add};
 pub use available::{AvailableArgs, available};
+pub use bundle::{BundleArgs, bundle};
+pub use env::{EnvArgs, Shell, env};
+pub use gc::{GcArgs, gc};
 pub(crate) use help::help;
+pub use import::{ImportArgs, import};
 pub use install::{InstallArgs, install};
-pub use install_all::install_all;
+pub use install_all::{InstallAllArgs, install_all};
+pub use installed::{InstalledArgs, installed};
+pub use outdated::{OutdatedArgs, outdated};
 pub use reinstall::reinstall;
+pub use release_notes::ReleaseNotesOutput;
 pub use run::{RunArgs, run};
+pub use shims::{ShimsArgs, shims};
 pub use test::{TestArgs, test};
-pub use update::{UpdateArgs, update};
+pub use update::{UpdateArgs, UpdatePolicy, update};
 pub use version::version;
 pub use versions::{VersionsArgs, versions};
 pub use which::{WhichArgs, which};
+pub use which_config::which_config;
@@ -0,0 +1,239 @@
+use super::update::{UpdatePolicy, newest_version};
+use crate::applications::{self, AnalyzeResult, AppDefinition, Apps};
+use crate::configuration::{self, RequestedVersion, Version};
+use crate::context::{self, RuntimeContext};
+use crate::error::Result;
+use crate::executables::load_from_path;
+use crate::yard::{self, VersionsCache, Yard};
+use crate::{logging, platform};
+use serde_json::{Value, json};
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+/// lists the configured apps that have newer versions available, without changing anything
+pub fn outdated(args: &OutdatedArgs, apps: &Apps) -> Result<ExitCode> {
+  let log = logging::new(args.verbose);
+  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let cache = VersionsCache::new(&yard, args.refresh, context::offline(args.offline))?;
+  let platform = platform::detect(log)?;
+  let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
+  let ctx = RuntimeContext {
+    platform,
+    yard: &yard,
+    config_file: &config_file,
+    lock_file: &lock_file,
+    log,
+    offline: context::offline(args.offline),
+    cross_platform: false,
+    bundle: None,
+  };
+  let mut rows = vec![];
+  for app_versions in config_file.all_apps() {
+    let app = apps.lookup(&app_versions.app_name)?;
+    let pinned = app_versions.versions.largest_yard().cloned();
+    let installed = app_versions
+      .versions
+      .0
+      .iter()
+      .filter_map(|version| match version {
+        RequestedVersion::Yard(version) if is_installed(app, version, &ctx) => Some(version.clone()),
+        RequestedVersion::Yard(_) | RequestedVersion::Path(_) | RequestedVersion::PathAuto => None,
+      })
+      .collect();
+    let newest = newest_version(app, pinned.as_ref(), UpdatePolicy::Major, &cache, log)?;
+    let system = system_version(app, &app_versions.versions, &ctx)?;
+    rows.push(Row {
+      app: app.name().as_str().to_string(),
+      pinned,
+      installed,
+      newest,
+      system,
+    });
+  }
+  if args.json {
+    println!("{}", to_json(&rows));
+  } else {
+    print!("{}", render(&rows));
+  }
+  if rows.iter().any(Row::is_behind) {
+    Ok(ExitCode::FAILURE)
+  } else {
+    Ok(ExitCode::SUCCESS)
+  }
+}
+
+/// named arguments for the [`outdated`] command
+#[derive(Debug, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct OutdatedArgs {
+  /// whether to print machine-readable output
+  pub json: bool,
+  pub offline: bool,
+  /// whether to ignore the cached versions
+  pub refresh: bool,
+  pub verbose: bool,
+}
+
+/// what the report knows about a configured app
+#[derive(Debug, PartialEq)]
+struct Row {
+  app: String,
+  /// the largest version in the config file, None if the config file only allows system installations
+  pinned: Option<Version>,
+  /// the versions from the config file that exist in the yard
+  installed: Vec<Version>,
+  /// the newest stable version that the project allows
+  newest: Version,
+  /// the version of the matching globally installed app, empty if its version is unknown
+  system: Option<String>,
+}
+
+impl Row {
+  fn is_behind(&self) -> bool {
+    self.pinned.as_ref().is_some_and(|pinned| pinned < &self.newest)
+  }
+}
+
+fn is_installed(app: &dyn AppDefinition, version: &Version, ctx: &RuntimeContext) -> bool {
+  let (carrier, executable_name) = applications::carrier(app, version, ctx.platform);
+  let executable = executable_name.platform_path(ctx.platform.os);
+  ctx.yard.load_executable(carrier.as_ref(), &executable, version, ctx).is_some()
+}
+
+/// provides the version of the globally installed app if the config file allows it
+fn system_version(app: &dyn AppDefinition, versions: &configuration::RequestedVersions, ctx: &RuntimeContext) -> Result<Option<String>> {
+  for version in versions {
+    let Some(range) = version.path_range(app, ctx)? else {
+      continue;
+    };
+    let executable_name = app.executable_filename().platform_path(ctx.platform.os);
+    let Some(executable) = load_from_path(app, &executable_name, &range, ctx)? else {
+      continue;
+    };
+    return Ok(Some(match app.analyze_executable(&executable)? {
+      AnalyzeResult::IdentifiedWithVersion(version) => version.to_string(),
+      AnalyzeResult::IdentifiedButUnknownVersion | AnalyzeResult::NotIdentified { output: _ } => String::new(),
+    }));
+  }
+  Ok(None)
+}
+
+/// formats the given rows as a table for humans
+fn render(rows: &[Row]) -> String {
+  let cells: Vec<[String; 6]> = rows
+    .iter()
+    .map(|row| {
+      [
+        row.app.clone(),
+        row.pinned.as_ref().map_or_else(|| String::from("-"), ToString::to_string),
+        if row.installed.is_empty() {
+          String::from("-")
+        } else {
+          row.installed.iter().map(Version::as_str).collect::<Vec<_>>().join(", ")
+        },
+        row.newest.to_string(),
+        match &row.system {
+          Some(version) if version.is_empty() => String::from("yes"),
+          Some(version) => version.clone(),
+          None => String::from("-"),
+        },
+        String::from(if row.is_behind() { "outdated" } else { "up to date" }),
+      ]
+    })
+    .collect();
+  let header = ["APP", "PINNED", "INSTALLED", "NEWEST", "SYSTEM", "STATUS"].map(String::from);
+  let mut widths = header.clone().map(|cell| cell.len());
+  for row in &cells {
+    for (width, cell) in widths.iter_mut().zip(row) {
+      *width = (*width).max(cell.len());
+    }
+  }
+  let mut result = String::new();
+  for row in std::iter::once(&header).chain(&cells) {
+    let line: Vec<String> = row.iter().zip(widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+    result.push_str(line.join("  ").trim_end());
+    result.push('\n');
+  }
+  result
+}
+
+fn to_json(rows: &[Row]) -> Value {
+  let rows: Vec<Value> = rows
+    .iter()
+    .map(|row| {
+      json!({
+        "app": row.app,
+        "pinned": row.pinned.as_ref().map(Version::as_str),
+        "installed": row.installed.iter().map(Version::as_str).collect::<Vec<_>>(),
+        "newest": row.newest.as_str(),
+        "system": row.system,
+        "outdated": row.is_behind(),
+      })
+    })
+    .collect();
+  Value::Array(rows)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod render {
+    use super::super::{Row, render};
+
+    #[test]
+    fn table() {
+      let rows = vec![
+        Row {
+          app: "actionlint".into(),
+          pinned: Some("1.6.26".into()),
+          installed: vec!["1.6.26".into()],
+          newest: "1.7.7".into(),
+          system: None,
+        },
+        Row {
+          app: "go".into(),
+          pinned: Some("1.22.3".into()),
+          installed: vec![],
+          newest: "1.22.3".into(),
+          system: Some("1.21.0".into()),
+        },
+      ];
+      let want = "
+APP         PINNED  INSTALLED  NEWEST  SYSTEM  STATUS
+actionlint  1.6.26  1.6.26     1.7.7   -       outdated
+go          1.22.3  -          1.22.3  1.21.0  up to date
+";
+      pretty::assert_eq!(render(&rows), want.trim_start());
+    }
+  }
+
+  mod is_behind {
+    use super::super::Row;
+
+    fn row(pinned: Option<&str>, newest: &str) -> Row {
+      Row {
+        app: "shellcheck".into(),
+        pinned: pinned.map(Into::into),
+        installed: vec![],
+        newest: newest.into(),
+        system: None,
+      }
+    }
+
+    #[test]
+    fn behind() {
+      assert!(row(Some("0.9.0"), "0.10.0").is_behind());
+    }
+
+    #[test]
+    fn current() {
+      assert!(!row(Some("0.10.0"), "0.10.0").is_behind());
+    }
+
+    #[test]
+    fn only_system() {
+      assert!(!row(None, "0.10.0").is_behind());
+    }
+  }
+}
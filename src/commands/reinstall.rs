@@ -2,10 +2,14 @@ use crate::applications::Apps;
 use crate::commands::install;
 use crate::error::Result;
 use crate::yard::{self, Yard};
+use crate::{logging, platform};
 use std::process::ExitCode;
 
 pub fn reinstall(args: install::InstallArgs, apps: &Apps) -> Result<ExitCode> {
-  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let log = logging::new(args.verbose);
+  let host = platform::detect(log)?;
+  let target = platform::target(args.platform, host)?;
+  let yard = Yard::load_or_create(&yard::location(target, host)?)?;
   yard.delete_app_folders(&args.app_name, args.version.as_ref())?;
   install(args, apps)
 }
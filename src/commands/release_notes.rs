@@ -0,0 +1,96 @@
+//! collects the release notes of app updates
+
+use crate::applications::AppDefinition;
+use crate::configuration::Version;
+use crate::hosting::github_releases::{self, Release};
+use crate::logging::Log;
+use std::path::PathBuf;
+
+/// how many releases to look at when collecting release notes
+const RELEASES_AMOUNT: usize = 100;
+
+/// where to put the release notes of the updated apps
+#[derive(Debug, PartialEq)]
+pub enum ReleaseNotesOutput {
+  /// print them to STDOUT
+  Print,
+  /// write them into the Markdown file with the given path
+  File(PathBuf),
+}
+
+/// provides the release notes for updating the given app from the given old to the given new version, in Markdown
+pub fn section(app: &dyn AppDefinition, old: &Version, new: &Version, log: Log) -> String {
+  let title = format!("## {} {old} -> {new}\n\n", app.name());
+  let Some((org, repo)) = app.github_repo() else {
+    return format!("{title}No release notes available, see {}\n", app.homepage());
+  };
+  match github_releases::releases(org, repo, RELEASES_AMOUNT, &app.tag_format(), log) {
+    Ok(releases) => format_section(&title, &releases, old, new),
+    Err(_) => format!("{title}Cannot load the release notes, see https://github.com/{org}/{repo}/releases\n"),
+  }
+}
+
+/// formats the notes of the given releases that are newer than the old version and not newer than the new version
+fn format_section(title: &str, releases: &[Release], old: &Version, new: &Version) -> String {
+  let mut releases: Vec<&Release> = releases.iter().filter(|release| &release.version > old && &release.version <= new).collect();
+  releases.sort_by(|a, b| b.version.cmp(&a.version));
+  if releases.is_empty() {
+    return format!("{title}No release notes found.\n");
+  }
+  let notes: Vec<String> = releases
+    .into_iter()
+    .map(|release| {
+      let body = release.body.trim();
+      let body = if body.is_empty() { "No description." } else { body };
+      format!("### [{}]({})\n\n{body}\n\n", release.tag, release.url)
+    })
+    .collect();
+  format!("{title}{}", notes.concat())
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod format_section {
+    use super::super::format_section;
+    use crate::hosting::github_releases::Release;
+
+    fn release(version: &str, body: &str) -> Release {
+      Release {
+        version: version.into(),
+        tag: format!("v{version}"),
+        body: body.into(),
+        url: format!("https://github.com/rhysd/actionlint/releases/tag/v{version}"),
+      }
+    }
+
+    #[test]
+    fn releases_between() {
+      let releases = vec![
+        release("1.7.8", "too new"),
+        release("1.7.7", "- fix crash"),
+        release("1.7.0", ""),
+        release("1.6.26", "already installed"),
+      ];
+      let have = format_section("## actionlint 1.6.26 -> 1.7.7\n\n", &releases, &"1.6.26".into(), &"1.7.7".into());
+      let want = "## actionlint 1.6.26 -> 1.7.7
+
+### [v1.7.7](https://github.com/rhysd/actionlint/releases/tag/v1.7.7)
+
+- fix crash
+
+### [v1.7.0](https://github.com/rhysd/actionlint/releases/tag/v1.7.0)
+
+No description.
+
+";
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn no_releases() {
+      let have = format_section("## actionlint 1.6.26 -> 1.7.7\n\n", &[], &"1.6.26".into(), &"1.7.7".into());
+      assert_eq!(have, "## actionlint 1.6.26 -> 1.7.7\n\nNo release notes found.\n");
+    }
+  }
+}
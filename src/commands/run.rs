@@ -16,6 +16,7 @@ pub fn run(args: RunArgs, apps: &Apps) -> Result<ExitCode> {
     from_source: args.from_source,
     include_apps,
     optional: args.optional,
+    offline: args.offline,
     verbose: args.verbose,
   };
   let Some(cmd_info) = get_cmd(get_cmd_args)? else {
@@ -58,6 +59,9 @@ pub struct RunArgs {
   /// whether it's okay to not run the app if it cannot be installed
   pub optional: bool,
 
+  /// whether to use only already installed applications, without accessing the network
+  pub offline: bool,
+
   pub verbose: bool,
 
   /// optional working directory in which to execute the app
@@ -0,0 +1,228 @@
+use super::env::posix_quote;
+use crate::applications::{AppDefinition, Apps};
+use crate::error::{Result, UserError};
+use crate::executables::SHIM_MARKER;
+use crate::platform::{self, Os};
+use crate::{configuration, filesystem, logging};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs};
+
+/// creates wrapper scripts in the given folder that run the configured apps via run-that-app
+pub fn shims(args: &ShimsArgs, apps: &Apps) -> Result<ExitCode> {
+  let Some(config_file) = configuration::File::read(apps)? else {
+    return Err(UserError::NoConfigFile);
+  };
+  let log = logging::new(args.verbose);
+  let os = platform::detect(log)?.os;
+  let rta = env::current_exe().map_err(|err| UserError::CannotDetermineExecutablePath(err.to_string()))?;
+  let mut configured = vec![];
+  for app_versions in config_file.all_apps() {
+    configured.push(apps.lookup(&app_versions.app_name)?);
+  }
+  let shims = shim_files(&configured, &rta.to_string_lossy(), os);
+  fs::create_dir_all(&args.folder).map_err(|err| UserError::CannotCreateFolder {
+    folder: args.folder.clone(),
+    reason: err.to_string(),
+  })?;
+  for stale in stale_shims(&args.folder, &shims)? {
+    fs::remove_file(&stale).map_err(|err| UserError::CannotDeleteFile {
+      file: stale.clone(),
+      err: err.to_string(),
+    })?;
+    println!("removed {}", stale.display());
+  }
+  for shim in &shims {
+    let path = args.folder.join(&shim.filename);
+    fs::write(&path, &shim.content).map_err(|err| UserError::CannotCreateFile {
+      filename: path.clone(),
+      err: err.to_string(),
+    })?;
+    filesystem::set_executable_bit(&path);
+    println!("created {}", path.display());
+  }
+  Ok(ExitCode::SUCCESS)
+}
+
+/// named arguments for the [`shims`] command
+#[derive(Debug, PartialEq)]
+pub struct ShimsArgs {
+  /// the folder to create the shims in
+  pub folder: PathBuf,
+  pub verbose: bool,
+}
+
+/// a wrapper script that runs an executable via run-that-app
+#[derive(Debug, PartialEq)]
+struct Shim {
+  filename: String,
+  content: String,
+}
+
+/// provides the shims for the executables of the given apps
+fn shim_files(apps: &[&dyn AppDefinition], rta: &str, os: Os) -> Vec<Shim> {
+  let mut result: Vec<Shim> = vec![];
+  for app in apps {
+    let app_name = app.name();
+    let mut executables = vec![(app.executable_filename().to_string(), None)];
+    for additional in app.additional_executables() {
+      executables.push((additional.to_string(), Some(additional.platform_path(os).to_string())));
+    }
+    for (name, additional) in executables {
+      let filename = match os {
+        Os::Linux | Os::MacOS => name,
+        Os::Windows => format!("{name}.cmd"),
+      };
+      // an earlier app already provides this executable
+      if result.iter().any(|shim| shim.filename == filename) {
+        continue;
+      }
+      let content = match (os, additional) {
+        (Os::Linux | Os::MacOS, None) => format!(
+          "#!/bin/sh\n# {SHIM_MARKER}\nexec {} {} \"$@\"\n",
+          posix_quote(rta),
+          posix_quote(app_name.as_str())
+        ),
+        (Os::Linux | Os::MacOS, Some(executable)) => format!(
+          "#!/bin/sh\n# {SHIM_MARKER}\napp_path=$({} --which {}) || exit 1\nexec \"$(dirname \"$app_path\")\"/{} \"$@\"\n",
+          posix_quote(rta),
+          posix_quote(app_name.as_str()),
+          posix_quote(&executable)
+        ),
+        (Os::Windows, None) => format!("@echo off\r\nrem {SHIM_MARKER}\r\n{} {} %*\r\n", cmd_quote(rta), cmd_quote(app_name.as_str())),
+        (Os::Windows, Some(executable)) => format!(
+          "@echo off\r\nrem {SHIM_MARKER}\r\nfor /f \"delims=\" %%i in ('{} --which {}') do set \"RTA_APP_FOLDER=%%~dpi\"\r\n\"%RTA_APP_FOLDER%{}\" %*\r\n",
+          cmd_quote(rta),
+          cmd_quote(app_name.as_str()),
+          cmd_escape(&executable)
+        ),
+      };
+      result.push(Shim { filename, content });
+    }
+  }
+  result
+}
+
+/// quotes the given text for Windows batch files
+fn cmd_quote(text: &str) -> String {
+  format!("\"{}\"", cmd_escape(text))
+}
+
+/// escapes the given text for use inside double quotes in Windows batch files
+fn cmd_escape(text: &str) -> String {
+  // batch files expand %...% even inside quotes, and double quotes cannot appear in Windows paths
+  text.replace('%', "%%").replace('"', "")
+}
+
+/// provides the shims in the given folder that this command created earlier for apps that are no longer configured
+fn stale_shims(folder: &Path, shims: &[Shim]) -> Result<Vec<PathBuf>> {
+  let entries = match fs::read_dir(folder) {
+    Ok(entries) => entries,
+    Err(err) => {
+      return Err(UserError::CannotReadFolder {
+        folder: folder.to_path_buf(),
+        err: err.to_string(),
+      });
+    }
+  };
+  let mut result = vec![];
+  for entry in entries.flatten() {
+    let path = entry.path();
+    let filename = entry.file_name().to_string_lossy().to_string();
+    if shims.iter().any(|shim| shim.filename == filename) {
+      continue;
+    }
+    // never delete files that this command didn't create
+    if fs::read_to_string(&path).is_ok_and(|content| content.contains(SHIM_MARKER)) {
+      result.push(path);
+    }
+  }
+  result.sort();
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod shim_files {
+    use super::super::{Shim, shim_files};
+    use crate::applications;
+    use crate::platform::Os;
+
+    #[test]
+    fn unix() {
+      let apps = applications::all();
+      let ripgrep = apps.lookup("ripgrep").unwrap();
+      let have = shim_files(&[ripgrep], "/usr/local/bin/rta", Os::Linux);
+      let want = vec![Shim {
+        filename: "rg".into(),
+        content: "#!/bin/sh\n# generated by run-that-app --shims\nexec '/usr/local/bin/rta' 'ripgrep' \"$@\"\n".into(),
+      }];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn special_characters() {
+      let apps = applications::all();
+      let ripgrep = apps.lookup("ripgrep").unwrap();
+      let have = shim_files(&[ripgrep], "/home/o'brien/$HOME/rta", Os::Linux);
+      let want = vec![Shim {
+        filename: "rg".into(),
+        content: "#!/bin/sh\n# generated by run-that-app --shims\nexec '/home/o'\\''brien/$HOME/rta' 'ripgrep' \"$@\"\n".into(),
+      }];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn windows_percent() {
+      let apps = applications::all();
+      let shellcheck = apps.lookup("shellcheck").unwrap();
+      let have = shim_files(&[shellcheck], r"C:\100%\rta.exe", Os::Windows);
+      let want = vec![Shim {
+        filename: "shellcheck.cmd".into(),
+        content: "@echo off\r\nrem generated by run-that-app --shims\r\n\"C:\\100%%\\rta.exe\" \"shellcheck\" %*\r\n".into(),
+      }];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn windows() {
+      let apps = applications::all();
+      let shellcheck = apps.lookup("shellcheck").unwrap();
+      let have = shim_files(&[shellcheck], r"C:\tools\rta.exe", Os::Windows);
+      let want = vec![Shim {
+        filename: "shellcheck.cmd".into(),
+        content: "@echo off\r\nrem generated by run-that-app --shims\r\n\"C:\\tools\\rta.exe\" \"shellcheck\" %*\r\n".into(),
+      }];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn duplicate_executables() {
+      let apps = applications::all();
+      let shellcheck = apps.lookup("shellcheck").unwrap();
+      let have = shim_files(&[shellcheck, shellcheck], "rta", Os::MacOS);
+      assert_eq!(have.len(), 1);
+    }
+  }
+
+  mod stale_shims {
+    use super::super::{Shim, stale_shims};
+    use std::fs;
+
+    #[test]
+    fn prunes_only_generated_files() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let folder = tempdir.path();
+      fs::write(folder.join("rg"), "#!/bin/sh\n# generated by run-that-app --shims\n").unwrap();
+      fs::write(folder.join("shfmt"), "#!/bin/sh\n# generated by run-that-app --shims\n").unwrap();
+      fs::write(folder.join("custom-tool"), "#!/bin/sh\necho hello\n").unwrap();
+      let shims = vec![Shim {
+        filename: "rg".into(),
+        content: String::new(),
+      }];
+      let have = stale_shims(folder, &shims).unwrap();
+      assert_eq!(have, vec![folder.join("shfmt")]);
+    }
+  }
+}
@@ -1,12 +1,15 @@
 use crate::applications::{AnalyzeResult, ApplicationName, Apps};
+use crate::configuration::Version;
 use crate::context::RuntimeContext;
 use crate::error::{Result, UserError};
 use crate::logging::Event;
 use crate::yard::Yard;
 use crate::{configuration, installation, logging, platform};
 use colored::Colorize;
+use serde_json::{Value, json};
 use std::io;
 use std::process::ExitCode;
+use std::sync::Mutex;
 
 pub fn test(args: &mut TestArgs, apps: &Apps) -> Result<ExitCode> {
   find_duplicate_app_names(apps)?;
@@ -15,12 +18,18 @@ pub fn test(args: &mut TestArgs, apps: &Apps) -> Result<ExitCode> {
   let temp_folder = tempfile::tempdir().map_err(|err| UserError::CannotCreateTempDir { err: err.to_string() })?;
   let yard = Yard::load_or_create(temp_folder.path())?;
   let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::default());
   let ctx = RuntimeContext {
     platform,
     yard: &yard,
     config_file: &config_file,
+    lock_file: &lock_file,
     log,
+    offline: false,
+    cross_platform: false,
+    bundle: None,
   };
+  let mut report = Report::new(args.json);
   for app in apps {
     if let Some(start_app_name) = &args.start_at_app {
       if &app.name() != start_app_name {
@@ -37,7 +46,14 @@ pub fn test(args: &mut TestArgs, apps: &Apps) -> Result<ExitCode> {
         method: &install_method,
         version: &latest_version,
       });
+      let mut result = TestResult {
+        app: app.name(),
+        version: latest_version.clone(),
+        install_method: install_method.lock_name(),
+        outcome: Outcome::NotInstalled,
+      };
       if !installation::version_method(app.as_ref(), &install_method, &latest_version, args.optional, &ctx, apps)?.success() {
+        report.add(result);
         continue;
       }
       let app_folder = yard.app_folder(&app.name(), &latest_version);
@@ -51,42 +67,141 @@ pub fn test(args: &mut TestArgs, apps: &Apps) -> Result<ExitCode> {
         let executable = app.run_method(&latest_version, platform).executable(executable_path);
         match app.analyze_executable(&executable)? {
           AnalyzeResult::NotIdentified { output } => {
-            println!("executable {executable} not identified based on this output:\n\"{output}\"\nOUTPUT END");
-            return Ok(ExitCode::FAILURE);
+            report.print(|| println!("executable {executable} not identified based on this output:\n\"{output}\"\nOUTPUT END"));
+            result.outcome = Outcome::NotIdentified;
+            report.add(result);
+            return Ok(report.finish(ExitCode::FAILURE));
           }
           AnalyzeResult::IdentifiedButUnknownVersion => {
-            println!("{}", "executable identified".green());
+            report.print(|| println!("{}", "executable identified".green()));
+            result.outcome = Outcome::Identified;
           }
           AnalyzeResult::IdentifiedWithVersion(executable_version) if executable_version == latest_version => {
-            println!("{}", "executable has the correct version".green());
+            report.print(|| println!("{}", "executable has the correct version".green()));
+            result.outcome = Outcome::CorrectVersion;
           }
           AnalyzeResult::IdentifiedWithVersion(executable_version) => {
-            println!("executable has version {executable_version} but we installed version {latest_version}");
-            return Ok(ExitCode::FAILURE);
+            report.print(|| println!("executable has version {executable_version} but we installed version {latest_version}"));
+            result.outcome = Outcome::WrongVersion;
+            report.add(result);
+            return Ok(report.finish(ExitCode::FAILURE));
           }
         }
       }
       if !executable_found {
-        println!("executable for {} not found, press ENTER after inspecting the yard", app.name());
-        let mut buffer = String::new();
-        if let Err(err) = io::stdin().read_line(&mut buffer) {
-          eprintln!("Error: {err}");
+        result.outcome = Outcome::ExecutableNotFound;
+        report.add(result);
+        if !args.json {
+          println!("executable for {} not found, press ENTER after inspecting the yard", app.name());
+          let mut buffer = String::new();
+          if let Err(err) = io::stdin().read_line(&mut buffer) {
+            eprintln!("Error: {err}");
+          }
         }
-        return Ok(ExitCode::FAILURE);
+        return Ok(report.finish(ExitCode::FAILURE));
       }
+      report.add(result);
       yard.delete_app_version(&app.name(), &latest_version)?;
     }
   }
-  Ok(ExitCode::SUCCESS)
+  Ok(report.finish(ExitCode::SUCCESS))
 }
 
 #[derive(Debug, PartialEq)]
 pub struct TestArgs {
+  /// whether to print machine-readable output
+  pub json: bool,
   pub optional: bool,
   pub start_at_app: Option<ApplicationName>,
   pub verbose: bool,
 }
 
+/// the results of testing the installation methods of apps
+struct Report {
+  /// whether to print the results as JSON when done, instead of human-readable output while testing
+  json: bool,
+  results: Vec<TestResult>,
+}
+
+impl Report {
+  fn new(json: bool) -> Report {
+    Report { json, results: vec![] }
+  }
+
+  fn add(&mut self, result: TestResult) {
+    self.results.push(result);
+  }
+
+  /// prints the results if this report is in JSON mode and provides the given exit code
+  fn finish(self, exit_code: ExitCode) -> ExitCode {
+    if self.json {
+      println!("{}", results_json(&self.results));
+    }
+    exit_code
+  }
+
+  /// runs the given human-readable output only if this report is not in JSON mode
+  fn print(&self, f: impl FnOnce()) {
+    if !self.json {
+      f();
+    }
+  }
+}
+
+/// the result of testing an installation method of an app
+#[derive(Debug, PartialEq)]
+struct TestResult {
+  app: ApplicationName,
+  version: Version,
+  install_method: &'static str,
+  outcome: Outcome,
+}
+
+/// what happened when testing an installation method of an app
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Outcome {
+  /// the installation method didn't install the app
+  NotInstalled,
+  /// the installed executable identified as the app but didn't report its version
+  Identified,
+  /// the installed executable has the version that was installed
+  CorrectVersion,
+  /// the installed executable has a different version than the one that was installed
+  WrongVersion,
+  /// the installed executable doesn't identify as the app
+  NotIdentified,
+  /// the installation method didn't create the executable
+  ExecutableNotFound,
+}
+
+impl Outcome {
+  fn as_str(self) -> &'static str {
+    match self {
+      Outcome::NotInstalled => "not-installed",
+      Outcome::Identified => "identified",
+      Outcome::CorrectVersion => "correct-version",
+      Outcome::WrongVersion => "wrong-version",
+      Outcome::NotIdentified => "not-identified",
+      Outcome::ExecutableNotFound => "executable-not-found",
+    }
+  }
+}
+
+fn results_json(results: &[TestResult]) -> Value {
+  let results: Vec<Value> = results
+    .iter()
+    .map(|result| {
+      json!({
+        "app": result.app.as_str(),
+        "version": result.version.as_str(),
+        "install_method": result.install_method,
+        "outcome": result.outcome.as_str(),
+      })
+    })
+    .collect();
+  Value::Array(results)
+}
+
 fn find_duplicate_app_names(apps: &Apps) -> Result<()> {
   let mut names: Vec<ApplicationName> = vec![];
   for app in apps {
@@ -98,3 +213,46 @@ fn find_duplicate_app_names(apps: &Apps) -> Result<()> {
   }
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+
+  mod results_json {
+    use super::super::{Outcome, TestResult, results_json};
+    use serde_json::json;
+
+    #[test]
+    fn normal() {
+      let give = vec![
+        TestResult {
+          app: "shellcheck".into(),
+          version: "0.10.0".into(),
+          install_method: "download-archive",
+          outcome: Outcome::CorrectVersion,
+        },
+        TestResult {
+          app: "shellcheck".into(),
+          version: "0.10.0".into(),
+          install_method: "compile-rust-crate",
+          outcome: Outcome::NotInstalled,
+        },
+      ];
+      let have = results_json(&give);
+      let want = json!([
+        {
+          "app": "shellcheck",
+          "version": "0.10.0",
+          "install_method": "download-archive",
+          "outcome": "correct-version",
+        },
+        {
+          "app": "shellcheck",
+          "version": "0.10.0",
+          "install_method": "compile-rust-crate",
+          "outcome": "not-installed",
+        },
+      ]);
+      pretty::assert_eq!(have, want);
+    }
+  }
+}
@@ -1,17 +1,51 @@
-use crate::applications::Apps;
-use crate::configuration::File;
-use crate::error::Result;
-use crate::logging::{self, Event};
+use super::release_notes::{self, ReleaseNotesOutput};
+use crate::applications::{AppDefinition, ApplicationName, Apps};
+use crate::configuration::{File, LockFile, Version, VersionRange};
+use crate::context::{self, RuntimeContext};
+use crate::error::{Result, UserError};
+use crate::executables::{LoadOrInstallAppAndCarrierArgs, load_or_install_app_and_carrier};
+use crate::logging::{self, Event, Log};
+use crate::platform;
+use crate::yard::{self, VersionsCache, Yard};
+use serde_json::{Value, json};
+use std::fs;
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::{Mutex, PoisonError};
 
 pub fn update(args: &UpdateArgs, all_apps: &Apps) -> Result<ExitCode> {
   let mut config = File::load(all_apps)?;
+  for app_name in &args.apps {
+    if config.apps.iter().any(|app| &app.app_name == app_name) {
+      continue;
+    }
+    // inherited config files are shared with other projects, updating them is up to their own folder
+    if let Some(inherited) = inherited_from(&config, app_name) {
+      return Err(UserError::CannotUpdateInheritedApp {
+        app: app_name.clone(),
+        config_file: inherited,
+      });
+    }
+    return Err(UserError::NoVersionsFound { app: app_name.clone() });
+  }
   let log = logging::new(args.verbose);
+  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let offline = context::offline(args.offline);
+  let cache = VersionsCache::new(&yard, args.refresh, offline)?;
+  let mut updates = vec![];
+  let mut updated_apps = vec![];
+  let mut notes = vec![];
+  // update only the closest config file, the config files it inherits from might be shared with other projects
+  let before = config.to_string();
   for old_app in &mut config.apps {
+    if !args.apps.is_empty() && !args.apps.contains(&old_app.app_name) {
+      continue;
+    }
     let app = all_apps.lookup(&old_app.app_name)?;
     log(Event::UpdateBegin { app: &old_app.app_name });
-    let latest = app.latest_installable_version(log)?;
-    if let Some(previous) = &old_app.versions.update_largest_with(&latest) {
+    let latest = newest_version(app, old_app.versions.largest_yard(), args.policy, &cache, log)?;
+    let previous = old_app.versions.update_largest_with(&latest);
+    if let Some(previous) = &previous {
       log(Event::UpdateNewVersion {
         app: &app.name(),
         old_version: previous,
@@ -20,12 +54,465 @@ pub fn update(args: &UpdateArgs, all_apps: &Apps) -> Result<ExitCode> {
     } else {
       log(Event::UpdateAlreadyNewest { app: &app.name() });
     }
+    let app_notes = match (&previous, &args.release_notes) {
+      (Some(previous), Some(_)) => Some(release_notes::section(app, previous, &latest, log)),
+      _ => None,
+    };
+    updates.push(json!({
+      "app": app.name().as_str(),
+      "version": latest.as_str(),
+      // null if the app was already at the latest version
+      "previous_version": previous.as_ref().map(Version::as_str),
+      "release_notes": app_notes,
+    }));
+    notes.extend(app_notes);
+    if previous.is_some() {
+      updated_apps.push((app, latest));
+    }
+  }
+  if args.dry_run {
+    let changes = diff(&before, &config.to_string());
+    if !args.json && !changes.is_empty() {
+      let path = config.path.as_deref().map_or_else(String::new, |path| path.to_string_lossy().to_string());
+      println!("--- {path}\n+++ {path}");
+      for change in changes {
+        println!("{change}");
+      }
+    }
+  } else {
+    config.save()?;
+    lock_versions(&updated_apps, &config, &yard, offline, log, all_apps)?;
+  }
+  match &args.release_notes {
+    Some(ReleaseNotesOutput::File(path)) => {
+      fs::write(path, format!("# Release notes\n\n{}", notes.join("\n"))).map_err(|err| UserError::CannotCreateFile {
+        filename: path.clone(),
+        err: err.to_string(),
+      })?;
+    }
+    // in JSON mode, the release notes are part of the JSON output
+    Some(ReleaseNotesOutput::Print) if !args.json => {
+      for note in &notes {
+        println!("{note}");
+      }
+    }
+    Some(ReleaseNotesOutput::Print) | None => {}
+  }
+  if args.json {
+    println!("{}", Value::Array(updates));
   }
-  config.save()?;
   Ok(ExitCode::SUCCESS)
 }
 
+/// provides the path of the inherited config file that defines the given app
+fn inherited_from(config: &File, app_name: &ApplicationName) -> Option<PathBuf> {
+  let mut file = config.parent.as_deref();
+  while let Some(current) = file {
+    if current.apps.iter().any(|app| &app.app_name == app_name) {
+      return current.path.clone();
+    }
+    file = current.parent.as_deref();
+  }
+  None
+}
+
+/// installs the given new app versions for this platform to lock them,
+/// and removes the lockfile entries for the old versions
+fn lock_versions(updated_apps: &[(&dyn AppDefinition, Version)], config: &File, yard: &Yard, offline: bool, log: Log, all_apps: &Apps) -> Result<()> {
+  let lock_file = Mutex::new(LockFile::load(all_apps)?);
+  // without internet access we can only remove the old entries, the new versions get locked when installing them
+  if !offline {
+    let ctx = RuntimeContext {
+      platform: platform::detect(log)?,
+      yard,
+      config_file: config,
+      lock_file: &lock_file,
+      log,
+      offline,
+      cross_platform: false,
+      bundle: None,
+    };
+    for (app, version) in updated_apps {
+      load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
+        app: *app,
+        cli_version: Some(version),
+        optional: true,
+        from_source: false,
+        ctx: &ctx,
+        apps: all_apps,
+      })?;
+    }
+  }
+  lock_file.into_inner().unwrap_or_else(PoisonError::into_inner).save(config)
+}
+
 #[derive(Debug, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct UpdateArgs {
+  /// the apps to update, all apps if empty
+  pub apps: Vec<ApplicationName>,
+  /// only display the changes to the config files, don't save them
+  pub dry_run: bool,
+  /// whether to print machine-readable output
+  pub json: bool,
+  pub offline: bool,
+  /// how far to update the app versions
+  pub policy: UpdatePolicy,
+  /// whether to ignore the cached versions
+  pub refresh: bool,
+  /// where to put the release notes of the updated apps, None to not collect them
+  pub release_notes: Option<ReleaseNotesOutput>,
   pub verbose: bool,
 }
+
+/// how far `--update` may move the versions of apps
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UpdatePolicy {
+  /// only update to newer patch versions of the same minor version
+  Patch,
+  /// only update to newer minor and patch versions of the same major version
+  Minor,
+  /// update to the newest version
+  #[default]
+  Major,
+}
+
+/// provides the newest stable version of the given app that the given policy and the project's version files allow
+pub(super) fn newest_version(app: &dyn AppDefinition, current: Option<&Version>, policy: UpdatePolicy, cache: &VersionsCache, log: Log) -> Result<Version> {
+  if current.is_none() && policy != UpdatePolicy::Major {
+    // the minor and patch policies are relative to the pinned version
+    return Err(UserError::NoPinnedVersion { app: app.name() });
+  }
+  let allowed = app.allowed_versions(cache, log)?;
+  if policy == UpdatePolicy::Major && allowed.is_any() {
+    let latest = cache.latest_installable_version(app, log)?;
+    if lenient_semver(&latest).is_none_or(|semver| semver.pre.is_empty()) {
+      return Ok(latest);
+    }
+  }
+  let versions = cache.installable_versions(app, 100, log)?;
+  match (select_version(versions, current, policy, &allowed), current) {
+    (Some(version), _) => Ok(version),
+    // no newer version matches the policy --> stay at the current version
+    (None, Some(current)) => Ok(current.clone()),
+    (None, None) => Err(UserError::NoInstallableVersionAllowed { app: app.name(), allowed }),
+  }
+}
+
+/// selects the newest stable version that the given policy and version restrictions allow,
+/// but never a version older than the current one
+fn select_version(versions: Vec<Version>, current: Option<&Version>, policy: UpdatePolicy, allowed: &VersionRange) -> Option<Version> {
+  let current_semver = current.and_then(lenient_semver);
+  let newest = versions
+    .into_iter()
+    .filter(|version| match lenient_semver(version) {
+      Some(semver) => semver.pre.is_empty() && allowed.matches(&semver) && policy_allows(policy, current_semver.as_ref(), &semver),
+      // we can only reason about unusual version formats if there are no restrictions
+      None => policy == UpdatePolicy::Major && allowed.is_any(),
+    })
+    .max()?;
+  if let (Some(current), Some(newest_semver)) = (&current_semver, lenient_semver(&newest))
+    && &newest_semver < current
+  {
+    return None;
+  }
+  Some(newest)
+}
+
+fn policy_allows(policy: UpdatePolicy, current: Option<&semver::Version>, candidate: &semver::Version) -> bool {
+  match (policy, current) {
+    (UpdatePolicy::Major, _) => true,
+    (UpdatePolicy::Minor, Some(current)) => candidate.major == current.major,
+    (UpdatePolicy::Patch, Some(current)) => candidate.major == current.major && candidate.minor == current.minor,
+    // without knowing the current version we cannot apply the policy
+    (UpdatePolicy::Minor | UpdatePolicy::Patch, None) => false,
+  }
+}
+
+/// parses the given version as semver, tolerating a leading "v" and missing minor or patch numbers
+fn lenient_semver(version: &Version) -> Option<semver::Version> {
+  let text = version.as_str().strip_prefix('v').unwrap_or(version.as_str());
+  let (numbers, suffix) = text.find(['-', '+']).map_or((text, ""), |pos| text.split_at(pos));
+  let padded = match (numbers.split('.').count(), suffix) {
+    (1, "") => format!("{numbers}.0.0"),
+    (2, "") => format!("{numbers}.0"),
+    _ => text.to_string(),
+  };
+  semver::Version::parse(&padded).ok()
+}
+
+/// how many unchanged lines to show around changes
+const DIFF_CONTEXT: usize = 3;
+
+/// one step in transforming the old into the new config file content
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DiffOp<'a> {
+  Keep(&'a str),
+  Remove(&'a str),
+  Add(&'a str),
+}
+
+/// provides the changes between the given config file contents as unified diff hunks
+fn diff(before: &str, after: &str) -> Vec<String> {
+  let ops = diff_ops(&before.lines().collect::<Vec<_>>(), &after.lines().collect::<Vec<_>>());
+  let mut result = vec![];
+  // line numbers (0-based) in the old and new content at the current op
+  let mut old_line = 0;
+  let mut new_line = 0;
+  let mut index = 0;
+  while index < ops.len() {
+    if let DiffOp::Keep(_) = ops[index] {
+      old_line += 1;
+      new_line += 1;
+      index += 1;
+      continue;
+    }
+    // here a hunk starts, include the context lines before it
+    let context_before = index.min(DIFF_CONTEXT);
+    let start = index - context_before;
+    let mut end = index;
+    let mut unchanged = 0;
+    while end < ops.len() && unchanged <= 2 * DIFF_CONTEXT {
+      match ops[end] {
+        DiffOp::Keep(_) => unchanged += 1,
+        DiffOp::Remove(_) | DiffOp::Add(_) => unchanged = 0,
+      }
+      end += 1;
+    }
+    // keep only the context lines after the last change
+    end -= unchanged.saturating_sub(DIFF_CONTEXT);
+    let hunk = &ops[start..end];
+    let old_count = hunk.iter().filter(|op| !matches!(op, DiffOp::Add(_))).count();
+    let new_count = hunk.iter().filter(|op| !matches!(op, DiffOp::Remove(_))).count();
+    let old_start = old_line - context_before;
+    let new_start = new_line - context_before;
+    result.push(format!("@@ -{} +{} @@", hunk_range(old_start, old_count), hunk_range(new_start, new_count)));
+    for op in hunk {
+      match op {
+        DiffOp::Keep(line) => result.push(format!(" {line}")),
+        DiffOp::Remove(line) => result.push(format!("-{line}")),
+        DiffOp::Add(line) => result.push(format!("+{line}")),
+      }
+    }
+    old_line = old_start + old_count;
+    new_line = new_start + new_count;
+    index = end;
+  }
+  result
+}
+
+/// formats the line range of a hunk, given the 0-based start line and the number of lines
+fn hunk_range(start: usize, count: usize) -> String {
+  match count {
+    // empty ranges refer to the line before them
+    0 => format!("{start},0"),
+    1 => format!("{}", start + 1),
+    _ => format!("{},{count}", start + 1),
+  }
+}
+
+/// provides the steps to transform the given old lines into the given new lines, based on their longest common subsequence
+fn diff_ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffOp<'a>> {
+  // lengths[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+  let mut lengths = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+  for i in (0..old.len()).rev() {
+    for j in (0..new.len()).rev() {
+      lengths[i][j] = if old[i] == new[j] {
+        lengths[i + 1][j + 1] + 1
+      } else {
+        lengths[i + 1][j].max(lengths[i][j + 1])
+      };
+    }
+  }
+  let mut result = vec![];
+  let (mut i, mut j) = (0, 0);
+  while i < old.len() && j < new.len() {
+    if old[i] == new[j] {
+      result.push(DiffOp::Keep(old[i]));
+      i += 1;
+      j += 1;
+    } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+      result.push(DiffOp::Remove(old[i]));
+      i += 1;
+    } else {
+      result.push(DiffOp::Add(new[j]));
+      j += 1;
+    }
+  }
+  result.extend(old[i..].iter().map(|line| DiffOp::Remove(line)));
+  result.extend(new[j..].iter().map(|line| DiffOp::Add(line)));
+  result
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod select_version {
+    use super::super::{UpdatePolicy, select_version};
+    use crate::configuration::{Version, VersionRange};
+
+    fn versions() -> Vec<Version> {
+      vec![
+        "2.0.0-rc.1".into(),
+        "1.3.0".into(),
+        "1.2.5".into(),
+        "1.2.4".into(),
+        "1.1.0".into(),
+        "0.9.0".into(),
+      ]
+    }
+
+    #[test]
+    fn major() {
+      let have = select_version(versions(), Some(&"1.2.4".into()), UpdatePolicy::Major, &VersionRange::any());
+      assert_eq!(have, Some("1.3.0".into()));
+    }
+
+    #[test]
+    fn minor() {
+      let versions: Vec<Version> = vec!["2.1.0".into(), "1.3.0".into(), "1.2.5".into()];
+      let have = select_version(versions, Some(&"1.2.4".into()), UpdatePolicy::Minor, &VersionRange::any());
+      assert_eq!(have, Some("1.3.0".into()));
+    }
+
+    #[test]
+    fn patch() {
+      let have = select_version(versions(), Some(&"1.2.4".into()), UpdatePolicy::Patch, &VersionRange::any());
+      assert_eq!(have, Some("1.2.5".into()));
+    }
+
+    #[test]
+    fn allowed_versions() {
+      let allowed = VersionRange::parse("~1.1").unwrap();
+      let have = select_version(versions(), Some(&"0.9.0".into()), UpdatePolicy::Major, &allowed);
+      assert_eq!(have, Some("1.1.0".into()));
+    }
+
+    #[test]
+    fn no_downgrade() {
+      let have = select_version(versions(), Some(&"2.0.0".into()), UpdatePolicy::Major, &VersionRange::any());
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn leading_v() {
+      let versions: Vec<Version> = vec!["v1.3.0".into(), "v1.2.5".into()];
+      let have = select_version(versions, Some(&"v1.2.0".into()), UpdatePolicy::Patch, &VersionRange::any());
+      assert_eq!(have, Some("v1.2.5".into()));
+    }
+  }
+
+  mod inherited_from {
+    use super::super::inherited_from;
+    use crate::configuration::{AppVersions, File, RequestedVersion, RequestedVersions};
+    use std::path::PathBuf;
+
+    fn file(path: &str, app: &'static str, parent: Option<File>) -> File {
+      File {
+        path: Some(PathBuf::from(path)),
+        apps: vec![AppVersions {
+          app_name: app.into(),
+          versions: RequestedVersions::from(vec![RequestedVersion::Yard("1.0.0".into())]),
+        }],
+        inherit: parent.is_some(),
+        parent: parent.map(Box::new),
+      }
+    }
+
+    #[test]
+    fn in_parent() {
+      let root = file("/repo/run-that-app", "shellcheck", None);
+      let config = file("/repo/api/run-that-app", "actionlint", Some(root));
+      let have = inherited_from(&config, &"shellcheck".into());
+      assert_eq!(have, Some(PathBuf::from("/repo/run-that-app")));
+    }
+
+    #[test]
+    fn in_own_file() {
+      let root = file("/repo/run-that-app", "shellcheck", None);
+      let config = file("/repo/api/run-that-app", "actionlint", Some(root));
+      assert_eq!(inherited_from(&config, &"actionlint".into()), None);
+    }
+
+    #[test]
+    fn unknown() {
+      let config = file("/repo/run-that-app", "shellcheck", None);
+      assert_eq!(inherited_from(&config, &"gh".into()), None);
+    }
+  }
+
+  mod lenient_semver {
+    use super::super::lenient_semver;
+
+    #[test]
+    fn partial() {
+      assert_eq!(lenient_semver(&"1.20".into()), Some(semver::Version::new(1, 20, 0)));
+      assert_eq!(lenient_semver(&"v2".into()), Some(semver::Version::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn prerelease() {
+      let have = lenient_semver(&"v1.2.0-rc.1".into()).unwrap();
+      assert_eq!(have.pre.as_str(), "rc.1");
+    }
+
+    #[test]
+    fn unparseable() {
+      assert_eq!(lenient_semver(&"2024-01-15".into()), None);
+    }
+  }
+
+  mod diff {
+    use super::super::diff;
+
+    #[test]
+    fn changes() {
+      let before = "actionlint 1.6.26\nshellcheck 0.9.0\n";
+      let after = "actionlint 1.7.7\nshellcheck 0.9.0\n";
+      let want = vec!["@@ -1,2 +1,2 @@", "-actionlint 1.6.26", "+actionlint 1.7.7", " shellcheck 0.9.0"];
+      assert_eq!(diff(before, after), want);
+    }
+
+    #[test]
+    fn separate_hunks() {
+      let before = "a 1\nb 1\nc 1\nd 1\ne 1\nf 1\ng 1\nh 1\ni 1\nj 1\n";
+      let after = "a 2\nb 1\nc 1\nd 1\ne 1\nf 1\ng 1\nh 1\ni 1\nj 2\n";
+      let want = vec![
+        "@@ -1,4 +1,4 @@",
+        "-a 1",
+        "+a 2",
+        " b 1",
+        " c 1",
+        " d 1",
+        "@@ -7,4 +7,4 @@",
+        " g 1",
+        " h 1",
+        " i 1",
+        "-j 1",
+        "+j 2",
+      ];
+      assert_eq!(diff(before, after), want);
+    }
+
+    #[test]
+    fn merged_hunk() {
+      let before = "a 1\nb 1\nc 1\nd 1\n";
+      let after = "a 2\nb 1\nc 1\nd 2\n";
+      let want = vec!["@@ -1,4 +1,4 @@", "-a 1", "+a 2", " b 1", " c 1", "-d 1", "+d 2"];
+      assert_eq!(diff(before, after), want);
+    }
+
+    #[test]
+    fn added_line() {
+      let before = "go 1.22.3\n";
+      let after = "go 1.22.3 1.21.0\nshellcheck 0.9.0\n";
+      let want = vec!["@@ -1 +1,2 @@", "-go 1.22.3", "+go 1.22.3 1.21.0", "+shellcheck 0.9.0"];
+      assert_eq!(diff(before, after), want);
+    }
+
+    #[test]
+    fn no_changes() {
+      assert!(diff("go 1.22.3\n", "go 1.22.3\n").is_empty());
+    }
+  }
+}
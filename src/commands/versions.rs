@@ -1,12 +1,22 @@
 use crate::applications::{ApplicationName, Apps};
+use crate::configuration::Version;
 use crate::error::Result;
-use crate::logging;
+use crate::yard::{self, VersionsCache, Yard};
+use crate::{context, logging};
+use serde_json::json;
 use std::process::ExitCode;
 
 pub fn versions(args: &VersionsArgs, apps: &Apps) -> Result<ExitCode> {
   let app = apps.lookup(&args.app_name)?;
   let log = logging::new(args.verbose);
-  let versions = app.installable_versions(args.amount, log)?;
+  let yard = Yard::load_or_create(&yard::production_location()?)?;
+  let cache = VersionsCache::new(&yard, args.refresh, context::offline(args.offline))?;
+  let versions = cache.installable_versions(app, args.amount, log)?;
+  if args.json {
+    let versions: Vec<&str> = versions.iter().map(Version::as_str).collect();
+    println!("{}", json!({ "app": app.name().as_str(), "homepage": app.homepage(), "versions": versions }));
+    return Ok(ExitCode::SUCCESS);
+  }
   println!("{} is available in these versions:", args.app_name);
   for version in versions {
     println!("- {version}");
@@ -15,8 +25,14 @@ pub fn versions(args: &VersionsArgs, apps: &Apps) -> Result<ExitCode> {
 }
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct VersionsArgs {
   pub app_name: ApplicationName,
   pub amount: usize,
+  /// whether to print machine-readable output
+  pub json: bool,
+  pub offline: bool,
+  /// whether to ignore the cached versions
+  pub refresh: bool,
   pub verbose: bool,
 }
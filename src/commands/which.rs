@@ -1,17 +1,22 @@
-use crate::applications::{ApplicationName, Apps};
+use crate::applications::{AnalyzeResult, AppDefinition, ApplicationName, Apps};
 use crate::configuration::{self, Version};
-use crate::context::RuntimeContext;
+use crate::context::{self, RuntimeContext};
 use crate::error::Result;
-use crate::executables::{LoadOrInstallAppAndCarrierArgs, LoadOrInstallAppOutcome, load_or_install_app_and_carrier};
+use crate::executables::{Executable, LoadOrInstallAppAndCarrierArgs, LoadOrInstallAppOutcome, RunMethod, load_or_install_app_and_carrier};
 use crate::yard::Yard;
 use crate::{logging, platform, yard};
+use serde_json::{Value, json};
+use std::path::Path;
 use std::process::ExitCode;
+use std::sync::Mutex;
 
 pub fn which(
   WhichArgs {
     app_name,
+    json,
     optional,
     version,
+    offline,
     verbose,
   }: WhichArgs,
   apps: &Apps,
@@ -21,11 +26,16 @@ pub fn which(
   let yard = Yard::load_or_create(&yard::production_location()?)?;
   let platform = platform::detect(log)?;
   let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
   let ctx = RuntimeContext {
     platform,
     yard: &yard,
     config_file: &config_file,
+    lock_file: &lock_file,
     log,
+    offline: context::offline(offline),
+    cross_platform: false,
+    bundle: None,
   };
   match load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
     app,
@@ -36,17 +46,172 @@ pub fn which(
     apps,
   })? {
     LoadOrInstallAppOutcome::Loaded { executable } => {
-      println!("{executable}");
+      ctx.lock_file_mut().save(&config_file)?;
+      if json {
+        println!("{}", executable_json(app, Some(&executable), &ctx)?);
+      } else {
+        println!("{executable}");
+      }
       Ok(ExitCode::SUCCESS)
     }
-    LoadOrInstallAppOutcome::NotInstallable { app: _ } => Ok(ExitCode::FAILURE),
+    LoadOrInstallAppOutcome::NotInstallable { app: _ } => {
+      if json {
+        println!("{}", executable_json(app, None, &ctx)?);
+      }
+      Ok(ExitCode::FAILURE)
+    }
   }
 }
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct WhichArgs {
   pub app_name: ApplicationName,
+  /// whether to print machine-readable output
+  pub json: bool,
   pub optional: bool,
   pub version: Option<Version>,
+  pub offline: bool,
   pub verbose: bool,
 }
+
+/// describes the given executable of the given app in machine-readable form,
+/// all fields except the app are null if the app is not available
+pub(super) fn executable_json(app: &dyn AppDefinition, executable: Option<&Executable>, ctx: &RuntimeContext) -> Result<Value> {
+  let (version, install_method) = match executable {
+    Some(executable) => resolve_version(app, executable, ctx)?,
+    None => (None, None),
+  };
+  Ok(json!({
+    "app": app.name().as_str(),
+    "homepage": app.homepage(),
+    "version": version.as_ref().map(Version::as_str),
+    "executable": executable.map(|executable| executable.as_path().to_string_lossy()),
+    "install_method": install_method,
+  }))
+}
+
+/// determines the version of the given executable and, if run-that-app installed it, the method that installed it
+fn resolve_version(app: &dyn AppDefinition, executable: &Executable, ctx: &RuntimeContext) -> Result<(Option<Version>, Option<String>)> {
+  if let Some((_, version)) = yard_app_version(&ctx.yard.apps_folder(), executable.as_path()) {
+    let install_method = install_method(app, &version, ctx);
+    return Ok((Some(version), install_method));
+  }
+  // here the executable is installed globally, run-that-app didn't install it
+  match app.analyze_executable(executable)? {
+    AnalyzeResult::IdentifiedWithVersion(version) => Ok((Some(version), None)),
+    AnalyzeResult::IdentifiedButUnknownVersion | AnalyzeResult::NotIdentified { output: _ } => Ok((None, None)),
+  }
+}
+
+/// Provides the lockfile name of the method that installs the given app version.
+/// If the app has several installation methods, the lockfile tells which one got used,
+/// otherwise it's the one that run-that-app tries first.
+fn install_method(app: &dyn AppDefinition, version: &Version, ctx: &RuntimeContext) -> Option<String> {
+  let install_methods = match app.run_method(version, ctx.platform) {
+    RunMethod::OtherAppOtherExecutable { carrier, executable_name: _ } | RunMethod::OtherAppShellScript { carrier, script_name: _ } => {
+      return install_method(carrier.as_ref(), version, ctx);
+    }
+    run_method => run_method.install_methods(),
+  };
+  if let [install_method] = &install_methods[..] {
+    return Some(install_method.lock_name().to_string());
+  }
+  if let Some(entry) = ctx.lock_file_mut().lookup(&app.name(), version, ctx.platform) {
+    return Some(entry.method.clone());
+  }
+  install_methods.first().map(|install_method| install_method.lock_name().to_string())
+}
+
+/// provides the app name and version of the yard folder containing the given executable
+fn yard_app_version(apps_folder: &Path, executable: &Path) -> Option<(String, Version)> {
+  let folder_name = executable.strip_prefix(apps_folder).ok()?.components().next()?.as_os_str().to_str()?;
+  yard::parse_app_version(folder_name)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod install_method {
+    use super::super::install_method;
+    use crate::configuration::{self, LockFile};
+    use crate::context::RuntimeContext;
+    use crate::platform::{Cpu, Os, Platform};
+    use crate::yard::Yard;
+    use crate::{applications, logging};
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    fn have(app_name: &str, lock_file: LockFile) -> Option<String> {
+      let apps = applications::all();
+      let app = apps.lookup(app_name).unwrap();
+      let yard = Yard { root: PathBuf::new() };
+      let config_file = configuration::File::default();
+      let lock_file = Mutex::new(lock_file);
+      let ctx = RuntimeContext {
+        platform: Platform {
+          os: Os::Linux,
+          cpu: Cpu::Intel64,
+        },
+        yard: &yard,
+        config_file: &config_file,
+        lock_file: &lock_file,
+        log: logging::normal_log,
+        offline: false,
+        cross_platform: false,
+        bundle: None,
+      };
+      install_method(app, &"1.7.7".into(), &ctx)
+    }
+
+    #[test]
+    fn single_method() {
+      assert_eq!(have("shellcheck", LockFile::default()).as_deref(), Some("download-archive"));
+    }
+
+    #[test]
+    fn carrier_app() {
+      assert_eq!(have("npm", LockFile::default()).as_deref(), Some("download-archive"));
+    }
+
+    #[test]
+    fn multiple_methods_without_lockfile() {
+      assert_eq!(have("actionlint", LockFile::default()).as_deref(), Some("download-archive"));
+    }
+
+    #[test]
+    fn multiple_methods_from_lockfile() {
+      let mut lock_file = LockFile::default();
+      let apps = applications::all();
+      let app = apps.lookup("actionlint").unwrap();
+      let platform = Platform {
+        os: Os::Linux,
+        cpu: Cpu::Intel64,
+      };
+      let compile = app.run_method(&"1.7.7".into(), platform).install_methods().pop().unwrap();
+      lock_file.record(&"actionlint".into(), &"1.7.7".into(), platform, &compile);
+      assert_eq!(have("actionlint", lock_file).as_deref(), Some("compile-go-source"));
+    }
+  }
+
+  mod yard_app_version {
+    use super::super::yard_app_version;
+    use crate::configuration::Version;
+    use std::path::Path;
+
+    #[test]
+    fn in_yard() {
+      let have = yard_app_version(
+        Path::new("/home/user/.run-that-app/apps"),
+        Path::new("/home/user/.run-that-app/apps/node@20.1.0/bin/npm"),
+      );
+      assert_eq!(have, Some((String::from("node"), Version::from("20.1.0"))));
+    }
+
+    #[test]
+    fn outside_yard() {
+      let have = yard_app_version(Path::new("/home/user/.run-that-app/apps"), Path::new("/usr/bin/npm"));
+      assert_eq!(have, None);
+    }
+  }
+}
@@ -0,0 +1,15 @@
+use crate::applications::Apps;
+use crate::configuration;
+use crate::error::{Result, UserError};
+use std::process::ExitCode;
+
+/// displays the paths of the config files that apply to the current folder, closest first
+pub fn which_config(apps: &Apps) -> Result<ExitCode> {
+  let Some(config_file) = configuration::File::read(apps)? else {
+    return Err(UserError::NoConfigFile);
+  };
+  for path in config_file.paths() {
+    println!("{}", path.display());
+  }
+  Ok(ExitCode::SUCCESS)
+}
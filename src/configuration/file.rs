@@ -3,15 +3,25 @@ use crate::applications::{ApplicationName, Apps};
 use crate::error::{Result, UserError};
 use crate::filesystem;
 use std::fmt::Display;
-use std::fs::OpenOptions;
+use std::fs::{self, OpenOptions};
 use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
 use std::str::SplitAsciiWhitespace;
 
 const HEADER: &str = "# more info at https://github.com/kevgo/run-that-app\n\n";
 
+/// a line containing only this word makes a config file inherit the app versions from the config file in a parent folder
+const INHERIT: &str = "inherit";
+
 #[derive(Debug, Default, PartialEq)]
 pub struct File {
+  /// where this config file is stored, None if there is no config file
+  pub path: Option<PathBuf>,
   pub apps: Vec<AppVersions>,
+  /// whether this config file inherits the app versions from the config file in a parent folder
+  pub inherit: bool,
+  /// the config file this config file inherits from
+  pub parent: Option<Box<File>>,
 }
 
 impl File {
@@ -35,9 +45,10 @@ impl File {
       .map_err(|err| UserError::CannotAccessConfigFile(err.to_string()))
   }
 
+  /// reads the config file in the current folder or its closest parent folder
   pub fn read(apps: &Apps) -> Result<Option<File>> {
-    match filesystem::read_file(FILE_NAME)? {
-      Some(text) => Ok(Some(parse(&text, apps)?)),
+    match filesystem::find_file(FILE_NAME)? {
+      Some(path) => Ok(Some(Self::read_path(path, apps)?)),
       None => Ok(None),
     }
   }
@@ -46,6 +57,48 @@ impl File {
     Ok(Self::read(apps)?.unwrap_or_default())
   }
 
+  /// reads the config file in the given folder, without looking in parent folders
+  pub fn read_in(folder: &Path, apps: &Apps) -> Result<Option<File>> {
+    let path = folder.join(FILE_NAME);
+    if !path.is_file() {
+      return Ok(None);
+    }
+    Ok(Some(Self::read_path(path, apps)?))
+  }
+
+  /// reads the config file at the given path and the config files it inherits from
+  fn read_path(path: PathBuf, apps: &Apps) -> Result<File> {
+    let text = fs::read_to_string(&path).map_err(|err| UserError::CannotAccessConfigFile(err.to_string()))?;
+    let mut file = parse(&text, apps)?;
+    if file.inherit
+      && let Some(parent_path) = parent_path(&path)?
+    {
+      file.parent = Some(Box::new(Self::read_path(parent_path, apps)?));
+    }
+    file.path = Some(path);
+    Ok(file)
+  }
+
+  /// provides the app names in the config file at the given path and the config files it inherits from,
+  /// without checking that these apps exist
+  pub fn app_names(path: &Path) -> Result<Vec<String>> {
+    let text = fs::read_to_string(path).map_err(|err| UserError::CannotAccessConfigFile(err.to_string()))?;
+    let mut names = vec![];
+    let mut inherit = false;
+    for line in text.lines() {
+      let mut parts = LinePartsIterator::from(line.trim());
+      match parts.next() {
+        Some(INHERIT) if parts.next().is_none() => inherit = true,
+        Some(name) => names.push(name.to_string()),
+        None => {}
+      }
+    }
+    if inherit && let Some(parent_path) = parent_path(path)? {
+      names.extend(Self::app_names(&parent_path)?);
+    }
+    Ok(names)
+  }
+
   // METHODS
 
   pub fn add(mut self, app_name: ApplicationName, version: Version) -> Result<()> {
@@ -57,15 +110,46 @@ impl File {
     self.save()
   }
 
+  /// provides the app versions in this config file and the config files it inherits from,
+  /// app versions in this config file override the ones for the same app in inherited config files
+  pub fn all_apps(&self) -> Vec<&AppVersions> {
+    let mut result: Vec<&AppVersions> = self.apps.iter().collect();
+    if let Some(parent) = &self.parent {
+      for inherited in parent.all_apps() {
+        if !result.iter().any(|app| app.app_name == inherited.app_name) {
+          result.push(inherited);
+        }
+      }
+    }
+    result
+  }
+
   pub fn lookup(&self, app_name: &ApplicationName) -> Option<&RequestedVersions> {
-    self.apps.iter().find(|app| &app.app_name == app_name).map(|app_version| &app_version.versions)
+    match self.apps.iter().find(|app| &app.app_name == app_name) {
+      Some(app_versions) => Some(&app_versions.versions),
+      None => self.parent.as_ref().and_then(|parent| parent.lookup(app_name)),
+    }
+  }
+
+  /// provides the paths of this config file and the config files it inherits from, closest first
+  pub fn paths(&self) -> Vec<&Path> {
+    let mut result = vec![];
+    let mut file = Some(self);
+    while let Some(current) = file {
+      if let Some(path) = &current.path {
+        result.push(path.as_path());
+      }
+      file = current.parent.as_deref();
+    }
+    result
   }
 
+  /// saves this config file, without the config files it inherits from
   pub fn save(&self) -> Result<()> {
     let mut file = OpenOptions::new()
       .write(true)
       .truncate(true)
-      .open(FILE_NAME)
+      .open(self.path.as_deref().unwrap_or(Path::new(FILE_NAME)))
       .map_err(|err| UserError::CannotAccessConfigFile(err.to_string()))?;
     file
       .write_all(HEADER.as_bytes())
@@ -79,6 +163,10 @@ impl File {
 
 impl Display for File {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.inherit {
+      f.write_str(INHERIT)?;
+      f.write_str("\n")?;
+    }
     for AppVersions { app_name, versions } in &self.apps {
       f.write_str(app_name.as_str())?;
       for version in versions {
@@ -91,14 +179,38 @@ impl Display for File {
   }
 }
 
+/// provides the path of the config file that the config file at the given path inherits from
+fn parent_path(path: &Path) -> Result<Option<PathBuf>> {
+  let Some(folder) = path.parent() else {
+    return Ok(None);
+  };
+  if filesystem::is_repo_root(folder) {
+    return Ok(None);
+  }
+  let Some(parent_folder) = folder.parent() else {
+    return Ok(None);
+  };
+  filesystem::find_file_in(parent_folder, FILE_NAME)
+}
+
 fn parse(text: &str, all_apps: &Apps) -> Result<File> {
   let mut apps = vec![];
+  let mut inherit = false;
   for (i, line) in text.lines().enumerate() {
+    if LinePartsIterator::from(line.trim()).eq([INHERIT]) {
+      inherit = true;
+      continue;
+    }
     if let Some(app_version) = parse_line(line, i, all_apps)? {
       apps.push(app_version);
     }
   }
-  Ok(File { apps })
+  Ok(File {
+    path: None,
+    apps,
+    inherit,
+    parent: None,
+  })
 }
 
 fn parse_line(line_text: &str, line_no: usize, apps: &Apps) -> Result<Option<AppVersions>> {
@@ -116,9 +228,9 @@ fn parse_line(line_text: &str, line_no: usize, apps: &Apps) -> Result<Option<App
       text: line_text.to_string(),
     });
   };
-  let mut versions = RequestedVersions::from(vec![RequestedVersion::parse(version, app)?]);
+  let mut versions = RequestedVersions::from(vec![RequestedVersion::parse(version)?]);
   for part in parts {
-    versions.push(RequestedVersion::parse(part, app)?);
+    versions.push(RequestedVersion::parse(part)?);
   }
   Ok(Some(AppVersions {
     app_name: app.name(),
@@ -172,6 +284,7 @@ mod tests {
       let go = apps.lookup("go").unwrap();
       let have = parse(give, &apps).unwrap();
       let want = configuration::File {
+        path: None,
         apps: vec![
           AppVersions {
             app_name: actionlint.name(),
@@ -188,11 +301,13 @@ mod tests {
           AppVersions {
             app_name: go.name(),
             versions: RequestedVersions::from(vec![
-              RequestedVersion::Path(semver::VersionReq::parse("1.21").unwrap()),
+              RequestedVersion::Path(semver::VersionReq::parse("1.21").unwrap().into()),
               RequestedVersion::Yard("1.22.1".into()),
             ]),
           },
         ],
+        inherit: false,
+        parent: None,
       };
       pretty::assert_eq!(have, want);
     }
@@ -202,9 +317,21 @@ mod tests {
       let give = "";
       let apps = applications::all();
       let have = parse(give, &apps).unwrap();
-      let want = configuration::File { apps: vec![] };
+      let want = configuration::File::default();
       pretty::assert_eq!(have, want);
     }
+
+    #[test]
+    fn inherit() {
+      let give = "# overrides for this subproject\n\
+                  inherit\n\
+                  actionlint 1.2.3";
+      let apps = applications::all();
+      let have = parse(give, &apps).unwrap();
+      assert!(have.inherit);
+      assert_eq!(have.apps.len(), 1);
+      assert_eq!(have.to_string(), "inherit\nactionlint 1.2.3\n");
+    }
   }
 
   mod parse_line {
@@ -315,4 +442,67 @@ mod tests {
       pretty::assert_eq!(have, want);
     }
   }
+  mod read_in {
+    use crate::applications;
+    use crate::configuration::{File, RequestedVersion, RequestedVersions};
+    use std::fs;
+
+    #[test]
+    fn no_config_file() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let apps = applications::all();
+      let have = File::read_in(tempdir.path(), &apps).unwrap();
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn without_inherit() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let subproject = tempdir.path().join("services").join("api");
+      fs::create_dir_all(&subproject).unwrap();
+      fs::write(tempdir.path().join("run-that-app"), "actionlint 1.6.26\nshellcheck 0.9.0").unwrap();
+      fs::write(subproject.join("run-that-app"), "shellcheck 0.10.0").unwrap();
+      let apps = applications::all();
+      let have = File::read_in(&subproject, &apps).unwrap().unwrap();
+      assert_eq!(have.parent, None);
+      assert_eq!(have.paths(), vec![subproject.join("run-that-app")]);
+      assert_eq!(have.lookup(&apps.lookup("actionlint").unwrap().name()), None);
+    }
+
+    #[test]
+    fn inherit() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let subproject = tempdir.path().join("services").join("api");
+      fs::create_dir_all(&subproject).unwrap();
+      fs::write(tempdir.path().join("run-that-app"), "actionlint 1.6.26\nshellcheck 0.9.0").unwrap();
+      fs::write(subproject.join("run-that-app"), "inherit\nshellcheck 0.10.0").unwrap();
+      let apps = applications::all();
+      let have = File::read_in(&subproject, &apps).unwrap().unwrap();
+      assert_eq!(have.paths(), vec![subproject.join("run-that-app"), tempdir.path().join("run-that-app")]);
+      let actionlint = apps.lookup("actionlint").unwrap().name();
+      let shellcheck = apps.lookup("shellcheck").unwrap().name();
+      assert_eq!(
+        have.lookup(&actionlint),
+        Some(&RequestedVersions::from(vec![RequestedVersion::Yard("1.6.26".into())]))
+      );
+      assert_eq!(
+        have.lookup(&shellcheck),
+        Some(&RequestedVersions::from(vec![RequestedVersion::Yard("0.10.0".into())]))
+      );
+      let all_apps: Vec<&str> = have.all_apps().iter().map(|app| app.app_name.as_str()).collect();
+      assert_eq!(all_apps, vec!["shellcheck", "actionlint"]);
+    }
+
+    #[test]
+    fn inherit_stops_at_repo_root() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let repo = tempdir.path().join("repo");
+      fs::create_dir_all(repo.join(".git")).unwrap();
+      fs::write(tempdir.path().join("run-that-app"), "actionlint 1.6.26").unwrap();
+      fs::write(repo.join("run-that-app"), "inherit\nshellcheck 0.10.0").unwrap();
+      let apps = applications::all();
+      let have = File::read_in(&repo, &apps).unwrap().unwrap();
+      assert_eq!(have.parent, None);
+    }
+  }
 }
@@ -0,0 +1,407 @@
+use super::{FILE_NAME, File, RequestedVersion, Version};
+use crate::applications::{ApplicationName, Apps};
+use crate::download::Url;
+use crate::error::{Result, UserError};
+use crate::filesystem;
+use crate::installation::{Method, sha256};
+use crate::platform::Platform;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// name of the lockfile, which lives next to the config file
+pub const LOCK_FILE_NAME: &str = "run-that-app.lock";
+
+const HEADER: &str = "# generated by run-that-app, do not edit manually\n# more info at https://github.com/kevgo/run-that-app\n\n";
+
+/// the lockfile records how exactly each app version in the config file got installed on each platform,
+/// so that all machines install byte-identical executables
+#[derive(Debug, Default, PartialEq)]
+pub struct LockFile {
+  /// where to store this lockfile, None if there is no config file to put it next to
+  pub path: Option<PathBuf>,
+  pub entries: Vec<LockEntry>,
+  /// hashes of downloaded artifacts whose installation hasn't finished yet
+  pending: Vec<LockEntry>,
+  /// whether this lockfile contains entries that aren't saved yet
+  changed: bool,
+}
+
+/// how a particular app version got installed on a particular platform
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockEntry {
+  pub app_name: ApplicationName,
+  pub version: Version,
+  /// the platform this entry applies to, e.g. "linux/intel64"
+  pub platform: String,
+  /// the installation method used, as provided by [`Method::lock_name`]
+  pub method: String,
+  /// the location installed from: the resolved download URL for downloaded artifacts, otherwise [`Method::source`]
+  pub source: String,
+  /// lowercase hex-encoded SHA-256 digest of the downloaded artifact, None for installation methods that don't download artifacts
+  pub hash: Option<String>,
+}
+
+impl LockEntry {
+  /// indicates whether this entry describes the given installation method
+  pub fn matches(&self, method: &Method) -> bool {
+    self.method == method.lock_name() && method.has_source(&self.source)
+  }
+}
+
+impl Display for LockEntry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} {} {} {} {} {}",
+      self.app_name,
+      self.version,
+      self.platform,
+      self.method,
+      self.source,
+      self.hash.as_deref().unwrap_or("-")
+    )
+  }
+}
+
+impl LockFile {
+  // CONSTRUCTORS
+
+  /// loads the lockfile next to the config file
+  pub fn load(apps: &Apps) -> Result<LockFile> {
+    let Some(config_path) = filesystem::find_file(FILE_NAME)? else {
+      return Ok(LockFile::default());
+    };
+    let path = config_path.with_file_name(LOCK_FILE_NAME);
+    let entries = match fs::read_to_string(&path) {
+      Ok(text) => parse(&text, apps)?,
+      Err(err) if err.kind() == ErrorKind::NotFound => vec![],
+      Err(err) => return Err(UserError::CannotAccessLockFile(err.to_string())),
+    };
+    Ok(LockFile {
+      path: Some(path),
+      entries,
+      pending: vec![],
+      changed: false,
+    })
+  }
+
+  // METHODS
+
+  pub fn lookup(&self, app_name: &ApplicationName, version: &Version, platform: Platform) -> Option<&LockEntry> {
+    let platform = platform.to_string();
+    self
+      .entries
+      .iter()
+      .find(|entry| &entry.app_name == app_name && &entry.version == version && entry.platform == platform)
+  }
+
+  /// Records that the given app version got installed successfully using the given method.
+  /// Locks the hash of the artifact that [`verify_download`](Self::verify_download) saw for this installation.
+  pub fn record(&mut self, app_name: &ApplicationName, version: &Version, platform: Platform, method: &Method) {
+    let platform_name = platform.to_string();
+    let is_same = |entry: &LockEntry| &entry.app_name == app_name && &entry.version == version && entry.platform == platform_name;
+    let pending = self
+      .pending
+      .iter()
+      .position(|pending| is_same(pending) && pending.matches(method))
+      .map(|index| self.pending.remove(index));
+    if let Some(entry) = self.entries.iter_mut().find(|entry| is_same(entry)) {
+      if let Some(pending) = pending
+        && entry.hash.is_none()
+        && entry.source == pending.source
+      {
+        entry.hash = pending.hash;
+        self.changed = true;
+      }
+      return;
+    }
+    self.entries.push(pending.unwrap_or_else(|| LockEntry {
+      app_name: app_name.clone(),
+      version: version.clone(),
+      platform: platform_name,
+      method: method.lock_name().to_string(),
+      source: method.source(),
+      hash: None,
+    }));
+    self.changed = true;
+  }
+
+  /// Verifies the artifact downloaded from the given URL against the locked hash.
+  /// If no hash is locked yet, remembers URL and hash so that [`record`](Self::record) can lock them once the installation succeeds.
+  pub fn verify_download(&mut self, app_name: &ApplicationName, version: &Version, platform: Platform, method: &Method, url: &Url, data: &[u8]) -> Result<()> {
+    let actual = sha256(data);
+    if let Some(entry) = self.lookup(app_name, version, platform)
+      && let Some(locked) = &entry.hash
+    {
+      if locked == &actual {
+        return Ok(());
+      }
+      return Err(UserError::LockFileHashMismatch {
+        app: app_name.clone(),
+        version: version.clone(),
+        url: entry.source.clone(),
+        locked: locked.clone(),
+        actual,
+      });
+    }
+    let platform = platform.to_string();
+    self
+      .pending
+      .retain(|pending| !(&pending.app_name == app_name && &pending.version == version && pending.platform == platform));
+    self.pending.push(LockEntry {
+      app_name: app_name.clone(),
+      version: version.clone(),
+      platform,
+      method: method.lock_name().to_string(),
+      source: url.to_string(),
+      hash: Some(actual),
+    });
+    Ok(())
+  }
+
+  /// saves this lockfile to disk, keeping only the entries for app versions listed in the given config file
+  pub fn save(&mut self, config: &File) -> Result<()> {
+    let Some(path) = &self.path else {
+      return Ok(());
+    };
+    let entries_before = self.entries.len();
+    self.entries.retain(|entry| is_configured(config, &entry.app_name, &entry.version));
+    if !self.changed && self.entries.len() == entries_before {
+      return Ok(());
+    }
+    self
+      .entries
+      .sort_by(|a, b| (a.app_name.as_str(), &a.version, &a.platform).cmp(&(b.app_name.as_str(), &b.version, &b.platform)));
+    fs::write(path, format!("{HEADER}{self}")).map_err(|err| UserError::CannotAccessLockFile(err.to_string()))?;
+    self.changed = false;
+    Ok(())
+  }
+}
+
+impl Display for LockFile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for entry in &self.entries {
+      writeln!(f, "{entry}")?;
+    }
+    Ok(())
+  }
+}
+
+/// indicates whether the given config file requests the given app version
+fn is_configured(config: &File, app_name: &ApplicationName, version: &Version) -> bool {
+  let Some(versions) = config.lookup(app_name) else {
+    return false;
+  };
+  versions.into_iter().any(|requested| match requested {
+    RequestedVersion::Yard(requested) => requested == version,
+    RequestedVersion::Path(_) | RequestedVersion::PathAuto => false,
+  })
+}
+
+fn parse(text: &str, apps: &Apps) -> Result<Vec<LockEntry>> {
+  let mut entries = vec![];
+  for (i, line) in text.lines().enumerate() {
+    if let Some(entry) = parse_line(line, i + 1, apps)? {
+      entries.push(entry);
+    }
+  }
+  Ok(entries)
+}
+
+fn parse_line(line_text: &str, line_no: usize, apps: &Apps) -> Result<Option<LockEntry>> {
+  let line_text = line_text.trim();
+  if line_text.is_empty() || line_text.starts_with('#') {
+    return Ok(None);
+  }
+  let parts: Vec<&str> = line_text.split_ascii_whitespace().collect();
+  let [app_name, version, platform, method, source, hash] = parts[..] else {
+    return Err(UserError::InvalidLockFileFormat {
+      line_no,
+      text: line_text.to_string(),
+    });
+  };
+  // entries for apps that this version of run-that-app doesn't know can't apply to any installation,
+  // saving the lockfile removes them since the config file can't list such apps
+  let Ok(app) = apps.lookup(app_name) else {
+    return Ok(None);
+  };
+  Ok(Some(LockEntry {
+    app_name: app.name(),
+    version: Version::from(version),
+    platform: platform.to_string(),
+    method: method.to_string(),
+    source: source.to_string(),
+    hash: if hash == "-" { None } else { Some(hash.to_string()) },
+  }))
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse_line {
+    use super::super::{LockEntry, parse_line};
+    use crate::applications;
+    use crate::error::UserError;
+    use big_s::S;
+
+    #[test]
+    fn download() {
+      let apps = applications::all();
+      let give = "actionlint 1.7.7 linux/intel64 download-archive https://github.com/rhysd/actionlint/releases/download/v1.7.7/actionlint_1.7.7_linux_amd64.tar.gz 023070a287cd8cccd71515fedc843f1985bf96c436b7effaecce67290e7e0757";
+      let have = parse_line(give, 1, &apps).unwrap();
+      let want = Some(LockEntry {
+        app_name: "actionlint".into(),
+        version: "1.7.7".into(),
+        platform: S("linux/intel64"),
+        method: S("download-archive"),
+        source: S("https://github.com/rhysd/actionlint/releases/download/v1.7.7/actionlint_1.7.7_linux_amd64.tar.gz"),
+        hash: Some(S("023070a287cd8cccd71515fedc843f1985bf96c436b7effaecce67290e7e0757")),
+      });
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn without_hash() {
+      let apps = applications::all();
+      let give = "shfmt 3.7.0 linux/arm64 compile-go-source mvdan.cc/sh/v3/cmd/shfmt@v3.7.0 -";
+      let have = parse_line(give, 1, &apps).unwrap();
+      let want = Some(LockEntry {
+        app_name: "shfmt".into(),
+        version: "3.7.0".into(),
+        platform: S("linux/arm64"),
+        method: S("compile-go-source"),
+        source: S("mvdan.cc/sh/v3/cmd/shfmt@v3.7.0"),
+        hash: None,
+      });
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn comment() {
+      let apps = applications::all();
+      let have = parse_line("# generated by run-that-app", 1, &apps).unwrap();
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn unknown_app() {
+      let apps = applications::all();
+      let have = parse_line("zonk 1.0.0 linux/arm64 download-executable https://example.com/zonk -", 1, &apps).unwrap();
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn missing_fields() {
+      let apps = applications::all();
+      let have = parse_line("shfmt 3.7.0 linux/arm64", 3, &apps);
+      let want = Err(UserError::InvalidLockFileFormat {
+        line_no: 3,
+        text: S("shfmt 3.7.0 linux/arm64"),
+      });
+      pretty::assert_eq!(have, want);
+    }
+  }
+
+  mod verify_download {
+    use crate::configuration::LockFile;
+    use crate::download::Url;
+    use crate::error::UserError;
+    use crate::installation::{Checksum, Method};
+    use crate::platform::{Cpu, Os, Platform};
+
+    const PLATFORM: Platform = Platform {
+      os: Os::Linux,
+      cpu: Cpu::Intel64,
+    };
+
+    fn method() -> Method {
+      Method::DownloadExecutable {
+        url: url(),
+        checksum: Checksum::None,
+      }
+    }
+
+    fn url() -> Url {
+      Url::from("https://example.com/app")
+    }
+
+    #[test]
+    fn records_new_hash() {
+      let mut lock_file = LockFile::default();
+      let have = lock_file.verify_download(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method(), &url(), b"content");
+      assert_eq!(have, Ok(()));
+      // the hash gets locked only once the installation succeeds
+      assert_eq!(lock_file.lookup(&"shfmt".into(), &"3.7.0".into(), PLATFORM), None);
+      lock_file.record(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method());
+      let entry = lock_file.lookup(&"shfmt".into(), &"3.7.0".into(), PLATFORM).unwrap();
+      assert_eq!(entry.hash.as_deref(), Some("ed7002b439e9ac845f22357d822bac1444730fbdb6016d3ec9432297b9ec9f73"));
+      assert_eq!(entry.method, "download-executable");
+    }
+
+    #[test]
+    fn matching_hash() {
+      let mut lock_file = LockFile::default();
+      lock_file
+        .verify_download(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method(), &url(), b"content")
+        .unwrap();
+      lock_file.record(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method());
+      let have = lock_file.verify_download(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method(), &url(), b"content");
+      assert_eq!(have, Ok(()));
+    }
+
+    #[test]
+    fn mismatching_hash() {
+      let mut lock_file = LockFile::default();
+      lock_file
+        .verify_download(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method(), &url(), b"content")
+        .unwrap();
+      lock_file.record(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method());
+      let have = lock_file.verify_download(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method(), &url(), b"tampered content");
+      let want = Err(UserError::LockFileHashMismatch {
+        app: "shfmt".into(),
+        version: "3.7.0".into(),
+        url: "https://example.com/app".into(),
+        locked: "ed7002b439e9ac845f22357d822bac1444730fbdb6016d3ec9432297b9ec9f73".into(),
+        actual: "3109af4bf22dace5fa33b410c9f2e40dc1eed214d6c2b9ea0848a17f187ef661".into(),
+      });
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn other_platform() {
+      let mut lock_file = LockFile::default();
+      lock_file
+        .verify_download(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method(), &url(), b"content")
+        .unwrap();
+      lock_file.record(&"shfmt".into(), &"3.7.0".into(), PLATFORM, &method());
+      let mac = Platform {
+        os: Os::MacOS,
+        cpu: Cpu::Arm64,
+      };
+      let have = lock_file.verify_download(&"shfmt".into(), &"3.7.0".into(), mac, &method(), &url(), b"tampered content");
+      assert_eq!(have, Ok(()));
+      lock_file.record(&"shfmt".into(), &"3.7.0".into(), mac, &method());
+      assert_eq!(lock_file.entries.len(), 2);
+    }
+
+    #[test]
+    fn github_asset() {
+      let mut lock_file = LockFile::default();
+      let method = Method::DownloadGitHubAsset {
+        org: "acme".into(),
+        repo: "tool".into(),
+        tag: "v1.0.0".into(),
+      };
+      let asset = Url::from("https://github.com/acme/tool/releases/download/v1.0.0/tool_linux_amd64.tar.gz");
+      lock_file
+        .verify_download(&"tool".into(), &"1.0.0".into(), PLATFORM, &method, &asset, b"content")
+        .unwrap();
+      lock_file.record(&"tool".into(), &"1.0.0".into(), PLATFORM, &method);
+      let entry = lock_file.lookup(&"tool".into(), &"1.0.0".into(), PLATFORM).unwrap();
+      assert_eq!(entry.source, "https://github.com/acme/tool/releases/download/v1.0.0/tool_linux_amd64.tar.gz");
+      assert!(entry.matches(&method));
+    }
+  }
+}
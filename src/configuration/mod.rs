@@ -2,16 +2,22 @@
 
 mod app_versions;
 mod file;
+mod lock_file;
+mod pyproject;
 mod requested_version;
 mod requested_versions;
 mod tag_format;
 mod version;
+mod version_range;
 
 pub use app_versions::AppVersions;
 pub use file::File;
+pub use lock_file::{LOCK_FILE_NAME, LockFile};
+pub use pyproject::required_version as pyproject_required_version;
 pub use requested_version::RequestedVersion;
 pub use requested_versions::RequestedVersions;
 pub use tag_format::TagFormat;
 pub use version::Version;
+pub use version_range::VersionRange;
 
 pub const FILE_NAME: &str = "run-that-app";
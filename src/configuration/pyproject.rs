@@ -0,0 +1,374 @@
+//! reads the versions of Python tooling that Python projects require
+
+use crate::error::{Result, UserError};
+use crate::filesystem;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::{env, fs};
+
+/// provides the versions of the given Python tool that the project allows,
+/// as declared via `required-version` in the tool's own config files or in the `[tool.<name>]` section of `pyproject.toml`
+pub fn required_version(tool: &str, config_files: &[&str]) -> Result<semver::VersionReq> {
+  let cwd = env::current_dir().map_err(|err| UserError::CannotDetermineCurrentDirectory(err.to_string()))?;
+  required_version_in(&cwd, tool, config_files)
+}
+
+/// provides the versions of the given Python tool that the project in the given folder allows.
+/// Like the Python tools themselves, this uses only the nearest config file,
+/// looking at the given config files before `pyproject.toml` within each folder.
+fn required_version_in(folder: &Path, tool: &str, config_files: &[&str]) -> Result<semver::VersionReq> {
+  let table = format!("tool.{tool}");
+  let mut dir = folder;
+  loop {
+    for config_file in config_files {
+      if let Some(text) = read(&dir.join(config_file))? {
+        return parse_specifiers(&parse_required_version(&text, None).unwrap_or_default());
+      }
+    }
+    // pyproject.toml files without a section for the tool don't configure it
+    if let Some(text) = read(&dir.join("pyproject.toml"))?
+      && has_table(&text, &table)
+    {
+      return parse_specifiers(&parse_required_version(&text, Some(&table)).unwrap_or_default());
+    }
+    if filesystem::is_repo_root(dir) {
+      return Ok(semver::VersionReq::STAR);
+    }
+    dir = match dir.parent() {
+      Some(parent) => parent,
+      None => return Ok(semver::VersionReq::STAR),
+    };
+  }
+}
+
+/// provides the content of the file at the given path, None if it doesn't exist
+fn read(path: &Path) -> Result<Option<String>> {
+  match fs::read_to_string(path) {
+    Ok(text) => Ok(Some(text)),
+    Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+    Err(err) => Err(UserError::CannotAccessConfigFile(err.to_string())),
+  }
+}
+
+/// a line of a TOML document that matters for finding `required-version`
+#[derive(Debug, PartialEq)]
+enum Line<'a> {
+  /// the header of a table, e.g. "tool.ruff" for "[tool.ruff]"
+  Header(&'a str),
+  /// a line with a key-value pair in the given table, None for the top level
+  Entry { table: Option<&'a str>, text: &'a str },
+}
+
+/// provides the table headers and key-value lines of the given TOML document,
+/// skipping the continuation lines of arrays and inline tables that span multiple lines
+fn lines(text: &str) -> Vec<Line<'_>> {
+  let mut result = vec![];
+  let mut table = None;
+  let mut open_brackets = 0;
+  for line in text.lines() {
+    let line = line.trim();
+    if open_brackets == 0 {
+      if let Some(header) = line.strip_prefix('[') {
+        // array tables like "[[tool.uv.index]]" have two brackets
+        let name = header.trim_start_matches('[').split(']').next().unwrap_or_default().trim();
+        table = Some(name);
+        result.push(Line::Header(name));
+        continue;
+      }
+      result.push(Line::Entry { table, text: line });
+    }
+    open_brackets = count_open_brackets(line, open_brackets);
+  }
+  result
+}
+
+/// provides how many brackets are still open after the given line, given how many were open before it
+fn count_open_brackets(line: &str, mut open: usize) -> usize {
+  let mut quote: Option<char> = None;
+  let mut escaped = false;
+  for c in line.chars() {
+    match quote {
+      Some(q) => {
+        if escaped {
+          escaped = false;
+        } else if c == '\\' && q == '"' {
+          escaped = true;
+        } else if c == q {
+          quote = None;
+        }
+      }
+      None => match c {
+        '"' | '\'' => quote = Some(c),
+        '#' => break,
+        '[' | '{' => open += 1,
+        ']' | '}' => open = open.saturating_sub(1),
+        _ => {}
+      },
+    }
+  }
+  open
+}
+
+/// indicates whether the given TOML document contains the given table or one of its subtables
+fn has_table(text: &str, table: &str) -> bool {
+  lines(text).into_iter().any(|line| match line {
+    Line::Header(name) => name == table || name.strip_prefix(table).is_some_and(|rest| rest.starts_with('.')),
+    Line::Entry { table: _, text: _ } => false,
+  })
+}
+
+/// provides the value of the `required-version` key in the given TOML table, or at the top level if no table is given
+fn parse_required_version(text: &str, table: Option<&str>) -> Option<String> {
+  for line in lines(text) {
+    let Line::Entry { table: line_table, text: line } = line else {
+      continue;
+    };
+    if line_table != table {
+      continue;
+    }
+    let Some((key, value)) = line.split_once('=') else {
+      continue;
+    };
+    if key.trim() != "required-version" {
+      continue;
+    }
+    let value = value.trim();
+    let quote = value.chars().next()?;
+    if quote != '"' && quote != '\'' {
+      return None;
+    }
+    let value = &value[1..];
+    return value.find(quote).map(|end| value[..end].to_string());
+  }
+  None
+}
+
+/// converts the given PEP 440 version specifiers into a semver requirement
+fn parse_specifiers(specifiers: &str) -> Result<semver::VersionReq> {
+  let mut comparators = vec![];
+  for specifier in specifiers.split(',') {
+    let specifier = specifier.trim();
+    if specifier.is_empty() {
+      continue;
+    }
+    let comparator = parse_specifier(specifier).map_err(|reason| UserError::CannotParseSemverRange {
+      expression: specifiers.to_string(),
+      reason,
+    })?;
+    comparators.extend(comparator);
+  }
+  if comparators.is_empty() {
+    return Ok(semver::VersionReq::STAR);
+  }
+  let expression = comparators.join(", ");
+  semver::VersionReq::parse(&expression).map_err(|err| UserError::CannotParseSemverRange {
+    expression: specifiers.to_string(),
+    reason: err.to_string(),
+  })
+}
+
+/// converts a single PEP 440 version specifier into semver comparators,
+/// None for exclusions like "!=0.5.0", which semver requirements cannot express
+fn parse_specifier(specifier: &str) -> std::result::Result<Option<String>, String> {
+  let operator_len = specifier.find(|c: char| c.is_ascii_alphanumeric()).unwrap_or(specifier.len());
+  let (operator, version) = specifier.split_at(operator_len);
+  let version = version.trim();
+  match operator.trim() {
+    "" | "==" | "===" => match version.strip_suffix(".*") {
+      Some(prefix) => Ok(Some(format!("={prefix}"))),
+      None => Ok(Some(format!("={}", pad_version(version)))),
+    },
+    "~=" => {
+      let parts: Vec<&str> = version.split('.').collect();
+      match parts.as_slice() {
+        [major, minor] => {
+          let next_major = major.parse::<u64>().map_err(|_| format!("invalid version: {version}"))? + 1;
+          Ok(Some(format!(">={major}.{minor}.0, <{next_major}.0.0")))
+        }
+        [major, minor, patch] => Ok(Some(format!("~{major}.{minor}.{patch}"))),
+        _ => Err(format!("{specifier} needs two or three version numbers")),
+      }
+    }
+    operator @ (">=" | "<=" | ">" | "<") => Ok(Some(format!("{operator}{version}"))),
+    // allowing the excluded version is better than not allowing any version
+    "!=" => Ok(None),
+    operator => Err(format!("run-that-app doesn't support the {operator} operator")),
+  }
+}
+
+/// pads the given version to three numbers, because PEP 440 treats missing numbers as zero
+fn pad_version(version: &str) -> String {
+  match version.split('.').count() {
+    1 => format!("{version}.0.0"),
+    2 => format!("{version}.0"),
+    _ => version.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse_required_version {
+    use super::super::parse_required_version;
+
+    #[test]
+    fn pyproject() {
+      let give = r#"
+[project]
+name = "app"
+required-version = "1.0"
+
+[tool.ruff]
+line-length = 100
+required-version = ">=0.5.0"
+
+[tool.uv]
+required-version = '>=0.4, <0.6'
+"#;
+      assert_eq!(parse_required_version(give, Some("tool.ruff")).as_deref(), Some(">=0.5.0"));
+      assert_eq!(parse_required_version(give, Some("tool.uv")).as_deref(), Some(">=0.4, <0.6"));
+      assert_eq!(parse_required_version(give, Some("tool.black")), None);
+    }
+
+    #[test]
+    fn top_level() {
+      let give = "required-version = \"==0.6.1\"\n\n[lint]\nselect = [\"E\"]\n";
+      assert_eq!(parse_required_version(give, None).as_deref(), Some("==0.6.1"));
+    }
+
+    #[test]
+    fn only_in_subtable() {
+      let give = "[format]\nrequired-version = \"0.1.0\"\n";
+      assert_eq!(parse_required_version(give, None), None);
+    }
+
+    #[test]
+    fn multiline_array() {
+      let give = r#"
+[tool.ruff.lint]
+select = [
+  ["E", "F"],
+  "I",
+]
+required-version = "0.1.0"
+
+[tool.ruff]
+required-version = ">=0.5.0"
+"#;
+      assert_eq!(parse_required_version(give, Some("tool.ruff")).as_deref(), Some(">=0.5.0"));
+    }
+
+    #[test]
+    fn brackets_in_strings_and_comments() {
+      let give = "[tool.uv]\nextra = [\"a]\", # ]\n  \"b\",\n]\nrequired-version = \">=0.4\"\n";
+      assert_eq!(parse_required_version(give, Some("tool.uv")).as_deref(), Some(">=0.4"));
+    }
+  }
+
+  mod has_table {
+    use super::super::has_table;
+
+    #[test]
+    fn table() {
+      assert!(has_table("[tool.ruff]\nline-length = 100\n", "tool.ruff"));
+    }
+
+    #[test]
+    fn subtable() {
+      assert!(has_table("[tool.ruff.lint]\nselect = [\"E\"]\n", "tool.ruff"));
+    }
+
+    #[test]
+    fn other_table() {
+      assert!(!has_table("[tool.ruffle]\n[project]\n", "tool.ruff"));
+    }
+  }
+
+  mod required_version_in {
+    use super::super::required_version_in;
+    use std::fs;
+
+    #[test]
+    fn nearest_config_file() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let root = tempdir.path();
+      fs::create_dir(root.join(".git")).unwrap();
+      fs::write(root.join("ruff.toml"), "required-version = \">=0.4.0\"\n").unwrap();
+      let subfolder = root.join("sub");
+      fs::create_dir(&subfolder).unwrap();
+      fs::write(subfolder.join("pyproject.toml"), "[tool.ruff]\nrequired-version = \">=0.6.0\"\n").unwrap();
+      let have = required_version_in(&subfolder, "ruff", &[".ruff.toml", "ruff.toml"]).unwrap();
+      assert_eq!(have.to_string(), ">=0.6.0");
+    }
+
+    #[test]
+    fn own_config_file_before_pyproject() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let root = tempdir.path();
+      fs::create_dir(root.join(".git")).unwrap();
+      fs::write(root.join("ruff.toml"), "line-length = 100\n").unwrap();
+      fs::write(root.join("pyproject.toml"), "[tool.ruff]\nrequired-version = \">=0.6.0\"\n").unwrap();
+      let have = required_version_in(root, "ruff", &[".ruff.toml", "ruff.toml"]).unwrap();
+      assert_eq!(have, semver::VersionReq::STAR);
+    }
+
+    #[test]
+    fn pyproject_without_tool_section() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let root = tempdir.path();
+      fs::create_dir(root.join(".git")).unwrap();
+      fs::write(root.join("uv.toml"), "required-version = \">=0.4\"\n").unwrap();
+      let subfolder = root.join("sub");
+      fs::create_dir(&subfolder).unwrap();
+      fs::write(subfolder.join("pyproject.toml"), "[project]\nname = \"app\"\n").unwrap();
+      let have = required_version_in(&subfolder, "uv", &["uv.toml"]).unwrap();
+      assert_eq!(have.to_string(), ">=0.4");
+    }
+  }
+
+  mod parse_specifiers {
+    use super::super::parse_specifiers;
+
+    fn parse(text: &str) -> String {
+      parse_specifiers(text).unwrap().to_string()
+    }
+
+    #[test]
+    fn exact() {
+      assert_eq!(parse("==0.6.1"), "=0.6.1");
+      assert_eq!(parse("0.6"), "=0.6.0");
+    }
+
+    #[test]
+    fn wildcard() {
+      assert_eq!(parse("==0.6.*"), "=0.6");
+    }
+
+    #[test]
+    fn compatible_release() {
+      assert_eq!(parse("~=0.6.1"), "~0.6.1");
+      assert_eq!(parse("~=0.6"), ">=0.6.0, <1.0.0");
+    }
+
+    #[test]
+    fn multiple() {
+      assert_eq!(parse(">=0.4, <0.6"), ">=0.4, <0.6");
+    }
+
+    #[test]
+    fn empty() {
+      assert_eq!(parse(""), "*");
+    }
+
+    #[test]
+    fn exclusion() {
+      assert_eq!(parse("!=0.5.0"), "*");
+      assert_eq!(parse(">=0.4, !=0.5.0"), ">=0.4");
+    }
+
+    #[test]
+    fn unsupported() {
+      assert!(parse_specifiers("~=1").is_err());
+    }
+  }
+}
@@ -1,31 +1,45 @@
-use super::Version;
+use super::{Version, VersionRange};
 use crate::applications::AppDefinition;
-use crate::error::{Result, UserError};
+use crate::context::RuntimeContext;
+use crate::error::Result;
+use crate::yard::VersionsCache;
 use std::fmt::Display;
 
 /// an application version requested by the user
 #[derive(Clone, Debug, PartialEq)]
 pub enum RequestedVersion {
   /// the user has requested an externally installed application that matches the given version requirement
-  Path(semver::VersionReq),
+  Path(VersionRange),
+  /// the user has requested an externally installed application in a version that the project files allow (`system@auto`)
+  PathAuto,
   /// the user has requested an application in the Yard with the exact version given
   Yard(Version),
 }
 
 impl RequestedVersion {
-  pub fn parse(version: &str, app_definition: &dyn AppDefinition) -> Result<RequestedVersion> {
+  pub fn parse(version: &str) -> Result<RequestedVersion> {
     if let Some(system_version) = is_system(version) {
       if system_version == "auto" {
-        return Ok(RequestedVersion::Path(app_definition.allowed_versions()?));
+        // determining the versions that the project files allow might require network access, do it only when needed
+        return Ok(RequestedVersion::PathAuto);
       }
-      let version_req = semver::VersionReq::parse(&system_version).map_err(|err| UserError::CannotParseSemverRange {
-        expression: system_version.clone(),
-        reason: err.to_string(),
-      })?;
-      return Ok(RequestedVersion::Path(version_req));
+      return Ok(RequestedVersion::Path(VersionRange::parse(&system_version)?));
     }
     Ok(RequestedVersion::Yard(version.into()))
   }
+
+  /// provides the versions of an externally installed application that this request allows,
+  /// None if this requests an application in the yard
+  pub fn path_range(&self, app: &dyn AppDefinition, ctx: &RuntimeContext) -> Result<Option<VersionRange>> {
+    match self {
+      RequestedVersion::Path(range) => Ok(Some(range.clone())),
+      RequestedVersion::PathAuto => {
+        let cache = VersionsCache::new(ctx.yard, false, ctx.offline)?;
+        app.allowed_versions(&cache, ctx.log).map(Some)
+      }
+      RequestedVersion::Yard(_) => Ok(None),
+    }
+  }
 }
 
 impl Display for RequestedVersion {
@@ -35,6 +49,7 @@ impl Display for RequestedVersion {
         f.write_str("system@")?;
         version.fmt(f)
       }
+      RequestedVersion::PathAuto => f.write_str("system@auto"),
       RequestedVersion::Yard(version) => f.write_str(version.as_str()),
     }
   }
@@ -69,95 +84,26 @@ mod tests {
   use big_s::S;
 
   mod parse {
-    use crate::applications::{AnalyzeResult, AppDefinition, ApplicationName};
-    use crate::configuration::{TagFormat, Version};
-    use crate::error::Result;
-    use crate::executables::{Executable, ExecutableNameUnix, RunMethod};
-    use crate::logging::Log;
-    use crate::platform::Platform;
-
-    /// an App instance that allows to mock the system version restrictions
-    #[derive(Clone)]
-    struct TestApp {
-      allowed_versions: semver::VersionReq,
-    }
-    impl AppDefinition for TestApp {
-      fn allowed_versions(&self) -> Result<semver::VersionReq> {
-        Ok(self.allowed_versions.clone())
-      }
+    use crate::configuration::{RequestedVersion, VersionRange};
 
-      fn name(&self) -> ApplicationName {
-        unimplemented!()
-      }
-      fn executable_filename(&self) -> ExecutableNameUnix {
-        unimplemented!()
-      }
-      fn homepage(&self) -> &'static str {
-        unimplemented!()
-      }
-      fn installable_versions(&self, _amount: usize, _log: Log) -> Result<Vec<Version>> {
-        unimplemented!()
-      }
-      fn latest_installable_version(&self, _log: Log) -> Result<Version> {
-        unimplemented!()
-      }
-      fn analyze_executable(&self, _executable: &Executable) -> Result<AnalyzeResult> {
-        unimplemented!()
-      }
-      fn run_method(&self, _version: &Version, _platform: Platform) -> RunMethod {
-        unimplemented!()
-      }
-      fn tag_format(&self) -> TagFormat {
-        unimplemented!()
-      }
+    #[test]
+    fn system_request_with_version() {
+      let have = RequestedVersion::parse("system@1.2").unwrap();
+      let want = RequestedVersion::Path(VersionRange::parse("1.2").unwrap());
+      assert_eq!(have, want);
     }
 
-    mod unknown_allowed_versions {
-      use crate::configuration::RequestedVersion;
-
-      #[test]
-      fn system_request_with_version() {
-        let app = super::TestApp {
-          allowed_versions: semver::VersionReq::STAR,
-        };
-        let have = RequestedVersion::parse("system@1.2", &app).unwrap();
-        let want = RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap());
-        assert_eq!(have, want);
-      }
-
-      #[test]
-      fn system_request_auto_version() {
-        let app = super::TestApp {
-          allowed_versions: semver::VersionReq::STAR,
-        };
-        let have = RequestedVersion::parse("system@auto", &app).unwrap();
-        let want = RequestedVersion::Path(semver::VersionReq::STAR);
-        assert_eq!(have, want);
-      }
+    #[test]
+    fn system_request_auto_version() {
+      let have = RequestedVersion::parse("system@auto").unwrap();
+      assert_eq!(have, RequestedVersion::PathAuto);
+      assert_eq!(have.to_string(), "system@auto");
     }
 
-    mod known_allowed_versions {
-      use crate::configuration::RequestedVersion;
-
-      #[test]
-      fn system_request_with_version() {
-        let app = super::TestApp {
-          allowed_versions: semver::VersionReq::parse("1.21").unwrap(),
-        };
-        let have = RequestedVersion::parse("system@1.5", &app).unwrap();
-        let want = RequestedVersion::Path(semver::VersionReq::parse("1.5").unwrap());
-        assert_eq!(have, want);
-      }
-
-      #[test]
-      fn system_request_auto_version() {
-        let app = super::TestApp {
-          allowed_versions: semver::VersionReq::parse("1.21").unwrap(),
-        };
-        let have = RequestedVersion::parse("system@auto", &app).unwrap();
-        let want = RequestedVersion::Path(semver::VersionReq::parse("1.21").unwrap());
-        assert_eq!(have, want);
-      }
+    #[test]
+    fn yard_version() {
+      let have = RequestedVersion::parse("1.2.3").unwrap();
+      assert_eq!(have, RequestedVersion::Yard("1.2.3".into()));
     }
   }
 
@@ -18,10 +18,10 @@ impl Ord for RequestedVersions {
     for (a, b) in self.0.iter().zip(other.0.iter()) {
       let cmp = match (a, b) {
         (RequestedVersion::Yard(v1), RequestedVersion::Yard(v2)) => v1.cmp(v2),
-        (RequestedVersion::Path(v1), RequestedVersion::Path(v2)) => v1.to_string().cmp(&v2.to_string()),
         // Path comes before Yard in ordering
-        (RequestedVersion::Path(_), RequestedVersion::Yard(_)) => std::cmp::Ordering::Less,
-        (RequestedVersion::Yard(_), RequestedVersion::Path(_)) => std::cmp::Ordering::Greater,
+        (RequestedVersion::Path(_) | RequestedVersion::PathAuto, RequestedVersion::Yard(_)) => std::cmp::Ordering::Less,
+        (RequestedVersion::Yard(_), RequestedVersion::Path(_) | RequestedVersion::PathAuto) => std::cmp::Ordering::Greater,
+        (RequestedVersion::Path(_) | RequestedVersion::PathAuto, RequestedVersion::Path(_) | RequestedVersion::PathAuto) => a.to_string().cmp(&b.to_string()),
       };
       if cmp != std::cmp::Ordering::Equal {
         return cmp;
@@ -43,7 +43,7 @@ impl RequestedVersions {
   // METHODS
 
   /// provides the largest yard version contained in this collection
-  fn largest_yard(&self) -> Option<&Version> {
+  pub fn largest_yard(&self) -> Option<&Version> {
     let mut result = None;
     for version in &self.0 {
       let RequestedVersion::Yard(version) = version else {
@@ -148,7 +148,7 @@ mod tests {
     #[test]
     fn system_and_versions() {
       let versions = RequestedVersions::from(vec![
-        RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap()),
+        RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap().into()),
         RequestedVersion::Yard("1.2".into()),
         RequestedVersion::Yard("1.1".into()),
       ]);
@@ -159,7 +159,7 @@ mod tests {
 
     #[test]
     fn system_no_versions() {
-      let versions = RequestedVersions::from(vec![RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap())]);
+      let versions = RequestedVersions::from(vec![RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap().into())]);
       let have = versions.largest_yard();
       assert_eq!(have, None);
     }
@@ -178,14 +178,14 @@ mod tests {
     #[test]
     fn system_and_versions() {
       let mut versions = RequestedVersions::from(vec![
-        RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap()),
+        RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap().into()),
         RequestedVersion::Yard("1.2".into()),
         RequestedVersion::Yard("1.1".into()),
       ]);
       let have = versions.update_largest_with(&Version::from("1.4"));
       assert_eq!(have, Some(Version::from("1.2")));
       let want = RequestedVersions::from(vec![
-        RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap()),
+        RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap().into()),
         RequestedVersion::Yard("1.4".into()),
         RequestedVersion::Yard("1.1".into()),
       ]);
@@ -194,10 +194,10 @@ mod tests {
 
     #[test]
     fn system_only() {
-      let mut versions = RequestedVersions::from(vec![RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap())]);
+      let mut versions = RequestedVersions::from(vec![RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap().into())]);
       let have = versions.update_largest_with(&Version::from("1.4"));
       assert_eq!(have, None);
-      let want = RequestedVersions::from(vec![RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap())]);
+      let want = RequestedVersions::from(vec![RequestedVersion::Path(semver::VersionReq::parse("1.2").unwrap().into())]);
       assert_eq!(versions, want);
     }
   }
@@ -7,7 +7,7 @@ pub enum TagFormat {
   /// version tags have the prefix "v", i.e. version 1.2.3 has tag "v1.2.3"
   PrefixV,
   /// version tags have the given prefix
-  Prefix(&'static str),
+  Prefix(String),
 }
 
 impl TagFormat {
@@ -52,12 +52,12 @@ mod tests {
 
     #[test]
     fn custom_matching_prefix() {
-      assert_eq!(TagFormat::Prefix("bun-v").parse("bun-v1.2.3"), Version::from("1.2.3"));
+      assert_eq!(TagFormat::Prefix("bun-v".into()).parse("bun-v1.2.3"), Version::from("1.2.3"));
     }
 
     #[test]
     fn custom_no_prefix() {
-      assert_eq!(TagFormat::Prefix("bun-v").parse("1.2.3"), Version::from("1.2.3"));
+      assert_eq!(TagFormat::Prefix("bun-v".into()).parse("1.2.3"), Version::from("1.2.3"));
     }
   }
 
@@ -76,7 +76,7 @@ mod tests {
 
     #[test]
     fn custom() {
-      assert_eq!(TagFormat::Prefix("@pkg/").format_version(&Version::from("1.0.0")), "@pkg/1.0.0");
+      assert_eq!(TagFormat::Prefix("@pkg/".into()).format_version(&Version::from("1.0.0")), "@pkg/1.0.0");
     }
   }
 }
@@ -0,0 +1,87 @@
+use crate::error::{Result, UserError};
+use std::fmt::Display;
+
+/// versions that match any of several version requirements, like the npm range "^18 || ^20"
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionRange(Vec<semver::VersionReq>);
+
+impl VersionRange {
+  /// a range that allows all versions
+  #[must_use]
+  pub fn any() -> VersionRange {
+    VersionRange(vec![semver::VersionReq::STAR])
+  }
+
+  /// indicates whether this range allows all versions
+  #[must_use]
+  pub fn is_any(&self) -> bool {
+    self.0.contains(&semver::VersionReq::STAR)
+  }
+
+  /// indicates whether the given version matches any of the alternatives in this range
+  #[must_use]
+  pub fn matches(&self, version: &semver::Version) -> bool {
+    self.0.iter().any(|alternative| alternative.matches(version))
+  }
+
+  /// parses the given Cargo-style version requirements, separated by "||"
+  pub fn parse(text: &str) -> Result<VersionRange> {
+    let mut alternatives = vec![];
+    for alternative in text.split("||") {
+      let alternative = alternative.trim();
+      alternatives.push(semver::VersionReq::parse(alternative).map_err(|err| UserError::CannotParseSemverRange {
+        expression: alternative.to_string(),
+        reason: err.to_string(),
+      })?);
+    }
+    Ok(VersionRange(alternatives))
+  }
+}
+
+impl Display for VersionRange {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let alternatives: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+    f.write_str(&alternatives.join(" || "))
+  }
+}
+
+impl From<semver::VersionReq> for VersionRange {
+  fn from(value: semver::VersionReq) -> Self {
+    VersionRange(vec![value])
+  }
+}
+
+impl From<Vec<semver::VersionReq>> for VersionRange {
+  fn from(value: Vec<semver::VersionReq>) -> Self {
+    VersionRange(value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse {
+    use super::super::VersionRange;
+
+    #[test]
+    fn single() {
+      let have = VersionRange::parse(">=1.2").unwrap();
+      assert_eq!(have.to_string(), ">=1.2");
+      assert!(!have.is_any());
+    }
+
+    #[test]
+    fn alternatives() {
+      let have = VersionRange::parse("^18 || ^20").unwrap();
+      assert_eq!(have.to_string(), "^18 || ^20");
+      assert!(have.matches(&semver::Version::new(18, 1, 0)));
+      assert!(!have.matches(&semver::Version::new(19, 0, 0)));
+      assert!(have.matches(&semver::Version::new(20, 3, 1)));
+    }
+
+    #[test]
+    fn invalid() {
+      assert!(VersionRange::parse("^18 || zonk").is_err());
+    }
+  }
+}
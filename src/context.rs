@@ -1,9 +1,12 @@
 //! Runtime context for passing common parameters through function calls.
 
+use crate::bundle::Bundle;
 use crate::configuration;
 use crate::logging::Log;
 use crate::platform::Platform;
 use crate::yard::Yard;
+use std::env;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 /// Context struct that holds common runtime parameters to avoid passing
 /// many parameters through multiple layers of function calls.
@@ -12,5 +15,62 @@ pub struct RuntimeContext<'a> {
   pub platform: Platform,
   pub yard: &'a Yard,
   pub config_file: &'a configuration::File,
+  pub lock_file: &'a Mutex<configuration::LockFile>,
   pub log: Log,
+  /// whether to use only applications that are already installed, without accessing the network
+  pub offline: bool,
+  /// whether the platform differs from the platform of this machine,
+  /// which means the installed executables cannot run here
+  pub cross_platform: bool,
+  /// the bundle that records the downloaded artifacts or provides them instead of downloading them
+  pub bundle: Option<&'a Bundle>,
+}
+
+impl RuntimeContext<'_> {
+  /// provides exclusive access to the lockfile
+  pub fn lock_file_mut(&self) -> MutexGuard<'_, configuration::LockFile> {
+    self.lock_file.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  /// indicates whether globally installed applications can fulfill requests,
+  /// which isn't the case for other platforms and when creating bundles
+  pub fn uses_global_installations(&self) -> bool {
+    !self.cross_platform && self.bundle.is_none()
+  }
+}
+
+/// name of the environment variable that enables offline mode
+pub const OFFLINE_ENV_VAR: &str = "RTA_OFFLINE";
+
+/// indicates whether the user requested offline mode, either via the CLI flag or the environment
+pub fn offline(cli_flag: bool) -> bool {
+  cli_flag || env::var(OFFLINE_ENV_VAR).is_ok_and(|value| is_truthy(&value))
+}
+
+fn is_truthy(value: &str) -> bool {
+  !matches!(value.trim().to_lowercase().as_str(), "" | "0" | "false" | "no" | "off")
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod is_truthy {
+    use super::super::is_truthy;
+
+    #[test]
+    fn truthy() {
+      assert!(is_truthy("1"));
+      assert!(is_truthy("true"));
+      assert!(is_truthy("yes"));
+    }
+
+    #[test]
+    fn falsy() {
+      assert!(!is_truthy(""));
+      assert!(!is_truthy("0"));
+      assert!(!is_truthy("false"));
+      assert!(!is_truthy("FALSE"));
+      assert!(!is_truthy("off"));
+    }
+  }
 }
@@ -1,4 +1,4 @@
-use super::Url;
+use super::{Url, mirror};
 use crate::applications::ApplicationName;
 use crate::configuration::Version;
 use crate::error::{Result, UserError};
@@ -6,8 +6,9 @@ use crate::logging::{Event, Log};
 
 /// downloads the artifact at the given URL
 pub fn artifact(url: &Url, app: &ApplicationName, version: &Version, optional: bool, log: Log) -> Result<Option<Artifact>> {
+  let download_url = mirror::rewrite(url.as_ref(), log)?;
   log(Event::DownloadBegin { app, version, url });
-  let Ok(response) = minreq::get(url.as_ref()).send() else {
+  let Ok(response) = minreq::get(download_url).send() else {
     log(Event::NotOnline);
     return Err(UserError::NotOnline);
   };
@@ -0,0 +1,271 @@
+use crate::error::{Result, UserError};
+use crate::logging::{Event, Log};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::{env, fs};
+
+/// name of the environment variable that contains mirror rules
+pub const MIRRORS_ENV_VAR: &str = "RTA_MIRRORS";
+
+/// name of the file in the user's config folder that contains mirror rules
+const MIRRORS_FILE_NAME: &str = "mirrors";
+
+/// the mirror rules configured on this machine, loaded on first use
+static MIRRORS: OnceLock<Mirrors> = OnceLock::new();
+
+/// provides the URL to use for downloading the given URL, taking the configured mirrors into account
+pub fn rewrite(url: &str, log: Log) -> Result<String> {
+  let mirrors = if let Some(mirrors) = MIRRORS.get() {
+    mirrors
+  } else {
+    let loaded = Mirrors::load()?;
+    MIRRORS.get_or_init(|| loaded)
+  };
+  let rewritten = mirrors.rewrite(url);
+  if rewritten != url {
+    log(Event::MirrorRewrite { from: url, to: &rewritten });
+  }
+  Ok(rewritten)
+}
+
+/// rules that redirect downloads to mirrors, for example an internal artifact proxy
+#[derive(Debug, Default, PartialEq)]
+pub struct Mirrors {
+  rules: Vec<Rule>,
+}
+
+/// redirects all URLs starting with `from` to the same path under `to`
+#[derive(Debug, PartialEq)]
+struct Rule {
+  from: String,
+  to: String,
+}
+
+impl Mirrors {
+  /// loads the mirror rules from the environment and the user config file,
+  /// rules in the environment take precedence
+  fn load() -> Result<Mirrors> {
+    let mut rules = vec![];
+    if let Ok(text) = env::var(MIRRORS_ENV_VAR) {
+      rules.extend(parse(&text, MIRRORS_ENV_VAR)?);
+    }
+    if let Some(path) = file_path() {
+      match fs::read_to_string(&path) {
+        Ok(text) => rules.extend(parse(&text, &path.to_string_lossy())?),
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => {
+          return Err(UserError::CannotReadMirrorsFile { path, err: err.to_string() });
+        }
+      }
+    }
+    Ok(Mirrors { rules })
+  }
+
+  /// provides the given URL rewritten by the rule with the longest matching prefix
+  pub fn rewrite(&self, url: &str) -> String {
+    let mut best: Option<&Rule> = None;
+    for rule in &self.rules {
+      if has_prefix(url, &rule.from) && best.is_none_or(|best| rule.from.len() > best.from.len()) {
+        best = Some(rule);
+      }
+    }
+    match best {
+      Some(rule) => format!("{}{}", rule.to, &url[rule.from.len()..]),
+      None => url.to_string(),
+    }
+  }
+}
+
+/// indicates whether the given URL starts with the given prefix at a path boundary,
+/// so that `https://github.com` matches `https://github.com/org` but not `https://github.com.evil.com`
+pub fn has_prefix(url: &str, prefix: &str) -> bool {
+  let Some(rest) = url.strip_prefix(prefix) else {
+    return false;
+  };
+  prefix.ends_with('/') || rest.is_empty() || rest.starts_with('/')
+}
+
+/// location of the file containing mirror rules
+fn file_path() -> Option<PathBuf> {
+  dirs::config_dir().map(|dir| dir.join("run-that-app").join(MIRRORS_FILE_NAME))
+}
+
+/// parses rules in the format `<original prefix>=<mirror prefix>`, separated by newlines, commas, or whitespace
+fn parse(text: &str, source: &str) -> Result<Vec<Rule>> {
+  let mut rules = vec![];
+  for line in text.lines() {
+    let line = line.split('#').next().unwrap_or_default();
+    for rule_text in line.split(|c: char| c == ',' || c.is_ascii_whitespace()).filter(|part| !part.is_empty()) {
+      let Some((from, to)) = rule_text.split_once('=') else {
+        return Err(UserError::InvalidMirrorRule {
+          rule: rule_text.to_string(),
+          source: source.to_string(),
+        });
+      };
+      if from.is_empty() || to.is_empty() {
+        return Err(UserError::InvalidMirrorRule {
+          rule: rule_text.to_string(),
+          source: source.to_string(),
+        });
+      }
+      rules.push(Rule {
+        from: from.to_string(),
+        to: to.to_string(),
+      });
+    }
+  }
+  Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse {
+    use super::super::{Rule, parse};
+    use crate::error::UserError;
+    use big_s::S;
+
+    #[test]
+    fn env_var() {
+      let give = "https://github.com=https://mirror.corp/github,https://nodejs.org=http://localhost:8080/node";
+      let have = parse(give, "RTA_MIRRORS").unwrap();
+      let want = vec![
+        Rule {
+          from: S("https://github.com"),
+          to: S("https://mirror.corp/github"),
+        },
+        Rule {
+          from: S("https://nodejs.org"),
+          to: S("http://localhost:8080/node"),
+        },
+      ];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn file() {
+      let give = "# redirect to Artifactory\nhttps://github.com=https://mirror.corp/github  # GitHub\n\nhttps://go.dev=https://mirror.corp/go\n";
+      let have = parse(give, "mirrors").unwrap();
+      let want = vec![
+        Rule {
+          from: S("https://github.com"),
+          to: S("https://mirror.corp/github"),
+        },
+        Rule {
+          from: S("https://go.dev"),
+          to: S("https://mirror.corp/go"),
+        },
+      ];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn missing_target() {
+      let have = parse("https://github.com=", "RTA_MIRRORS");
+      let want = Err(UserError::InvalidMirrorRule {
+        rule: S("https://github.com="),
+        source: S("RTA_MIRRORS"),
+      });
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn missing_separator() {
+      let have = parse("https://github.com", "RTA_MIRRORS");
+      let want = Err(UserError::InvalidMirrorRule {
+        rule: S("https://github.com"),
+        source: S("RTA_MIRRORS"),
+      });
+      pretty::assert_eq!(have, want);
+    }
+  }
+
+  mod has_prefix {
+    use super::super::has_prefix;
+
+    #[test]
+    fn path_boundary() {
+      assert!(has_prefix("https://github.com/org/repo", "https://github.com"));
+      assert!(has_prefix("https://github.com/org/repo", "https://github.com/"));
+      assert!(has_prefix("https://github.com/org/repo", "https://github.com/org"));
+      assert!(has_prefix("https://github.com", "https://github.com"));
+    }
+
+    #[test]
+    fn other_host() {
+      assert!(!has_prefix("https://github.com.evil.com/org/repo", "https://github.com"));
+      assert!(!has_prefix("https://github.com@evil.com/org/repo", "https://github.com"));
+      assert!(!has_prefix("https://github.com:8080/org/repo", "https://github.com"));
+    }
+
+    #[test]
+    fn partial_path_element() {
+      assert!(!has_prefix("https://github.com/org2/repo", "https://github.com/org"));
+    }
+  }
+
+  mod rewrite {
+    use super::super::{Mirrors, parse};
+
+    fn mirrors(text: &str) -> Mirrors {
+      Mirrors {
+        rules: parse(text, "test").unwrap(),
+      }
+    }
+
+    #[test]
+    fn matching_prefix() {
+      let mirrors = mirrors("https://github.com=https://mirror.corp/github");
+      let have = mirrors.rewrite("https://github.com/rhysd/actionlint/releases/download/v1.7.7/actionlint_1.7.7_linux_amd64.tar.gz");
+      let want = "https://mirror.corp/github/rhysd/actionlint/releases/download/v1.7.7/actionlint_1.7.7_linux_amd64.tar.gz";
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+      let mirrors = mirrors("https://github.com=https://mirror.corp/github https://github.com/cli=https://mirror.corp/gh");
+      let have = mirrors.rewrite("https://github.com/cli/cli/releases/download/v2.39.1/gh_2.39.1_linux_amd64.tar.gz");
+      let want = "https://mirror.corp/gh/cli/releases/download/v2.39.1/gh_2.39.1_linux_amd64.tar.gz";
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn no_matching_prefix() {
+      let mirrors = mirrors("https://github.com=https://mirror.corp/github");
+      let have = mirrors.rewrite("https://nodejs.org/dist/v20.10.0/node-v20.10.0-linux-x64.tar.xz");
+      assert_eq!(have, "https://nodejs.org/dist/v20.10.0/node-v20.10.0-linux-x64.tar.xz");
+    }
+
+    #[test]
+    fn no_rules() {
+      let have = Mirrors::default().rewrite("https://api.github.com/repos/rhysd/actionlint/releases/latest");
+      assert_eq!(have, "https://api.github.com/repos/rhysd/actionlint/releases/latest");
+    }
+
+    #[test]
+    fn local_stand_in() {
+      use std::io::{Read, Write};
+      use std::net::TcpListener;
+      use std::thread;
+      let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+      let port = listener.local_addr().unwrap().port();
+      let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 1024];
+        let len = stream.read(&mut request).unwrap();
+        let request = String::from_utf8_lossy(&request[..len]).to_string();
+        stream
+          .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 7\r\nConnection: close\r\n\r\ncontent")
+          .unwrap();
+        request
+      });
+      let mirrors = mirrors(&format!("https://github.com=http://127.0.0.1:{port}/github"));
+      let url = mirrors.rewrite("https://github.com/rhysd/actionlint/releases/download/v1.7.7/actionlint_1.7.7_linux_amd64.tar.gz");
+      let response = minreq::get(url).send().unwrap();
+      assert_eq!(response.as_bytes(), b"content");
+      let request = server.join().unwrap();
+      assert!(request.starts_with("GET /github/rhysd/actionlint/releases/download/v1.7.7/actionlint_1.7.7_linux_amd64.tar.gz HTTP/1.1"));
+    }
+  }
+}
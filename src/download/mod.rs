@@ -1,6 +1,7 @@
 //! This module implements functionality to download files from the internet.
 mod artifact;
+pub mod mirror;
 mod url;
 
-pub use artifact::artifact;
+pub use artifact::{Artifact, artifact};
 pub use url::Url;
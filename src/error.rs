@@ -1,7 +1,7 @@
 use crate::applications::ApplicationName;
-use crate::configuration::{self, FILE_NAME};
+use crate::configuration::{self, FILE_NAME, VersionRange};
 use crate::download::Url;
-use crate::{CommandInfo, Version};
+use crate::{CommandInfo, Version, context, yard};
 use colored::Colorize;
 use std::path::PathBuf;
 
@@ -15,10 +15,21 @@ pub enum UserError {
   ArchiveCannotExtract {
     reason: String,
   },
+  BundleNeedsOneFile,
   CannotAccessConfigFile(String),
+  CannotAccessLockFile(String),
   CannotCompileRustSource {
     err: String,
   },
+  CannotCopyApp {
+    app: ApplicationName,
+    carrier: ApplicationName,
+  },
+  CannotCopyFile {
+    from: PathBuf,
+    to: PathBuf,
+    err: String,
+  },
   CannotCreateFile {
     filename: PathBuf,
     err: String,
@@ -30,11 +41,16 @@ pub enum UserError {
   CannotCreateTempDir {
     err: String,
   },
+  CannotDeleteFile {
+    file: PathBuf,
+    err: String,
+  },
   CannotDeleteFolder {
     folder: PathBuf,
     err: String,
   },
   CannotDetermineCurrentDirectory(String),
+  CannotDetermineExecutablePath(String),
   CannotDetermineHomeDirectory,
   CannotDownload {
     url: Url,
@@ -67,6 +83,14 @@ pub enum UserError {
     expression: String,
     reason: String,
   },
+  CannotReadFile {
+    filename: PathBuf,
+    err: String,
+  },
+  CannotReadMirrorsFile {
+    path: PathBuf,
+    err: String,
+  },
   CannotReadFolder {
     folder: PathBuf,
     err: String,
@@ -74,6 +98,19 @@ pub enum UserError {
   CannotReadZipFile {
     err: String,
   },
+  CannotUpdateInheritedApp {
+    app: ApplicationName,
+    config_file: PathBuf,
+  },
+  ChecksumMismatch {
+    url: Url,
+    expected: String,
+    actual: String,
+  },
+  ChecksumNotListed {
+    checksums: Url,
+    filename: String,
+  },
   CompilationError {
     reason: String,
   },
@@ -86,6 +123,16 @@ pub enum UserError {
     executable: PathBuf,
     err: String,
   },
+  GcWithoutCriteria,
+  GitHubRateLimitExceeded {
+    authenticated: bool,
+    reset_in_seconds: Option<u64>,
+  },
+  GitHubReleaseNotFound {
+    org: String,
+    repo: String,
+    tag: String,
+  },
   GitHubReleasesApiProblem {
     problem: String,
     payload: String,
@@ -96,10 +143,30 @@ pub enum UserError {
   },
   GoCompilationFailed,
   GoNoPermission,
+  InvalidAppsFile {
+    path: PathBuf,
+    problem: String,
+  },
+  InvalidAdhocAppName {
+    name: String,
+    format: &'static str,
+  },
   InvalidConfigFileFormat {
     line_no: usize,
     text: String,
   },
+  InvalidLockFileFormat {
+    line_no: usize,
+    text: String,
+  },
+  InvalidMirrorRule {
+    rule: String,
+    source: String,
+  },
+  InvalidBundle {
+    path: PathBuf,
+    reason: String,
+  },
   InvalidNumber,
   InvalidGitHubAPIResponse {
     err: String,
@@ -108,20 +175,74 @@ pub enum UserError {
     regex: String,
     err: String,
   },
+  InvalidVersionsTtl {
+    value: String,
+  },
+  JsonNotSupported,
   LockCannotAcquire {
     filename: PathBuf,
     err: String,
   },
+  LockFileHashMismatch {
+    app: ApplicationName,
+    version: Version,
+    url: String,
+    locked: String,
+    actual: String,
+  },
+  LockFileMethodMismatch {
+    app: ApplicationName,
+    version: Version,
+    locked: String,
+  },
   MissingApplication,
+  MissingFolder,
   MultipleCommandsGiven,
+  MultipleUpdatePolicies,
+  NotInBundle {
+    app: ApplicationName,
+    version: Version,
+  },
+  NotAvailableOffline {
+    needed: String,
+  },
+  NoConfigFile,
+  NoMatchingReleaseAsset {
+    app: ApplicationName,
+    version: Version,
+    platform: String,
+    assets: Vec<String>,
+  },
+  NoConfigFileInFolder {
+    folder: PathBuf,
+  },
+  NoInstallableVersionAllowed {
+    app: ApplicationName,
+    allowed: VersionRange,
+  },
+  NoPinnedVersion {
+    app: ApplicationName,
+  },
   NotOnline,
   NoVersionsFound {
     app: ApplicationName,
   },
+  PackageNotFound {
+    name: String,
+    url: String,
+  },
+  PackageRegistryProblem {
+    url: String,
+    problem: String,
+  },
   ProcessEmittedOutput {
     cmd: CommandInfo,
   },
   RegexDoesntMatch,
+  ReleaseAssetWithoutExecutable {
+    asset: String,
+    executable: String,
+  },
   RegexHasNoCaptures,
   RunRequestMissingVersion {
     app: ApplicationName,
@@ -129,9 +250,12 @@ pub enum UserError {
   RustCompilationFailed,
   RustNotInstalled,
   RustNoPermission,
+  ShimsNeedOneFolder,
   UnknownApp(String),
   UnknownArchive(String),
   UnknownCliOption(String),
+  UnknownPlatform(String),
+  UnknownShell(String),
   UnsupportedPlatform {
     app: ApplicationName,
   },
@@ -158,13 +282,28 @@ impl UserError {
       UserError::ArchiveCannotExtract { reason } => {
         error(&format!("cannot extract the archive: {reason}"));
       }
+      UserError::BundleNeedsOneFile => {
+        error("--bundle and --import need exactly one file");
+        desc("Example: rta --bundle tools.tar.gz");
+      }
       UserError::CannotAccessConfigFile(reason) => {
         error(&format!("cannot read the config file: {reason}"));
         desc(&format!("please make sure {} is a file and accessible to you", configuration::FILE_NAME));
       }
+      UserError::CannotAccessLockFile(reason) => {
+        error(&format!("cannot access the lockfile: {reason}"));
+        desc(&format!("please make sure {} is a file and accessible to you", configuration::LOCK_FILE_NAME));
+      }
       UserError::CannotCompileRustSource { err } => error(&format!("cannot compile Rust source: {err}")),
+      UserError::CannotDeleteFile { file, err } => error(&format!("cannot delete file {}: {err}", file.display())),
       UserError::CannotDeleteFolder { folder, err } => error(&format!("cannot delete folder {}: {err}", folder.to_string_lossy())),
       UserError::CannotDetermineCurrentDirectory(reason) => error(&format!("cannot determine the current directory: {reason}")),
+      UserError::CannotDetermineExecutablePath(reason) => error(&format!("cannot determine the path of the run-that-app executable: {reason}")),
+      UserError::CannotCopyApp { app, carrier } => {
+        error(&format!("cannot copy {app} into a folder"));
+        desc(&format!("{app} runs on top of {carrier} and doesn't work outside of run-that-app."));
+      }
+      UserError::CannotCopyFile { from, to, err } => error(&format!("cannot copy {} to {}: {err}", from.display(), to.display())),
       UserError::CannotCreateFile { filename, err } => error(&format!("cannot create file {}: {err}", filename.display())),
       UserError::CannotCreateFolder { folder, reason } => {
         error(&format!("cannot create folder {folder}: {reason}", folder = folder.to_string_lossy()));
@@ -207,8 +346,24 @@ impl UserError {
         error(&format!("semver range \"{expression}\" is incorrect: {reason}"));
         desc("Please use formats described at https://devhints.io/semver.");
       }
+      UserError::CannotReadFile { filename, err } => error(&format!("cannot read file {}: {err}", filename.display())),
       UserError::CannotReadFolder { folder, err } => error(&format!("cannot read folder {}: {err}", folder.display())),
+      UserError::CannotReadMirrorsFile { path, err } => error(&format!("cannot read mirrors file {}: {err}", path.display())),
       UserError::CannotReadZipFile { err } => error(&format!("cannot read ZIP file: {err}")),
+      UserError::CannotUpdateInheritedApp { app, config_file } => {
+        error(&format!("{app} is defined in the inherited config file {}", config_file.display()));
+        desc("--update changes only the nearest config file because other projects might share the inherited ones.");
+        desc("Please run --update in the folder of that config file.");
+      }
+      UserError::ChecksumMismatch { url, expected, actual } => {
+        error(&format!("checksum mismatch for {url}"));
+        desc(&format!("expected SHA-256: {expected}\nactual SHA-256:   {actual}"));
+        desc("The downloaded file differs from the one the publisher released. Nothing has been installed.");
+      }
+      UserError::ChecksumNotListed { checksums, filename } => {
+        error(&format!("{filename} is not listed in {checksums}"));
+        desc("Cannot verify the integrity of the download. Nothing has been installed.");
+      }
       UserError::CompilationError { reason } => {
         error(&format!("Compilation error: {reason}"));
       }
@@ -225,6 +380,29 @@ impl UserError {
       UserError::ExecutableCannotExecute { executable, err } => {
         error(&format!("cannot execute {}: {err}", executable.to_string_lossy()));
       }
+      UserError::GcWithoutCriteria => {
+        error("no criteria for deleting app versions given");
+        desc("Please provide the project folders whose config files reference the app versions to keep, or --unused-days=<n>");
+      }
+      UserError::GitHubRateLimitExceeded {
+        authenticated,
+        reset_in_seconds,
+      } => {
+        error("GitHub API rate limit exceeded");
+        match reset_in_seconds {
+          Some(seconds) => desc(&format!("The limit resets in {} minutes.", seconds.div_ceil(60))),
+          None => desc("Please try again later."),
+        }
+        if !authenticated {
+          desc(
+            "Unauthenticated requests are limited to 60 per hour. Provide a GitHub access token via the GITHUB_TOKEN or GH_TOKEN environment variable to raise this limit.",
+          );
+        }
+      }
+      UserError::GitHubReleaseNotFound { org, repo, tag } => {
+        error(&format!("release {tag} of {org}/{repo} not found on GitHub"));
+        desc(&format!("Please check https://github.com/{org}/{repo}/releases for the available releases."));
+      }
       UserError::GitHubReleasesApiProblem { problem, payload } => {
         error(&format!("Problem with the GitHub Releases API: {problem}"));
         desc(&payload);
@@ -238,35 +416,145 @@ impl UserError {
         desc("Please see the error output above and try again with a different version.");
       }
       UserError::GoNoPermission => error("No permission to execute the Go compiler"),
+      UserError::InvalidAppsFile { path, problem } => {
+        error(&format!("invalid app definitions in {}", path.display()));
+        desc(&problem);
+      }
+      UserError::InvalidAdhocAppName { name, format } => {
+        error(&format!("invalid application name: {name}"));
+        desc(&format!("Please provide it in this format: {format}"));
+      }
       UserError::InvalidConfigFileFormat { line_no, text } => {
         error("Invalid config file format");
         desc(&format!("{}:{line_no}: {text}", configuration::FILE_NAME));
       }
+      UserError::InvalidLockFileFormat { line_no, text } => {
+        error("Invalid lockfile format");
+        desc(&format!("{}:{line_no}: {text}", configuration::LOCK_FILE_NAME));
+      }
       UserError::InvalidGitHubAPIResponse { err } => error(&format!("invalid GitHub API response: {err}")),
+      UserError::InvalidMirrorRule { rule, source } => {
+        error(&format!("invalid mirror rule \"{rule}\" in {source}"));
+        desc("Please use the format <original URL prefix>=<mirror URL prefix>, for example https://github.com=https://mirror.example.com/github");
+      }
+      UserError::InvalidBundle { path, reason } => {
+        error(&format!("invalid bundle {}: {reason}", path.display()));
+        desc("Please create the bundle again with rta --bundle.");
+      }
       UserError::InvalidNumber => {
         error("Invalid number given");
       }
       UserError::InvalidRegex { regex, err } => error(&format!("invalid regex '{regex}': {err}")),
+      UserError::InvalidVersionsTtl { value } => {
+        error(&format!("invalid value for {}: {value}", yard::VERSIONS_TTL_ENV_VAR));
+        desc("Please provide the number of seconds that cached versions remain valid, for example 3600");
+      }
+      UserError::JsonNotSupported => {
+        error("this command doesn't support --json");
+        desc("Only --apps, --available, --installed, --outdated, --test, --update, --versions, and --which provide JSON output.");
+      }
       UserError::LockCannotAcquire { filename, err } => {
         error(&format!("cannot acquire lock for {}: {err}", filename.display()));
       }
+      UserError::LockFileHashMismatch {
+        app,
+        version,
+        url,
+        locked,
+        actual,
+      } => {
+        error(&format!("{app}@{version} differs from the locked download"));
+        desc(&format!("downloaded from: {url}\nlocked SHA-256: {locked}\nactual SHA-256: {actual}"));
+        desc(&format!(
+          "The file was modified after it was recorded in {}. Nothing has been installed.",
+          configuration::LOCK_FILE_NAME
+        ));
+      }
+      UserError::LockFileMethodMismatch { app, version, locked } => {
+        error(&format!(
+          "cannot install {app}@{version} the way {} requires: {locked}",
+          configuration::LOCK_FILE_NAME
+        ));
+        desc(&format!(
+          "To lock a new installation method, remove the entries for {app} from {} and install again.",
+          configuration::LOCK_FILE_NAME
+        ));
+      }
       UserError::MissingApplication => {
         error("missing application");
         desc("Please provide the application to execute");
       }
+      UserError::MissingFolder => {
+        error("missing folder");
+        desc("Please provide the folder to copy the executables into, for example: --to=bin");
+      }
       UserError::MultipleCommandsGiven => {
         error("multiple commands given");
         desc("Please provide either --which or --available or nothing to run the app, but not both");
       }
+      UserError::MultipleUpdatePolicies => {
+        error("multiple update policies given");
+        desc("Please provide only one of --patch, --minor, or --major");
+      }
+      UserError::NotInBundle { app, version } => {
+        error(&format!("the bundle doesn't contain {app}@{version}"));
+        desc("Please create the bundle again on a machine with internet access.");
+      }
+      UserError::NotAvailableOffline { needed } => {
+        error(&format!("not available offline: {needed}"));
+        desc(&format!(
+          "This requires network access, but offline mode is enabled via --offline or {}.\nPlease run this command once while online to install the app or cache its versions locally.",
+          context::OFFLINE_ENV_VAR
+        ));
+      }
+      UserError::NoConfigFile => {
+        error(&format!("no {FILE_NAME} file found"));
+        desc("Run-that-app looks for it in the current folder and its parent folders, up to the root of the Git repository.");
+      }
+      UserError::NoMatchingReleaseAsset {
+        app,
+        version,
+        platform,
+        assets,
+      } => {
+        error(&format!("release {version} of {app} contains no file for {platform}"));
+        if assets.is_empty() {
+          desc("The release has no files attached.");
+        } else {
+          desc(&format!("The release contains:\n{}", assets.join("\n")));
+        }
+      }
+      UserError::NoConfigFileInFolder { folder } => {
+        error(&format!("no {FILE_NAME} file in folder {}", folder.display()));
+      }
+      UserError::NoInstallableVersionAllowed { app, allowed } => {
+        error(&format!("no installable version of {app} matches {allowed}"));
+        desc("This restriction comes from the version files in your project.");
+      }
+      UserError::NoPinnedVersion { app } => {
+        error(&format!("{app} has no pinned version in the config file"));
+        desc("--minor and --patch update the pinned version. Use --major or pin a version to update this app.");
+      }
       UserError::NotOnline => error("not online"),
       UserError::NoVersionsFound { app } => {
         error(&format!(r#"application "{app}" is not listed in the "run-that-app" file"#));
         desc(&format!("To add it: run-that-app --add {app}"));
       }
+      UserError::PackageNotFound { name, url } => {
+        error(&format!("package {name} not found"));
+        desc(&format!("The package registry at {url} doesn't know it."));
+      }
+      UserError::PackageRegistryProblem { url, problem } => {
+        error(&format!("problem with the package registry at {url}"));
+        desc(&problem);
+      }
       UserError::ProcessEmittedOutput { cmd } => {
         error(&format!("process \"{cmd}\" emitted unexpected output"));
       }
       UserError::RegexDoesntMatch => error("this regex doesn't match"),
+      UserError::ReleaseAssetWithoutExecutable { asset, executable } => {
+        error(&format!("release asset {asset} contains no executable named {executable}"));
+      }
       UserError::RegexHasNoCaptures => error("regex has no captures"),
       UserError::RunRequestMissingVersion { app } => {
         error(&format!("missing application version for {app}"));
@@ -285,6 +573,10 @@ impl UserError {
         error("Rust is not installed.");
         desc("Please install Rust via https://rustup.rs and try again.");
       }
+      UserError::ShimsNeedOneFolder => {
+        error("--shims needs exactly one folder");
+        desc("Example: rta --shims .bin");
+      }
       UserError::UnknownApp(app_name) => {
         error(&format!("Unknown application: {app_name}"));
         // help::print_installable_apps();
@@ -296,6 +588,16 @@ impl UserError {
         error(&format!("Unknown option: {option}"));
         // help::print_options();
       }
+      UserError::UnknownPlatform(text) => {
+        error(&format!("Unknown platform: {text}"));
+        desc(
+          "Please provide the platform as <os>/<cpu>, for example linux/arm64. Supported operating systems: linux, macos, windows. Supported CPUs: intel64, arm64.",
+        );
+      }
+      UserError::UnknownShell(name) => {
+        error(&format!("Unknown shell: {name}"));
+        desc("Supported shells are: bash, fish, json, zsh");
+      }
       UserError::UnsupportedCPU(name) => {
         error(&format!("Your CPU ({name}) is currently not supported."));
         desc("Request support for your platform at https://github.com/kevgo/run-that-app/issues.");
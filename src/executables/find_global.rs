@@ -0,0 +1,58 @@
+//! Finding globally installed executables in the PATH.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// marks the wrapper scripts that `rta --shims` generates
+pub const SHIM_MARKER: &str = "generated by run-that-app --shims";
+
+/// Provides the first executable with the given name in the PATH that isn't a shim generated by run-that-app.
+/// Shims run run-that-app, which would then find the shim again and recurse forever.
+pub fn find_global(name: &str) -> Option<PathBuf> {
+  which::which_all_global(name).ok()?.find(|path| !is_shim(path))
+}
+
+/// indicates whether the file at the given path is a shim generated by run-that-app
+fn is_shim(path: &Path) -> bool {
+  let Ok(file) = File::open(path) else {
+    return false;
+  };
+  // the marker is in the first lines of a shim, no need to read large executables completely
+  let mut head = vec![];
+  if file.take(256).read_to_end(&mut head).is_err() {
+    return false;
+  }
+  String::from_utf8_lossy(&head).contains(SHIM_MARKER)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod is_shim {
+    use super::super::is_shim;
+    use std::fs;
+
+    #[test]
+    fn shim() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let path = tempdir.path().join("rg");
+      fs::write(&path, "#!/bin/sh\n# generated by run-that-app --shims\nexec 'rta' 'ripgrep' \"$@\"\n").unwrap();
+      assert!(is_shim(&path));
+    }
+
+    #[test]
+    fn executable() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let path = tempdir.path().join("rg");
+      fs::write(&path, "#!/bin/sh\necho hello\n").unwrap();
+      assert!(!is_shim(&path));
+    }
+
+    #[test]
+    fn missing_file() {
+      let tempdir = tempfile::tempdir().unwrap();
+      assert!(!is_shim(&tempdir.path().join("rg")));
+    }
+  }
+}
@@ -15,8 +15,14 @@ pub fn load_app_versions(
 ) -> Result<LoadAppOutcome> {
   for version in versions {
     match version {
-      RequestedVersion::Path(version) => {
-        if let Some(executable) = load_from_path(app, executable, version, ctx)? {
+      RequestedVersion::Path(_) | RequestedVersion::PathAuto => {
+        if !ctx.uses_global_installations() {
+          continue;
+        }
+        let Some(range) = version.path_range(app, ctx)? else {
+          continue;
+        };
+        if let Some(executable) = load_from_path(app, executable, &range, ctx)? {
           return Ok(LoadAppOutcome::Loaded { executable });
         }
       }
@@ -2,23 +2,24 @@
 
 use crate::Version;
 use crate::applications::{AnalyzeResult, AppDefinition};
+use crate::configuration::VersionRange;
 use crate::context::RuntimeContext;
 use crate::error::Result;
-use crate::executables::{Executable, ExecutableNamePlatform};
+use crate::executables::{Executable, ExecutableNamePlatform, find_global};
 use crate::logging::Event;
 
 // finds the given app in the PATH and verifies it has the correct version
 pub fn load_from_path(
   app_to_install: &dyn AppDefinition,
   executable_name: &ExecutableNamePlatform,
-  range: &semver::VersionReq,
+  range: &VersionRange,
   ctx: &RuntimeContext,
 ) -> Result<Option<Executable>> {
   // step 1: find the executable in the PATH
   (ctx.log)(Event::GlobalInstallSearch {
     binary: executable_name.as_ref(),
   });
-  let Ok(path) = which::which_global(executable_name.as_ref()) else {
+  let Some(path) = find_global(executable_name.as_ref()) else {
     (ctx.log)(Event::GlobalInstallNotFound);
     return Ok(None);
   };
@@ -12,6 +12,7 @@ pub fn load_from_yard(app: &dyn AppDefinition, version: &Version, executable: &E
   ctx.yard.with_lock(&app.name(), version, ctx, || {
     // try to load the app from the yard
     if let Some(executable) = ctx.yard.load_executable(app, executable, version, ctx) {
+      ctx.yard.mark_used(&app.name(), version);
       return Ok(LoadAppOutcome::Loaded { executable });
     }
     // here the app is not installed --> check if it is marked as uninstallable
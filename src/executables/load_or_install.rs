@@ -2,7 +2,7 @@ use crate::applications::{AnalyzeResult, AppDefinition, ApplicationName, Apps, N
 use crate::configuration::{RequestedVersion, RequestedVersions};
 use crate::context::RuntimeContext;
 use crate::error::{Result, UserError};
-use crate::executables::{Executable, ExecutableNameUnix, LoadAppOutcome, RunMethod, load_app_versions};
+use crate::executables::{Executable, ExecutableNameUnix, LoadAppOutcome, RunMethod, find_global, load_app_versions};
 use crate::installation::Outcome;
 use crate::logging::Event;
 use crate::{Version, installation};
@@ -98,15 +98,9 @@ pub fn load_or_install_app_and_carrier(
         apps,
       })?;
       // step 2: determine the version of the npm package to run
-      let app_versions = if let Some(version) = cli_version {
-        RequestedVersions::from(version)
-      } else if let Some(versions) = ctx.config_file.lookup(&app.name()) {
-        versions.clone()
-      } else {
-        return Err(UserError::NoVersionsFound { app: app.name() });
-      };
+      let app_versions = requested_versions(app, cli_version, ctx)?;
       // step 3: fast-path: try to load the app executable
-      if let Ok(executable) = locate_npm_package_executable(app, &app_versions, script, ctx) {
+      if let Ok(executable) = locate_npm_package_executable(app, &app_versions, &script, ctx) {
         return Ok(LoadOrInstallAppOutcome::Loaded { executable });
       }
       // step 4: install the npm package
@@ -115,7 +109,7 @@ pub fn load_or_install_app_and_carrier(
         Outcome::NotInstalled { app } => return Ok(LoadOrInstallAppOutcome::NotInstallable { app }),
       }
       // step 5: load the npm package executable
-      if let Ok(executable) = locate_npm_package_executable(app, &app_versions, script, ctx) {
+      if let Ok(executable) = locate_npm_package_executable(app, &app_versions, &script, ctx) {
         return Ok(LoadOrInstallAppOutcome::Loaded { executable });
       }
       println!("ERROR: this shouldn't happen, we just successfully installed npm package {package} and now we can't load it");
@@ -142,9 +136,13 @@ fn locate_npm_package_executable(app: &dyn AppDefinition, versions: &RequestedVe
   let mut tried_paths = Vec::new();
   for version in versions {
     match version {
-      RequestedVersion::Path(range) => {
+      RequestedVersion::Path(_) | RequestedVersion::PathAuto if !ctx.uses_global_installations() => {}
+      RequestedVersion::Path(_) | RequestedVersion::PathAuto => {
+        let Some(range) = &version.path_range(app, ctx)? else {
+          continue;
+        };
         (ctx.log)(Event::GlobalInstallSearch { binary: script });
-        if let Ok(path) = which::which(script) {
+        if let Some(path) = find_global(script) {
           (ctx.log)(Event::GlobalInstallFound { path: &path });
           let executable = Executable::ShellScript(path);
           match app.analyze_executable(&executable)? {
@@ -184,6 +182,7 @@ fn locate_npm_package_executable(app: &dyn AppDefinition, versions: &RequestedVe
         let platform_script_name = script_name(script);
         let script_path = app_folder.join("node_modules").join(".bin").join(platform_script_name);
         if script_path.exists() {
+          ctx.yard.mark_used(&app.name(), version);
           return Ok(Executable::ShellScript(script_path));
         }
         tried_paths.push(script_path.to_string_lossy().to_string());
@@ -196,6 +195,34 @@ fn locate_npm_package_executable(app: &dyn AppDefinition, versions: &RequestedVe
   })
 }
 
+/// provides the versions of the given app to try:
+/// the version given via the CLI, otherwise the versions in the config file,
+/// otherwise the version that the project files pin
+fn requested_versions(app: &dyn AppDefinition, cli_version: Option<&Version>, ctx: &RuntimeContext) -> Result<RequestedVersions> {
+  if let Some(version) = cli_version {
+    return Ok(RequestedVersions::from(version));
+  }
+  let Some(configured) = ctx.config_file.lookup(&app.name()) else {
+    return match app.pinned_version()? {
+      Some(pinned) => Ok(RequestedVersions::from(pinned)),
+      None => Err(UserError::NoVersionsFound { app: app.name() }),
+    };
+  };
+  let mut versions = configured.clone();
+  // install the pinned version if no matching system installation exists
+  if configured
+    .into_iter()
+    .any(|version| matches!(version, RequestedVersion::Path(_) | RequestedVersion::PathAuto))
+    && let Some(pinned) = app.pinned_version()?
+  {
+    let pinned = RequestedVersion::Yard(pinned);
+    if !configured.into_iter().any(|version| version == &pinned) {
+      versions.push(pinned);
+    }
+  }
+  Ok(versions)
+}
+
 #[cfg(not(windows))]
 fn script_name(unix_script_name: &str) -> String {
   unix_script_name.to_string()
@@ -209,20 +236,15 @@ fn script_name(unix_script_name: &str) -> String {
 #[allow(clippy::panic)]
 fn locate_shell_script(carrier: &dyn AppDefinition, cli_version: Option<&Version>, script_name: &str, ctx: &RuntimeContext) -> Result<Executable> {
   // step 1: determine the version of the app to install
-  let versions = if let Some(version) = cli_version {
-    RequestedVersions::from(version)
-  } else if let Some(versions) = ctx.config_file.lookup(&carrier.name()) {
-    versions.clone()
-  } else {
-    return Err(UserError::NoVersionsFound { app: carrier.name() });
-  };
+  let versions = requested_versions(carrier, cli_version, ctx)?;
   // step 2: find the first matching candidate
   let mut tried_paths = Vec::new();
   for version in &versions {
     match version {
-      RequestedVersion::Path(_range) => {
+      RequestedVersion::Path(_) | RequestedVersion::PathAuto if !ctx.uses_global_installations() => {}
+      RequestedVersion::Path(_) | RequestedVersion::PathAuto => {
         (ctx.log)(Event::GlobalInstallSearch { binary: script_name });
-        if let Ok(script_path) = which::which(script_name) {
+        if let Some(script_path) = find_global(script_name) {
           (ctx.log)(Event::GlobalInstallFound { path: &script_path });
           // Note: we cannot verify the version here because shell scripts usually get versioned together with their carrier app
           return Ok(Executable::ShellScript(script_path));
@@ -245,10 +267,16 @@ fn locate_shell_script(carrier: &dyn AppDefinition, cli_version: Option<&Version
         let mut bin_folders = Vec::new();
         for install_method in install_methods {
           match install_method {
-            installation::Method::DownloadArchive { url: _, bin_folder } | installation::Method::CompileRustCrate { name: _, bin_folder } => {
+            installation::Method::DownloadArchive {
+              url: _,
+              checksum: _,
+              bin_folder,
+            }
+            | installation::Method::CompileRustCrate { name: _, bin_folder } => {
               bin_folders.push(bin_folder);
             }
-            installation::Method::DownloadExecutable { url: _ }
+            installation::Method::DownloadExecutable { url: _, checksum: _ }
+            | installation::Method::DownloadGitHubAsset { org: _, repo: _, tag: _ }
             | installation::Method::CompileGoSource { import_path: _ }
             | installation::Method::CompileRustRepo { url: _ } => {}
             installation::Method::InstallNodeJSPackage { package, script: _ } => {
@@ -268,6 +296,7 @@ fn locate_shell_script(carrier: &dyn AppDefinition, cli_version: Option<&Version
           (ctx.log)(Event::YardCheckExistingAppBegin { path: &script_path });
           if script_path.exists() {
             (ctx.log)(Event::YardCheckExistingAppFound);
+            ctx.yard.mark_used(&carrier.name(), version);
             return Ok(Executable::ShellScript(script_path));
           }
           (ctx.log)(Event::YardCheckExistingAppNotFound);
@@ -295,13 +324,7 @@ fn load_or_install_app(
   }: LoadOrInstallAppArgs,
 ) -> Result<LoadOrInstallAppOutcome> {
   // step 1: determine the version of the app to install
-  let versions = if let Some(version) = cli_version {
-    RequestedVersions::from(version)
-  } else if let Some(versions) = ctx.config_file.lookup(&app.name()) {
-    versions.clone()
-  } else {
-    return Err(UserError::NoVersionsFound { app: app.name() });
-  };
+  let versions = requested_versions(app, cli_version, ctx)?;
   // step 2: fast-path: try to load the given executable for the given app
   let executable = executable_name.platform_path(ctx.platform.os);
   match load_app_versions(app, &versions, &executable, ctx)? {
@@ -3,6 +3,7 @@
 mod command_info;
 mod executable;
 mod executable_name;
+mod find_global;
 mod load;
 mod load_from_path;
 mod load_from_yard;
@@ -12,6 +13,7 @@ mod run_method;
 pub use command_info::CommandInfo;
 pub use executable::Executable;
 pub use executable_name::{ExecutableNamePlatform, ExecutableNameUnix};
+pub use find_global::{SHIM_MARKER, find_global};
 pub use load::{LoadAppOutcome, load_app_versions};
 pub use load_from_path::load_from_path;
 pub use load_from_yard::load_from_yard;
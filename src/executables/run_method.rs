@@ -32,10 +32,10 @@ pub enum RunMethod {
   /// the app is a `NodeJS` package
   NodeJS {
     /// name of the `NodeJS` package to install
-    package: &'static str,
+    package: String,
 
     /// unix name of the shell script for the package in `node_modules/.bin`
-    script: &'static str,
+    script: String,
   },
 }
 
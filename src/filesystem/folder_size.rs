@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::Path;
+
+/// provides the disk space used by the given file or folder, in bytes
+pub fn folder_size(path: &Path) -> u64 {
+  let Ok(metadata) = fs::symlink_metadata(path) else {
+    return 0;
+  };
+  if !metadata.is_dir() {
+    return metadata.len();
+  }
+  let Ok(entries) = fs::read_dir(path) else {
+    return 0;
+  };
+  entries.flatten().map(|entry| folder_size(&entry.path())).sum()
+}
+
+/// formats the given amount of bytes for humans
+#[allow(clippy::cast_precision_loss)]
+pub fn format_size(bytes: u64) -> String {
+  const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+  if bytes < 1024 {
+    return format!("{bytes} B");
+  }
+  let mut size = bytes as f64 / 1024.0;
+  let mut unit = 0;
+  while size >= 1024.0 && unit < UNITS.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+  format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod format_size {
+    use super::super::format_size;
+
+    #[test]
+    fn bytes() {
+      assert_eq!(format_size(512), "512 B");
+    }
+
+    #[test]
+    fn kilobytes() {
+      assert_eq!(format_size(1536), "1.5 KB");
+    }
+
+    #[test]
+    fn megabytes() {
+      assert_eq!(format_size(12 * 1024 * 1024 + 300 * 1024), "12.3 MB");
+    }
+
+    #[test]
+    fn gigabytes() {
+      assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+  }
+}
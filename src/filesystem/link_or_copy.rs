@@ -0,0 +1,57 @@
+use crate::error::{Result, UserError};
+use crate::filesystem;
+use std::fs;
+use std::path::Path;
+
+/// Provides the given executable file at the given target path.
+/// Creates a hardlink if possible, otherwise copies the file.
+pub fn link_or_copy_executable(from: &Path, to: &Path) -> Result<()> {
+  if to.exists() {
+    fs::remove_file(to).map_err(|err| UserError::CannotDeleteFile {
+      file: to.to_path_buf(),
+      err: err.to_string(),
+    })?;
+  }
+  // hardlinks don't work across filesystems
+  if fs::hard_link(from, to).is_err() {
+    fs::copy(from, to).map_err(|err| UserError::CannotCopyFile {
+      from: from.to_path_buf(),
+      to: to.to_path_buf(),
+      err: err.to_string(),
+    })?;
+  }
+  filesystem::set_executable_bit(to);
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod link_or_copy_executable {
+    use super::super::link_or_copy_executable;
+    use std::fs;
+
+    #[test]
+    fn replaces_existing_file() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let from = tempdir.path().join("source");
+      let to = tempdir.path().join("target");
+      fs::write(&from, "new").unwrap();
+      fs::write(&to, "old").unwrap();
+      link_or_copy_executable(&from, &to).unwrap();
+      assert_eq!(fs::read_to_string(&to).unwrap(), "new");
+      #[cfg(unix)]
+      {
+        use std::os::unix::fs::PermissionsExt;
+        assert_ne!(fs::metadata(&to).unwrap().permissions().mode() & 0o100, 0);
+      }
+    }
+
+    #[test]
+    fn missing_source() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let have = link_or_copy_executable(&tempdir.path().join("source"), &tempdir.path().join("target"));
+      assert!(have.is_err());
+    }
+  }
+}
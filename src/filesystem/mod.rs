@@ -1,11 +1,17 @@
 //! This module implements accessing the local filesystem.
 
+mod folder_size;
 mod has_extension;
+mod link_or_copy;
 mod read_file;
 mod save_buffer;
 mod set_executable_bit;
+mod subfolders;
 
+pub use folder_size::{folder_size, format_size};
 pub use has_extension::has_extension;
-pub use read_file::read_file;
+pub use link_or_copy::link_or_copy_executable;
+pub use read_file::{find_file, find_file_in, is_repo_root, read_file};
 pub use save_buffer::save_executable;
 pub use set_executable_bit::set_executable_bit;
+pub use subfolders::subfolders;
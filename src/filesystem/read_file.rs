@@ -1,29 +1,106 @@
 use crate::error::{Result, UserError};
 use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
 use std::{env, fs};
 
 /// looks for the file with the given name in the current or parent folders, and provides its content if it finds one
 pub fn read_file(name: &str) -> Result<Option<String>> {
+  let Some(file_path) = find_file(name)? else {
+    return Ok(None);
+  };
+  match fs::read_to_string(file_path) {
+    Ok(text) => Ok(Some(text)),
+    Err(err) => Err(UserError::CannotAccessConfigFile(err.to_string())),
+  }
+}
+
+/// looks for the file with the given name in the current or parent folders, and provides its path if it finds one
+pub fn find_file(name: &str) -> Result<Option<PathBuf>> {
   let cwd = env::current_dir().map_err(|err| UserError::CannotDetermineCurrentDirectory(err.to_string()))?;
-  let mut dir = cwd.as_path();
+  find_file_in(&cwd, name)
+}
+
+/// looks for the file with the given name in the given folder and its parent folders,
+/// up to the root of the Git repository containing the given folder or the filesystem root,
+/// and provides its path if it finds one
+pub fn find_file_in(folder: &Path, name: &str) -> Result<Option<PathBuf>> {
+  let mut dir = folder;
   loop {
     let file_path = dir.join(name);
-    match fs::read_to_string(file_path) {
-      Ok(text) => return Ok(Some(text)),
+    match fs::metadata(&file_path) {
+      Ok(metadata) if metadata.is_dir() => {
+        // we have reached the ".run-that-app" folder in the home directory --> give up looking
+        return Ok(None);
+      }
+      Ok(_) => return Ok(Some(file_path)),
       Err(err) => match err.kind() {
         ErrorKind::NotFound => {
+          if is_repo_root(dir) {
+            // files outside of the repository don't belong to this project
+            return Ok(None);
+          }
           // config file not found --> look in the parent folder
           dir = match dir.parent() {
             Some(parent) => parent,
             None => return Ok(None),
           };
         }
-        ErrorKind::IsADirectory => {
-          // we have reached the ".run-that-app" folder in the home directory --> give up looking
-          return Ok(None);
-        }
         _ => return Err(UserError::CannotAccessConfigFile(err.to_string())),
       },
     }
   }
 }
+
+/// indicates whether the given folder is the root folder of a Git repository
+pub fn is_repo_root(folder: &Path) -> bool {
+  // ".git" is a folder in normal repositories and a file in worktrees and submodules
+  folder.join(".git").exists()
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod find_file_in {
+    use super::super::find_file_in;
+    use std::fs;
+
+    #[test]
+    fn in_given_folder() {
+      let tempdir = tempfile::tempdir().unwrap();
+      fs::write(tempdir.path().join("config"), "").unwrap();
+      let have = find_file_in(tempdir.path(), "config").unwrap();
+      assert_eq!(have, Some(tempdir.path().join("config")));
+    }
+
+    #[test]
+    fn in_parent_folder() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let subfolder = tempdir.path().join("services").join("api");
+      fs::create_dir_all(&subfolder).unwrap();
+      fs::write(tempdir.path().join("config"), "").unwrap();
+      let have = find_file_in(&subfolder, "config").unwrap();
+      assert_eq!(have, Some(tempdir.path().join("config")));
+    }
+
+    #[test]
+    fn in_repo_root() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let subfolder = tempdir.path().join("services");
+      fs::create_dir_all(&subfolder).unwrap();
+      fs::create_dir(tempdir.path().join(".git")).unwrap();
+      fs::write(tempdir.path().join("config"), "").unwrap();
+      let have = find_file_in(&subfolder, "config").unwrap();
+      assert_eq!(have, Some(tempdir.path().join("config")));
+    }
+
+    #[test]
+    fn outside_repo() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let repo = tempdir.path().join("repo");
+      fs::create_dir_all(repo.join(".git")).unwrap();
+      fs::write(tempdir.path().join("config"), "").unwrap();
+      let have = find_file_in(&repo, "config").unwrap();
+      assert_eq!(have, None);
+    }
+  }
+}
@@ -0,0 +1,29 @@
+use crate::error::{Result, UserError};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// provides the names and paths of the subfolders of the given folder, sorted by name
+pub fn subfolders(folder: &Path) -> Result<Vec<(String, PathBuf)>> {
+  let entries = match fs::read_dir(folder) {
+    Ok(entries) => entries,
+    Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+    Err(err) => {
+      return Err(UserError::CannotReadFolder {
+        folder: folder.to_path_buf(),
+        err: err.to_string(),
+      });
+    }
+  };
+  let mut result = vec![];
+  for entry in entries.flatten() {
+    if !entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+      continue;
+    }
+    if let Ok(name) = entry.file_name().into_string() {
+      result.push((name, entry.path()));
+    }
+  }
+  result.sort();
+  Ok(result)
+}
@@ -0,0 +1,80 @@
+use super::registry_api;
+use crate::Log;
+use crate::configuration::Version;
+use crate::error::{Result, UserError};
+use serde_json::Value;
+
+const API_URL: &str = "https://crates.io/api/v1/crates";
+
+/// provides the latest stable version of the given crate on crates.io
+pub fn latest(name: &str, log: Log) -> Result<Version> {
+  let url = format!("{API_URL}/{name}");
+  let crate_info = load(name, &url, log)?;
+  let latest = crate_info["crate"]["max_stable_version"]
+    .as_str()
+    .or_else(|| crate_info["crate"]["newest_version"].as_str());
+  match latest {
+    Some(latest) => Ok(Version::from(latest)),
+    None => Err(UserError::PackageRegistryProblem {
+      url,
+      problem: String::from("missing 'crate.max_stable_version' field"),
+    }),
+  }
+}
+
+/// provides the given number of latest stable versions of the given crate on crates.io
+pub fn versions(name: &str, amount: usize, log: Log) -> Result<Vec<Version>> {
+  let url = format!("{API_URL}/{name}");
+  Ok(parse_versions(&load(name, &url, log)?, amount))
+}
+
+fn load(name: &str, url: &str, log: Log) -> Result<Value> {
+  let Some(text) = registry_api::get(url, log)? else {
+    return Err(UserError::PackageNotFound {
+      name: name.to_string(),
+      url: String::from("https://crates.io"),
+    });
+  };
+  serde_json::from_str(&text).map_err(|err| UserError::PackageRegistryProblem {
+    url: url.to_string(),
+    problem: err.to_string(),
+  })
+}
+
+fn parse_versions(crate_info: &Value, amount: usize) -> Vec<Version> {
+  let Value::Array(versions) = &crate_info["versions"] else {
+    return vec![];
+  };
+  let mut result: Vec<Version> = versions
+    .iter()
+    .filter(|version| version["yanked"].as_bool() != Some(true))
+    .filter_map(|version| version["num"].as_str())
+    .filter(|num| !num.contains('-'))
+    .map(Version::from)
+    .collect();
+  result.sort_unstable_by(|a, b| b.cmp(a));
+  result.truncate(amount);
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::configuration::Version;
+
+  #[test]
+  fn parse_versions() {
+    let give = serde_json::json!({
+      "crate": { "name": "tokei", "max_stable_version": "12.1.2" },
+      "versions": [
+        { "num": "13.0.0-alpha.1", "yanked": false },
+        { "num": "12.1.2", "yanked": false },
+        { "num": "12.1.1", "yanked": true },
+        { "num": "12.1.0", "yanked": false },
+        { "num": "12.0.4", "yanked": false },
+      ]
+    });
+    let have = super::parse_versions(&give, 2);
+    let want: Vec<Version> = vec!["12.1.2".into(), "12.1.0".into()];
+    assert_eq!(have, want);
+  }
+}
@@ -0,0 +1,135 @@
+use crate::Log;
+use crate::download::mirror;
+use crate::error::{Result, UserError};
+use crate::logging::Event;
+use std::collections::HashMap;
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// environment variables that can contain a GitHub access token, in order of precedence
+const TOKEN_ENV_VARS: [&str; 2] = ["GITHUB_TOKEN", "GH_TOKEN"];
+
+/// the GitHub API, the only server that receives the access token
+const API_URL: &str = "https://api.github.com";
+
+/// sends a GET request to the GitHub API at the given URL,
+/// authenticated with the access token in the environment if one exists
+pub fn get(url: &str, params: &[(&str, String)], log: Log) -> Result<minreq::Response> {
+  let request_url = mirror::rewrite(url, log)?;
+  log(Event::GitHubApiRequestBegin { url });
+  let mut request = minreq::get(&request_url)
+    .with_header("Accept", "application/vnd.github+json")
+    .with_header("User-Agent", format!("run-that-app-{}", env!("CARGO_PKG_VERSION")))
+    .with_header("X-GitHub-Api-Version", "2022-11-28");
+  for (key, value) in params {
+    request = request.with_param(*key, value);
+  }
+  // send the token only to GitHub, never to mirrors
+  let token = if mirror::has_prefix(&request_url, API_URL) { token() } else { None };
+  let authenticated = token.is_some();
+  if let Some(token) = token {
+    request = request.with_header("Authorization", format!("Bearer {token}"));
+  }
+  let Ok(response) = request.send() else {
+    log(Event::NotOnline);
+    return Err(UserError::NotOnline);
+  };
+  if let Some(err) = rate_limit_error(response.status_code, &response.headers, authenticated, now()) {
+    log(Event::GitHubApiRequestFail { err: &"rate limit exceeded" });
+    return Err(err);
+  }
+  Ok(response)
+}
+
+/// provides the GitHub access token in the environment
+fn token() -> Option<String> {
+  TOKEN_ENV_VARS
+    .iter()
+    .filter_map(|name| env::var(name).ok())
+    .map(|value| value.trim().to_string())
+    .find(|value| !value.is_empty())
+}
+
+/// provides the current time in seconds since the Unix epoch
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs())
+    .unwrap_or_default()
+}
+
+/// provides the error to report if the given response indicates an exhausted rate limit
+fn rate_limit_error(status_code: i32, headers: &HashMap<String, String>, authenticated: bool, now: u64) -> Option<UserError> {
+  if status_code != 403 && status_code != 429 {
+    return None;
+  }
+  if headers.get("x-ratelimit-remaining").map(|remaining| remaining.trim()) != Some("0") {
+    return None;
+  }
+  let reset_in_seconds = headers
+    .get("x-ratelimit-reset")
+    .and_then(|reset| reset.trim().parse::<u64>().ok())
+    .map(|reset| reset.saturating_sub(now));
+  Some(UserError::GitHubRateLimitExceeded {
+    authenticated,
+    reset_in_seconds,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod rate_limit_error {
+    use super::super::rate_limit_error;
+    use crate::error::UserError;
+    use big_s::S;
+    use maplit::hashmap;
+
+    #[test]
+    fn exhausted() {
+      let headers = hashmap! {
+        S("x-ratelimit-limit") => S("60"),
+        S("x-ratelimit-remaining") => S("0"),
+        S("x-ratelimit-reset") => S("1700003600"),
+      };
+      let have = rate_limit_error(403, &headers, false, 1_700_000_000);
+      let want = Some(UserError::GitHubRateLimitExceeded {
+        authenticated: false,
+        reset_in_seconds: Some(3600),
+      });
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn exhausted_without_reset() {
+      let headers = hashmap! {
+        S("x-ratelimit-remaining") => S("0"),
+      };
+      let have = rate_limit_error(429, &headers, true, 1_700_000_000);
+      let want = Some(UserError::GitHubRateLimitExceeded {
+        authenticated: true,
+        reset_in_seconds: None,
+      });
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn remaining() {
+      let headers = hashmap! {
+        S("x-ratelimit-remaining") => S("12"),
+        S("x-ratelimit-reset") => S("1700003600"),
+      };
+      let have = rate_limit_error(403, &headers, false, 1_700_000_000);
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn success() {
+      let headers = hashmap! {
+        S("x-ratelimit-remaining") => S("0"),
+      };
+      let have = rate_limit_error(200, &headers, false, 1_700_000_000);
+      assert_eq!(have, None);
+    }
+  }
+}
@@ -1,3 +1,4 @@
+use super::github_api;
 use crate::Log;
 use crate::configuration::{TagFormat, Version};
 use crate::error::{Result, UserError};
@@ -7,15 +8,7 @@ use big_s::S;
 /// provides the latest official version of the given application on GitHub Releases
 pub fn latest(org: &str, repo: &str, tag_format: &TagFormat, log: Log) -> Result<Version> {
   let url = format!("https://api.github.com/repos/{org}/{repo}/releases/latest");
-  log(Event::GitHubApiRequestBegin { url: &url });
-  let get = minreq::get(&url)
-    .with_header("Accept", "application/vnd.github+json")
-    .with_header("User-Agent", format!("run-that-app-{}", env!("CARGO_PKG_VERSION")))
-    .with_header("X-GitHub-Api-Version", "2022-11-28");
-  let Ok(response) = get.send() else {
-    log(Event::NotOnline);
-    return Err(UserError::NotOnline);
-  };
+  let response = github_api::get(&url, &[], log)?;
   let response_text = match response.as_str() {
     Ok(text) => {
       log(Event::GitHubApiRequestSuccess);
@@ -48,16 +41,7 @@ fn parse_latest_response(text: &str, tag_format: &TagFormat) -> Result<Version>
 /// provides the given number of latest versions of the given application on GitHub Releases
 pub fn versions(org: &str, repo: &str, amount: usize, tag_format: &TagFormat, log: Log) -> Result<Vec<Version>> {
   let url = format!("https://api.github.com/repos/{org}/{repo}/releases?per_page={amount}");
-  log(Event::GitHubApiRequestBegin { url: &url });
-  let get = minreq::get(&url)
-    .with_param("per_page", amount.to_string())
-    .with_header("Accept", "application/vnd.github+json")
-    .with_header("User-Agent", format!("run-that-app-{}", env!("CARGO_PKG_VERSION")))
-    .with_header("X-GitHub-Api-Version", "2022-11-28");
-  let Ok(response) = get.send() else {
-    log(Event::NotOnline);
-    return Err(UserError::NotOnline);
-  };
+  let response = github_api::get(&url, &[("per_page", amount.to_string())], log)?;
   let response_text = response.as_str().map_err(|err| UserError::InvalidGitHubAPIResponse { err: err.to_string() })?;
   parse_versions_response(response_text, tag_format)
 }
@@ -82,10 +66,194 @@ fn parse_versions_response(text: &str, tag_format: &TagFormat) -> Result<Vec<Ver
   Ok(result)
 }
 
+/// a published release on GitHub Releases
+#[derive(Debug, PartialEq)]
+pub struct Release {
+  pub version: Version,
+  pub tag: String,
+  /// the changelog of this release, in Markdown
+  pub body: String,
+  pub url: String,
+}
+
+/// provides the given number of latest stable releases of the given application on GitHub Releases, including their release notes
+pub fn releases(org: &str, repo: &str, amount: usize, tag_format: &TagFormat, log: Log) -> Result<Vec<Release>> {
+  let url = format!("https://api.github.com/repos/{org}/{repo}/releases?per_page={amount}");
+  let response = github_api::get(&url, &[("per_page", amount.to_string())], log)?;
+  let response_text = response.as_str().map_err(|err| UserError::InvalidGitHubAPIResponse { err: err.to_string() })?;
+  parse_releases_response(response_text, tag_format)
+}
+
+fn parse_releases_response(text: &str, tag_format: &TagFormat) -> Result<Vec<Release>> {
+  let releases: serde_json::Value = serde_json::from_str(text).map_err(|err| UserError::GitHubReleasesApiProblem {
+    problem: err.to_string(),
+    payload: text.to_string(),
+  })?;
+  let serde_json::Value::Array(releases) = releases else {
+    return Err(UserError::GitHubReleasesApiProblem {
+      problem: S("unknown API response: does not contain a list of releases"),
+      payload: text.to_string(),
+    });
+  };
+  let mut result = Vec::with_capacity(releases.len());
+  for release in releases {
+    if release["draft"].as_bool() == Some(true) || release["prerelease"].as_bool() == Some(true) {
+      continue;
+    }
+    let Some(tag) = release["tag_name"].as_str() else {
+      continue;
+    };
+    result.push(Release {
+      version: tag_format.parse(tag),
+      tag: tag.to_string(),
+      body: release["body"].as_str().unwrap_or_default().replace("\r\n", "\n"),
+      url: release["html_url"].as_str().unwrap_or_default().to_string(),
+    });
+  }
+  Ok(result)
+}
+
+/// a file attached to a release on GitHub Releases
+#[derive(Debug, PartialEq)]
+pub struct Asset {
+  pub name: String,
+  pub url: String,
+}
+
+/// provides the files attached to the release with the given tag of the given repository on GitHub Releases
+pub fn assets(org: &str, repo: &str, tag: &str, log: Log) -> Result<Vec<Asset>> {
+  let url = format!("https://api.github.com/repos/{org}/{repo}/releases/tags/{tag}");
+  let response = github_api::get(&url, &[], log)?;
+  if response.status_code == 404 {
+    return Err(UserError::GitHubReleaseNotFound {
+      org: org.to_string(),
+      repo: repo.to_string(),
+      tag: tag.to_string(),
+    });
+  }
+  let response_text = response.as_str().map_err(|err| UserError::InvalidGitHubAPIResponse { err: err.to_string() })?;
+  parse_assets_response(response_text)
+}
+
+fn parse_assets_response(text: &str) -> Result<Vec<Asset>> {
+  let release: serde_json::Value = serde_json::from_str(text).map_err(|err| UserError::GitHubReleasesApiProblem {
+    problem: err.to_string(),
+    payload: text.to_string(),
+  })?;
+  let serde_json::Value::Array(assets) = &release["assets"] else {
+    return Err(UserError::GitHubReleasesApiProblem {
+      problem: S("unknown API response: does not contain a list of assets"),
+      payload: text.to_string(),
+    });
+  };
+  let mut result = Vec::with_capacity(assets.len());
+  for asset in assets {
+    if let (Some(name), Some(url)) = (asset["name"].as_str(), asset["browser_download_url"].as_str()) {
+      result.push(Asset {
+        name: name.to_string(),
+        url: url.to_string(),
+      });
+    }
+  }
+  Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
   use crate::configuration::{TagFormat, Version};
 
+  mod parse_assets_response {
+    use super::super::{Asset, parse_assets_response};
+    use big_s::S;
+
+    #[test]
+    fn normal() {
+      let response = r#"
+{
+  "tag_name": "v1.0.0",
+  "assets": [
+    {
+      "name": "tool_linux_amd64.tar.gz",
+      "size": 1024,
+      "browser_download_url": "https://github.com/org/tool/releases/download/v1.0.0/tool_linux_amd64.tar.gz"
+    },
+    {
+      "name": "checksums.txt",
+      "size": 128,
+      "browser_download_url": "https://github.com/org/tool/releases/download/v1.0.0/checksums.txt"
+    }
+  ]
+}"#;
+      let have = parse_assets_response(response).unwrap();
+      let want = vec![
+        Asset {
+          name: S("tool_linux_amd64.tar.gz"),
+          url: S("https://github.com/org/tool/releases/download/v1.0.0/tool_linux_amd64.tar.gz"),
+        },
+        Asset {
+          name: S("checksums.txt"),
+          url: S("https://github.com/org/tool/releases/download/v1.0.0/checksums.txt"),
+        },
+      ];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn no_assets() {
+      let have = parse_assets_response(r#"{"message": "Not Found"}"#);
+      assert!(have.is_err());
+    }
+  }
+
+  mod parse_releases_response {
+    use super::super::{Release, parse_releases_response};
+    use crate::configuration::TagFormat;
+
+    #[test]
+    fn normal() {
+      let response = r#"
+[
+  {
+    "html_url": "https://github.com/rhysd/actionlint/releases/tag/v1.7.1",
+    "tag_name": "v1.7.1",
+    "draft": false,
+    "prerelease": false,
+    "body": "- Fix crash\r\n- Update dependencies"
+  },
+  {
+    "html_url": "https://github.com/rhysd/actionlint/releases/tag/v1.7.1-rc1",
+    "tag_name": "v1.7.1-rc1",
+    "draft": false,
+    "prerelease": true,
+    "body": "release candidate"
+  },
+  {
+    "html_url": "https://github.com/rhysd/actionlint/releases/tag/v1.7.0",
+    "tag_name": "v1.7.0",
+    "draft": false,
+    "prerelease": false,
+    "body": null
+  }
+]"#;
+      let have = parse_releases_response(response, &TagFormat::PrefixV).unwrap();
+      let want = vec![
+        Release {
+          version: "1.7.1".into(),
+          tag: "v1.7.1".into(),
+          body: "- Fix crash\n- Update dependencies".into(),
+          url: "https://github.com/rhysd/actionlint/releases/tag/v1.7.1".into(),
+        },
+        Release {
+          version: "1.7.0".into(),
+          tag: "v1.7.0".into(),
+          body: String::new(),
+          url: "https://github.com/rhysd/actionlint/releases/tag/v1.7.0".into(),
+        },
+      ];
+      pretty::assert_eq!(have, want);
+    }
+  }
+
   #[test]
   #[allow(clippy::too_many_lines)]
   fn parse_versions_response() {
@@ -1,3 +1,4 @@
+use super::github_api;
 use crate::Log;
 use crate::configuration::{TagFormat, Version};
 use crate::error::{Result, UserError};
@@ -6,16 +7,7 @@ use big_s::S;
 
 pub fn all(org: &str, repo: &str, amount: usize, tag_format: &TagFormat, log: Log) -> Result<Vec<Version>> {
   let url = format!("https://api.github.com/repos/{org}/{repo}/git/refs/tags");
-  log(Event::GitHubApiRequestBegin { url: &url });
-  let get = minreq::get(&url)
-    .with_param("per_page", amount.to_string())
-    .with_header("Accept", "application/vnd.github+json")
-    .with_header("User-Agent", format!("run-that-app-{}", env!("CARGO_PKG_VERSION")))
-    .with_header("X-GitHub-Api-Version", "2022-11-28");
-  let Ok(response) = get.send() else {
-    log(Event::NotOnline);
-    return Err(UserError::NotOnline);
-  };
+  let response = github_api::get(&url, &[("per_page", amount.to_string())], log)?;
   let response_text = match response.as_str() {
     Ok(text) => text,
     Err(err) => {
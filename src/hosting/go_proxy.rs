@@ -0,0 +1,109 @@
+use super::registry_api;
+use crate::Log;
+use crate::configuration::Version;
+use crate::error::{Result, UserError};
+
+const PROXY_URL: &str = "https://proxy.golang.org";
+
+/// provides the latest version of the Go module that contains the given package
+pub fn latest(import_path: &str, log: Log) -> Result<Version> {
+  for module in module_candidates(import_path) {
+    let url = format!("{PROXY_URL}/{}/@latest", escape(module));
+    let Some(text) = registry_api::get(&url, log)? else {
+      continue;
+    };
+    let info: serde_json::Value = serde_json::from_str(&text).map_err(|err| UserError::PackageRegistryProblem {
+      url: url.clone(),
+      problem: err.to_string(),
+    })?;
+    return match info["Version"].as_str() {
+      Some(version) => Ok(Version::from(version)),
+      None => Err(UserError::PackageRegistryProblem {
+        url,
+        problem: String::from("missing 'Version' field"),
+      }),
+    };
+  }
+  Err(not_found(import_path))
+}
+
+/// provides the given number of latest versions of the Go module that contains the given package
+pub fn versions(import_path: &str, amount: usize, log: Log) -> Result<Vec<Version>> {
+  for module in module_candidates(import_path) {
+    let url = format!("{PROXY_URL}/{}/@v/list", escape(module));
+    if let Some(text) = registry_api::get(&url, log)? {
+      return Ok(parse_versions(&text, amount));
+    }
+  }
+  Err(not_found(import_path))
+}
+
+/// provides the paths of the modules that could contain the package with the given import path, longest first
+fn module_candidates(import_path: &str) -> Vec<&str> {
+  let mut result = vec![import_path];
+  let mut path = import_path;
+  while let Some((parent, _)) = path.rsplit_once('/') {
+    result.push(parent);
+    path = parent;
+  }
+  result
+}
+
+/// escapes the given module path for use in URLs of the Go module proxy, which encodes uppercase letters as "!" followed by the lowercase letter
+fn escape(module: &str) -> String {
+  let mut result = String::with_capacity(module.len());
+  for c in module.chars() {
+    if c.is_ascii_uppercase() {
+      result.push('!');
+      result.push(c.to_ascii_lowercase());
+    } else {
+      result.push(c);
+    }
+  }
+  result
+}
+
+fn parse_versions(text: &str, amount: usize) -> Vec<Version> {
+  let mut versions: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.contains('-')).collect();
+  // Go versions start with "v", which prevents comparing them as semver
+  versions.sort_unstable_by(|a, b| Version::from(b.trim_start_matches('v')).cmp(&Version::from(a.trim_start_matches('v'))));
+  versions.into_iter().take(amount).map(Version::from).collect()
+}
+
+fn not_found(import_path: &str) -> UserError {
+  UserError::PackageNotFound {
+    name: import_path.to_string(),
+    url: PROXY_URL.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::configuration::Version;
+
+  #[test]
+  fn module_candidates() {
+    let have = super::module_candidates("golang.org/x/tools/cmd/stringer");
+    let want = vec![
+      "golang.org/x/tools/cmd/stringer",
+      "golang.org/x/tools/cmd",
+      "golang.org/x/tools",
+      "golang.org/x",
+      "golang.org",
+    ];
+    assert_eq!(have, want);
+  }
+
+  #[test]
+  fn escape() {
+    assert_eq!(super::escape("github.com/BurntSushi/toml"), "github.com/!burnt!sushi/toml");
+  }
+
+  #[test]
+  fn parse_versions() {
+    let give = "v0.9.0\nv0.20.0\nv0.19.1\nv0.21.0-rc.1\nv0.10.0\n";
+    let have = super::parse_versions(give, 3);
+    let want: Vec<Version> = vec!["v0.20.0".into(), "v0.19.1".into(), "v0.10.0".into()];
+    assert_eq!(have, want);
+  }
+}
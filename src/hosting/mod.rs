@@ -1,5 +1,11 @@
 //! This module accesses code hosting platforms on the internet.
 
+pub mod crates_io;
+mod github_api;
 pub mod github_releases;
 pub mod github_tags;
+pub mod go_proxy;
+pub mod nodejs_dist;
+pub mod npm_registry;
 pub mod pkg_go_dev;
+mod registry_api;
@@ -0,0 +1,100 @@
+use super::registry_api;
+use crate::Log;
+use crate::error::{Result, UserError};
+use serde_json::Value;
+
+/// lists all Node.js releases, newest first
+const INDEX_URL: &str = "https://nodejs.org/dist/index.json";
+
+/// an LTS release line of Node.js
+#[derive(Clone, Debug, PartialEq)]
+pub struct LtsLine {
+  /// lowercase codename of the release line, e.g. "iron"
+  pub codename: String,
+  pub major: u64,
+}
+
+/// provides the LTS release lines of Node.js, newest first
+pub fn lts_lines(log: Log) -> Result<Vec<LtsLine>> {
+  let Some(text) = registry_api::get(INDEX_URL, log)? else {
+    return Err(UserError::PackageRegistryProblem {
+      url: INDEX_URL.to_string(),
+      problem: String::from("not found"),
+    });
+  };
+  parse_lts_lines(&text)
+}
+
+fn parse_lts_lines(text: &str) -> Result<Vec<LtsLine>> {
+  let releases: Value = serde_json::from_str(text).map_err(|err| UserError::PackageRegistryProblem {
+    url: INDEX_URL.to_string(),
+    problem: err.to_string(),
+  })?;
+  let Some(releases) = releases.as_array() else {
+    return Err(UserError::PackageRegistryProblem {
+      url: INDEX_URL.to_string(),
+      problem: String::from("expected a list of releases"),
+    });
+  };
+  let mut result: Vec<LtsLine> = vec![];
+  for release in releases {
+    // the "lts" field contains the codename for LTS releases and false otherwise
+    let Some(codename) = release["lts"].as_str() else {
+      continue;
+    };
+    let Some(major) = release["version"]
+      .as_str()
+      .and_then(|version| version.trim_start_matches('v').split('.').next())
+      .and_then(|major| major.parse().ok())
+    else {
+      continue;
+    };
+    let codename = codename.to_ascii_lowercase();
+    if !result.iter().any(|line| line.codename == codename) {
+      result.push(LtsLine { codename, major });
+    }
+  }
+  result.sort_by_key(|line| std::cmp::Reverse(line.major));
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod parse_lts_lines {
+    use super::super::{LtsLine, parse_lts_lines};
+
+    #[test]
+    fn lts_and_current_releases() {
+      let give = r#"[
+        {"version":"v25.1.0","lts":false},
+        {"version":"v24.11.0","lts":"Krypton"},
+        {"version":"v24.10.0","lts":false},
+        {"version":"v22.21.1","lts":"Jod"},
+        {"version":"v22.21.0","lts":"Jod"},
+        {"version":"v20.19.5","lts":"Iron"}
+      ]"#;
+      let have = parse_lts_lines(give).unwrap();
+      let want = vec![
+        LtsLine {
+          codename: "krypton".into(),
+          major: 24,
+        },
+        LtsLine {
+          codename: "jod".into(),
+          major: 22,
+        },
+        LtsLine {
+          codename: "iron".into(),
+          major: 20,
+        },
+      ];
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn invalid() {
+      assert!(parse_lts_lines("{}").is_err());
+    }
+  }
+}
@@ -0,0 +1,90 @@
+use super::registry_api;
+use crate::Log;
+use crate::configuration::Version;
+use crate::error::{Result, UserError};
+use serde_json::Value;
+
+const REGISTRY_URL: &str = "https://registry.npmjs.org";
+
+/// provides the version of the given npm package tagged as "latest"
+pub fn latest(package: &str, log: Log) -> Result<Version> {
+  let url = package_url(package);
+  let text = load(package, &url, log)?;
+  let package_info = parse(&text, &url)?;
+  match package_info["dist-tags"]["latest"].as_str() {
+    Some(latest) => Ok(Version::from(latest)),
+    None => Err(UserError::PackageRegistryProblem {
+      url,
+      problem: String::from("missing 'dist-tags.latest' field"),
+    }),
+  }
+}
+
+/// provides the given number of latest stable versions of the given npm package
+pub fn versions(package: &str, amount: usize, log: Log) -> Result<Vec<Version>> {
+  let url = package_url(package);
+  let text = load(package, &url, log)?;
+  Ok(parse_versions(&parse(&text, &url)?, amount))
+}
+
+/// provides the URL of the metadata of the given package in the npm registry
+fn package_url(package: &str) -> String {
+  // scoped packages like "@scope/name" need their slash encoded
+  format!("{REGISTRY_URL}/{}", package.replace('/', "%2F"))
+}
+
+fn load(package: &str, url: &str, log: Log) -> Result<String> {
+  registry_api::get(url, log)?.ok_or_else(|| UserError::PackageNotFound {
+    name: package.to_string(),
+    url: REGISTRY_URL.to_string(),
+  })
+}
+
+fn parse(text: &str, url: &str) -> Result<Value> {
+  serde_json::from_str(text).map_err(|err| UserError::PackageRegistryProblem {
+    url: url.to_string(),
+    problem: err.to_string(),
+  })
+}
+
+fn parse_versions(package_info: &Value, amount: usize) -> Vec<Version> {
+  let Value::Object(versions) = &package_info["versions"] else {
+    return vec![];
+  };
+  let mut result: Vec<Version> = versions
+    .keys()
+    .filter(|version| !version.contains('-'))
+    .map(|version| Version::from(version.as_str()))
+    .collect();
+  result.sort_unstable_by(|a, b| b.cmp(a));
+  result.truncate(amount);
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::configuration::Version;
+
+  #[test]
+  fn package_url() {
+    assert_eq!(super::package_url("cowsay"), "https://registry.npmjs.org/cowsay");
+    assert_eq!(super::package_url("@biomejs/biome"), "https://registry.npmjs.org/@biomejs%2Fbiome");
+  }
+
+  #[test]
+  fn parse_versions() {
+    let give = serde_json::json!({
+      "name": "cowsay",
+      "dist-tags": { "latest": "1.6.0" },
+      "versions": {
+        "1.5.0": {},
+        "1.6.0": {},
+        "1.10.0-beta.1": {},
+        "1.4.0": {},
+      }
+    });
+    let have = super::parse_versions(&give, 2);
+    let want: Vec<Version> = vec!["1.6.0".into(), "1.5.0".into()];
+    assert_eq!(have, want);
+  }
+}
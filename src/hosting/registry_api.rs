@@ -0,0 +1,47 @@
+use crate::Log;
+use crate::download::mirror;
+use crate::error::{Result, UserError};
+use crate::logging::Event;
+
+/// sends a GET request to the package registry at the given URL and provides the response body,
+/// None if the registry doesn't know the requested resource
+pub fn get(url: &str, log: Log) -> Result<Option<String>> {
+  let request_url = mirror::rewrite(url, log)?;
+  log(Event::PackageRegistryRequestBegin { url });
+  let Ok(response) = minreq::get(&request_url)
+    .with_header("Accept", "application/json")
+    .with_header(
+      "User-Agent",
+      format!("run-that-app-{} (https://github.com/kevgo/run-that-app)", env!("CARGO_PKG_VERSION")),
+    )
+    .send()
+  else {
+    log(Event::NotOnline);
+    return Err(UserError::NotOnline);
+  };
+  // the Go module proxy indicates unknown modules with 410
+  if response.status_code == 404 || response.status_code == 410 {
+    log(Event::PackageRegistryRequestFail { err: &"not found" });
+    return Ok(None);
+  }
+  if response.status_code != 200 {
+    log(Event::PackageRegistryRequestFail { err: &response.status_code });
+    return Err(UserError::PackageRegistryProblem {
+      url: url.to_string(),
+      problem: format!("{} {}", response.status_code, response.reason_phrase),
+    });
+  }
+  match response.as_str() {
+    Ok(text) => {
+      log(Event::PackageRegistryRequestSuccess);
+      Ok(Some(text.to_string()))
+    }
+    Err(err) => {
+      log(Event::PackageRegistryRequestFail { err: &err });
+      Err(UserError::PackageRegistryProblem {
+        url: url.to_string(),
+        problem: err.to_string(),
+      })
+    }
+  }
+}
@@ -0,0 +1,266 @@
+use crate::applications::ApplicationName;
+use crate::configuration::Version;
+use crate::download::{Artifact, Url, mirror};
+use crate::error::{Result, UserError};
+use crate::logging::{Event, Log};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// the different ways to verify the integrity of a downloaded artifact
+#[derive(Clone, Debug, PartialEq)]
+pub enum Checksum {
+  /// the artifact cannot be verified
+  None,
+
+  /// the artifact must have the given SHA-256 digest
+  Sha256 {
+    /// lowercase hex-encoded SHA-256 digest of the artifact
+    hash: String,
+  },
+
+  /// the SHA-256 digest of the artifact is listed in a checksums file published alongside it,
+  /// for example `checksums.txt` or `<artifact>.sha256`
+  File {
+    /// the URL of the checksums file in the format emitted by `sha256sum`
+    url: Url,
+  },
+
+  /// the SHA-256 digest of the artifact is listed in a JSON release listing like the one at `https://go.dev/dl/?mode=json`
+  Listing {
+    /// the URL of a JSON list of releases whose `files` entries contain the `filename` and `sha256` of each artifact
+    url: Url,
+  },
+}
+
+impl Checksum {
+  /// ensures that the given artifact of the given app version has the expected content
+  pub fn verify(&self, app: &ApplicationName, version: &Version, artifact: &Artifact, log: Log) -> Result<()> {
+    let expected = match self {
+      Checksum::None => return Ok(()),
+      Checksum::Sha256 { hash } => hash.to_lowercase(),
+      Checksum::File { url } => {
+        let text = download_checksums_file(url, log)?;
+        let filename = artifact_filename(&artifact.filename);
+        let Some(hash) = find_hash(&text, filename) else {
+          log(Event::ChecksumFileDownloadFail { err: &"artifact not listed" });
+          return Err(UserError::ChecksumNotListed {
+            checksums: url.clone(),
+            filename: filename.to_string(),
+          });
+        };
+        log(Event::ChecksumFileDownloadSuccess);
+        hash.to_lowercase()
+      }
+      Checksum::Listing { url } => {
+        let text = download_checksums_file(url, log)?;
+        let filename = artifact_filename(&artifact.filename);
+        let Some(hash) = find_listed_hash(&text, filename) else {
+          log(Event::ChecksumFileDownloadFail { err: &"artifact not listed" });
+          return Err(UserError::ChecksumNotListed {
+            checksums: url.clone(),
+            filename: filename.to_string(),
+          });
+        };
+        log(Event::ChecksumFileDownloadSuccess);
+        hash.to_lowercase()
+      }
+    };
+    log(Event::ChecksumVerifyBegin);
+    let actual = sha256(&artifact.data);
+    if actual != expected {
+      log(Event::ChecksumVerifyFail {
+        app,
+        version,
+        file: artifact_filename(&artifact.filename),
+      });
+      return Err(UserError::ChecksumMismatch {
+        url: Url::from(artifact.filename.as_str()),
+        expected,
+        actual,
+      });
+    }
+    log(Event::ChecksumVerifySuccess);
+    Ok(())
+  }
+}
+
+/// provides the lowercase hex-encoded SHA-256 digest of the given data
+pub fn sha256(data: &[u8]) -> String {
+  format!("{:x}", Sha256::digest(data))
+}
+
+fn download_checksums_file(url: &Url, log: Log) -> Result<String> {
+  let download_url = mirror::rewrite(url.as_ref(), log)?;
+  log(Event::ChecksumFileDownloadBegin { url });
+  let Ok(response) = minreq::get(download_url).send() else {
+    log(Event::NotOnline);
+    return Err(UserError::NotOnline);
+  };
+  if response.status_code != 200 {
+    log(Event::ChecksumFileDownloadFail { err: &response.status_code });
+    return Err(UserError::CannotDownload {
+      url: url.clone(),
+      reason: response.reason_phrase,
+    });
+  }
+  response.as_str().map(ToString::to_string).map_err(|err| UserError::CannotDownload {
+    url: url.clone(),
+    reason: err.to_string(),
+  })
+}
+
+/// provides the last segment of the given URL
+fn artifact_filename(url: &str) -> &str {
+  url.rsplit('/').next().unwrap_or(url)
+}
+
+/// Finds the hash of the given file in the given checksums file content.
+/// Understands the output of `sha256sum` as well as files that contain only a hash.
+fn find_hash<'a>(text: &'a str, filename: &str) -> Option<&'a str> {
+  let lines: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+  for line in &lines {
+    let mut parts = line.split_ascii_whitespace();
+    let Some(hash) = parts.next() else {
+      continue;
+    };
+    match parts.next() {
+      Some(name) if name.trim_start_matches('*') == filename => return Some(hash),
+      None if lines.len() == 1 => return Some(hash),
+      _ => {}
+    }
+  }
+  None
+}
+
+/// finds the hash of the given file in the given JSON release listing
+fn find_listed_hash(text: &str, filename: &str) -> Option<String> {
+  let releases: Value = serde_json::from_str(text).ok()?;
+  releases
+    .as_array()?
+    .iter()
+    .filter_map(|release| release["files"].as_array())
+    .flatten()
+    .find(|file| file["filename"].as_str() == Some(filename))
+    .and_then(|file| file["sha256"].as_str())
+    .map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod find_listed_hash {
+    use super::super::find_listed_hash;
+    use big_s::S;
+
+    #[test]
+    fn listed() {
+      let text = r#"[
+        {"version":"go1.22.1","files":[
+          {"filename":"go1.22.1.linux-amd64.tar.gz","sha256":"aab8e15785c997ae20f9c88422ee35d962c4562212bb0f879d052a35c8307c7f"},
+          {"filename":"go1.22.1.linux-arm64.tar.gz","sha256":"e56685a245b6a0c592fc4a55f0b7803af5b3f827aaa29feab1f40e491acf35b8"}
+        ]},
+        {"version":"go1.22.0","files":[
+          {"filename":"go1.22.0.linux-amd64.tar.gz","sha256":"f6c8a87aa03b92c4b0bf3d558e28ea03006eb29db78917daec5cfb6ec1046265"}
+        ]}
+      ]"#;
+      let have = find_listed_hash(text, "go1.22.0.linux-amd64.tar.gz");
+      let want = Some(S("f6c8a87aa03b92c4b0bf3d558e28ea03006eb29db78917daec5cfb6ec1046265"));
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn not_listed() {
+      let text = r#"[{"version":"go1.22.1","files":[{"filename":"go1.22.1.linux-amd64.tar.gz","sha256":"aab8"}]}]"#;
+      assert_eq!(find_listed_hash(text, "go1.22.1.darwin-arm64.tar.gz"), None);
+    }
+
+    #[test]
+    fn invalid() {
+      assert_eq!(find_listed_hash("not JSON", "go1.22.1.linux-amd64.tar.gz"), None);
+    }
+  }
+
+  mod find_hash {
+    use super::super::find_hash;
+
+    #[test]
+    fn checksums_txt() {
+      let text = "\
+0c6f0a2e9a4a4bb3fd0d5d3d43db5e1dbc5c69a5d9a8ddbd7cf5d28d1dc4e8e3  actionlint_1.6.26_darwin_amd64.tar.gz
+5e1b4f5c21b5a4a9a5d4a6e0c7d8b6f5b4f9c8d7e6a5b4c3d2e1f0a9b8c7d6e5  actionlint_1.6.26_linux_amd64.tar.gz
+";
+      let have = find_hash(text, "actionlint_1.6.26_linux_amd64.tar.gz");
+      let want = Some("5e1b4f5c21b5a4a9a5d4a6e0c7d8b6f5b4f9c8d7e6a5b4c3d2e1f0a9b8c7d6e5");
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn binary_marker() {
+      let text = "5e1b4f5c21b5a4a9 *uv-x86_64-unknown-linux-gnu.tar.gz\n";
+      let have = find_hash(text, "uv-x86_64-unknown-linux-gnu.tar.gz");
+      assert_eq!(have, Some("5e1b4f5c21b5a4a9"));
+    }
+
+    #[test]
+    fn hash_only() {
+      let text = "5e1b4f5c21b5a4a9\n";
+      let have = find_hash(text, "anything.tar.gz");
+      assert_eq!(have, Some("5e1b4f5c21b5a4a9"));
+    }
+
+    #[test]
+    fn not_listed() {
+      let text = "0c6f0a2e9a4a4bb3  actionlint_1.6.26_darwin_amd64.tar.gz\n5e1b4f5c21b5a4a9  actionlint_1.6.26_linux_arm64.tar.gz\n";
+      let have = find_hash(text, "actionlint_1.6.26_linux_amd64.tar.gz");
+      assert_eq!(have, None);
+    }
+  }
+
+  mod verify {
+    use crate::download::Artifact;
+    use crate::error::UserError;
+    use crate::installation::Checksum;
+    use crate::logging;
+
+    #[test]
+    fn none() {
+      let artifact = Artifact {
+        filename: "https://example.com/app.tar.gz".into(),
+        data: b"content".to_vec(),
+      };
+      let have = Checksum::None.verify(&"app".into(), &"1.0.0".into(), &artifact, logging::normal_log);
+      assert_eq!(have, Ok(()));
+    }
+
+    #[test]
+    fn matching_hash() {
+      let artifact = Artifact {
+        filename: "https://example.com/app.tar.gz".into(),
+        data: b"content".to_vec(),
+      };
+      let checksum = Checksum::Sha256 {
+        hash: "ED7002B439E9AC845F22357D822BAC1444730FBDB6016D3EC9432297B9EC9F73".into(),
+      };
+      let have = checksum.verify(&"app".into(), &"1.0.0".into(), &artifact, logging::normal_log);
+      assert_eq!(have, Ok(()));
+    }
+
+    #[test]
+    fn mismatching_hash() {
+      let artifact = Artifact {
+        filename: "https://example.com/app.tar.gz".into(),
+        data: b"tampered content".to_vec(),
+      };
+      let checksum = Checksum::Sha256 {
+        hash: "ed7002b439e9ac845f22357d822bac1444730fbdb6016d3ec9432297b9ec9f73".into(),
+      };
+      let have = checksum.verify(&"app".into(), &"1.0.0".into(), &artifact, logging::normal_log);
+      let want = Err(UserError::ChecksumMismatch {
+        url: "https://example.com/app.tar.gz".into(),
+        expected: "ed7002b439e9ac845f22357d822bac1444730fbdb6016d3ec9432297b9ec9f73".into(),
+        actual: "3109af4bf22dace5fa33b410c9f2e40dc1eed214d6c2b9ea0848a17f187ef661".into(),
+      });
+      assert_eq!(have, want);
+    }
+  }
+}
@@ -15,7 +15,7 @@ use which::which;
 /// the different locations from where to get Rust source code
 pub enum RustSource {
   /// install from crates.io
-  CratesIo { name: &'static str },
+  CratesIo { name: String },
   /// install from a remote repository
   Repository { url: Url },
 }
@@ -30,7 +30,7 @@ pub fn run(app_definition: &dyn AppDefinition, version: &Version, app_folder: &P
   let app_folder_str = app_folder.to_string_lossy().to_string();
   let mut args: Vec<String> = vec![S("install"), S("--root"), app_folder_str, S("--locked")];
   match &source {
-    RustSource::CratesIo { name } => args.push(S(name)),
+    RustSource::CratesIo { name } => args.push(name.clone()),
     RustSource::Repository { url } => {
       args.push(S("--git"));
       args.push(url.to_string());
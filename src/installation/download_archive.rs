@@ -1,28 +1,33 @@
-use super::{BinFolder, Outcome};
+use super::{BinFolder, Checksum, Method, Outcome, load_artifact};
 use crate::applications::{AppDefinition, carrier};
 use crate::configuration::Version;
 use crate::context::RuntimeContext;
 use crate::download::Url;
 use crate::error::{Result, UserError};
-use crate::{archives, download, filesystem};
+use crate::{archives, filesystem};
 use std::path::Path;
 
 /// downloads and unpacks the content of an archive file
 pub fn run(
   app_definition: &dyn AppDefinition,
+  install_method: &Method,
   app_folder: &Path,
   version: &Version,
   url: &Url,
+  checksum: &Checksum,
   bin_folders: &BinFolder,
   optional: bool,
   ctx: &RuntimeContext,
 ) -> Result<Outcome> {
   let (app_to_install, executable_name) = carrier(app_definition, version, ctx.platform);
   let app_name = app_to_install.name();
-  // download the archive file
-  let Some(artifact) = download::artifact(url, &app_name, version, optional, ctx.log)? else {
+  // download and verify the archive before extracting anything from it
+  let Some(artifact) = load_artifact(&app_definition.name(), version, install_method, url, checksum, optional, ctx)? else {
     return Ok(Outcome::NotInstalled { app: app_name });
   };
+  ctx
+    .lock_file_mut()
+    .verify_download(&app_definition.name(), version, ctx.platform, install_method, url, &artifact.data)?;
   // determine the archive type
   let Some(archive) = archives::lookup(&artifact.filename, artifact.data) else {
     return Err(UserError::UnknownArchive(artifact.filename));
@@ -1,17 +1,29 @@
-use super::Outcome;
+use super::{Checksum, Method, Outcome, load_artifact};
 use crate::applications::AppDefinition;
 use crate::configuration::Version;
 use crate::context::RuntimeContext;
 use crate::download::Url;
 use crate::error::Result;
-use crate::{download, filesystem};
+use crate::filesystem;
 use std::path::Path;
 
 /// downloads an uncompressed precompiled binary
-pub fn run(app_definition: &dyn AppDefinition, app_folder: &Path, version: &Version, url: &Url, optional: bool, ctx: &RuntimeContext) -> Result<Outcome> {
-  let Some(artifact) = download::artifact(url, &app_definition.name(), version, optional, ctx.log)? else {
+pub fn run(
+  app_definition: &dyn AppDefinition,
+  install_method: &Method,
+  app_folder: &Path,
+  version: &Version,
+  url: &Url,
+  checksum: &Checksum,
+  optional: bool,
+  ctx: &RuntimeContext,
+) -> Result<Outcome> {
+  let Some(artifact) = load_artifact(&app_definition.name(), version, install_method, url, checksum, optional, ctx)? else {
     return Ok(Outcome::NotInstalled { app: app_definition.name() });
   };
+  ctx
+    .lock_file_mut()
+    .verify_download(&app_definition.name(), version, ctx.platform, install_method, url, &artifact.data)?;
   let filepath_on_disk = app_folder.join(app_definition.executable_filename().platform_path(ctx.platform.os).as_ref());
   filesystem::save_executable(artifact.data, &filepath_on_disk, ctx.log);
   Ok(Outcome::Installed)
@@ -0,0 +1,451 @@
+use super::{Checksum, Method, Outcome, load_artifact};
+use crate::applications::AppDefinition;
+use crate::configuration::Version;
+use crate::context::RuntimeContext;
+use crate::download::Url;
+use crate::error::{Result, UserError};
+use crate::executables::ExecutableNamePlatform;
+use crate::hosting::github_releases::{self, Asset};
+use crate::platform::{Cpu, Os, Platform};
+use crate::{archives, filesystem};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// name of the file in the app folder that records which release asset got installed and where its executable is
+const MARKER_FILE_NAME: &str = ".run-that-app-asset";
+
+/// endings of release assets that don't contain the application
+const IGNORED_ENDINGS: &[&str] = &[
+  ".7z",
+  ".apk",
+  ".asc",
+  ".b3",
+  ".bz2",
+  ".crt",
+  ".deb",
+  ".dmg",
+  ".intoto.jsonl",
+  ".json",
+  ".md",
+  ".md5",
+  ".msi",
+  ".pem",
+  ".pkg",
+  ".pub",
+  ".rpm",
+  ".sbom",
+  ".sha1",
+  ".sha256",
+  ".sha256sum",
+  ".sha512",
+  ".sig",
+  ".spdx",
+  ".txt",
+  ".yaml",
+  ".yml",
+  ".zst",
+];
+
+/// words in asset names that identify the respective operating system
+const LINUX_WORDS: &[&str] = &["linux"];
+const MACOS_WORDS: &[&str] = &["apple", "darwin", "mac", "macos", "osx"];
+const WINDOWS_WORDS: &[&str] = &["win", "win32", "win64", "windows"];
+const OTHER_OS_WORDS: &[&str] = &["android", "freebsd", "illumos", "netbsd", "openbsd", "solaris"];
+
+/// words in asset names that identify the respective CPU architecture
+const ARM64_WORDS: &[&str] = &["aarch64", "arm64"];
+const INTEL64_WORDS: &[&str] = &["amd64", "x64", "x86_64", "x86-64"];
+const OTHER_CPU_WORDS: &[&str] = &[
+  "386", "arm", "armv6", "armv7", "armhf", "i386", "i686", "mips", "ppc64le", "riscv64", "s390x", "x86", "x86_32",
+];
+
+/// downloads the release asset for the current platform from GitHub Releases
+pub fn run(
+  app_definition: &dyn AppDefinition,
+  install_method: &Method,
+  app_folder: &Path,
+  version: &Version,
+  org: &str,
+  repo: &str,
+  tag: &str,
+  optional: bool,
+  ctx: &RuntimeContext,
+) -> Result<Outcome> {
+  let app_name = app_definition.name();
+  // imported bundles know the asset, which avoids accessing the GitHub API
+  if let Some(entry) = ctx.bundle.and_then(|bundle| bundle.lookup(&app_name, version, ctx.platform, install_method)) {
+    let asset = Asset {
+      name: entry.url.rsplit('/').next().unwrap_or_default().to_string(),
+      url: entry.url.clone(),
+    };
+    return install_asset(app_definition, install_method, app_folder, version, &asset, optional, ctx);
+  }
+  // the lockfile knows which asset got installed before
+  let locked = ctx
+    .lock_file_mut()
+    .lookup(&app_name, version, ctx.platform)
+    .filter(|entry| entry.matches(install_method))
+    .map(|entry| entry.source.clone());
+  if let Some(url) = locked {
+    let asset = Asset {
+      name: url.rsplit('/').next().unwrap_or_default().to_string(),
+      url,
+    };
+    return install_asset(app_definition, install_method, app_folder, version, &asset, optional, ctx);
+  }
+  let assets = github_releases::assets(org, repo, tag, ctx.log)?;
+  let Some(asset) = select_asset(&assets, ctx.platform) else {
+    return Err(UserError::NoMatchingReleaseAsset {
+      app: app_name,
+      version: version.clone(),
+      platform: ctx.platform.to_string(),
+      assets: assets.into_iter().map(|asset| asset.name).collect(),
+    });
+  };
+  install_asset(app_definition, install_method, app_folder, version, asset, optional, ctx)
+}
+
+/// downloads the given release asset and installs the executable in it
+fn install_asset(
+  app_definition: &dyn AppDefinition,
+  install_method: &Method,
+  app_folder: &Path,
+  version: &Version,
+  asset: &Asset,
+  optional: bool,
+  ctx: &RuntimeContext,
+) -> Result<Outcome> {
+  let app_name = app_definition.name();
+  let url = Url::from(asset.url.as_str());
+  let Some(artifact) = load_artifact(&app_name, version, install_method, &url, &Checksum::None, optional, ctx)? else {
+    return Ok(Outcome::NotInstalled { app: app_name });
+  };
+  ctx
+    .lock_file_mut()
+    .verify_download(&app_name, version, ctx.platform, install_method, &url, &artifact.data)?;
+  let executable_filename = app_definition.executable_filename().platform_path(ctx.platform.os);
+  let executable_path = if archives::is_archive(&asset.name) {
+    let Some(archive) = archives::lookup(&asset.name, artifact.data) else {
+      return Err(UserError::UnknownArchive(asset.name.clone()));
+    };
+    archive.extract_all(app_folder, ctx.platform, ctx.log, &app_name)?;
+    let Some(executable_path) = find_executable(app_folder, &executable_filename) else {
+      return Err(UserError::ReleaseAssetWithoutExecutable {
+        asset: asset.name.clone(),
+        executable: executable_filename.to_string(),
+      });
+    };
+    filesystem::set_executable_bit(&executable_path);
+    executable_path
+  } else {
+    let executable_path = app_folder.join(executable_filename.as_ref());
+    filesystem::save_executable(artifact.data, &executable_path, ctx.log);
+    executable_path
+  };
+  let relative_path = executable_path.strip_prefix(app_folder).unwrap_or(&executable_path);
+  let marker = format!("asset={}\nexecutable={}\n", asset.url, relative_path.to_string_lossy());
+  fs::write(app_folder.join(MARKER_FILE_NAME), marker).map_err(|err| UserError::CannotCreateFile {
+    filename: app_folder.join(MARKER_FILE_NAME),
+    err: err.to_string(),
+  })?;
+  Ok(Outcome::Installed)
+}
+
+/// provides the location of the executable that the release asset installed into the given app folder
+pub fn executable_paths(app_folder: &Path, executable_filename: &ExecutableNamePlatform) -> Vec<PathBuf> {
+  let recorded = fs::read_to_string(app_folder.join(MARKER_FILE_NAME))
+    .ok()
+    .and_then(|text| text.lines().find_map(|line| line.strip_prefix("executable=").map(|path| app_folder.join(path))));
+  match recorded {
+    Some(path) => vec![path],
+    None => vec![app_folder.join(executable_filename.as_ref())],
+  }
+}
+
+/// provides the release asset that most likely contains the application for the given platform
+fn select_asset(assets: &[Asset], platform: Platform) -> Option<&Asset> {
+  let mut result: Option<(&Asset, u8)> = None;
+  for asset in assets {
+    if let Some(score) = score_asset(&asset.name, platform)
+      && result.is_none_or(|(_, best)| score > best)
+    {
+      result = Some((asset, score));
+    }
+  }
+  result.map(|(asset, _)| asset)
+}
+
+/// rates how well the release asset with the given name fits the given platform, None if it doesn't fit
+fn score_asset(name: &str, platform: Platform) -> Option<u8> {
+  let name = name.to_ascii_lowercase();
+  if IGNORED_ENDINGS.iter().any(|ending| name.ends_with(ending)) {
+    return None;
+  }
+  let words = words(&name);
+  let names_any = |candidates: &[&str]| {
+    candidates
+      .iter()
+      .any(|candidate| words.contains(candidate) || (candidate.contains(['_', '-']) && name.contains(candidate)))
+  };
+  let is_exe = words.last() == Some(&"exe");
+  let os_matches = match platform.os {
+    Os::Linux => names_any(LINUX_WORDS) && !names_any(MACOS_WORDS) && !names_any(WINDOWS_WORDS) && !is_exe,
+    Os::MacOS => names_any(MACOS_WORDS) && !names_any(LINUX_WORDS) && !names_any(WINDOWS_WORDS) && !is_exe,
+    Os::Windows => (names_any(WINDOWS_WORDS) || is_exe) && !names_any(LINUX_WORDS) && !names_any(MACOS_WORDS),
+  };
+  if !os_matches || names_any(OTHER_OS_WORDS) {
+    return None;
+  }
+  let (own_cpu, other_cpu) = match platform.cpu {
+    Cpu::Arm64 => (ARM64_WORDS, INTEL64_WORDS),
+    Cpu::Intel64 => (INTEL64_WORDS, ARM64_WORDS),
+  };
+  let mut score = 0;
+  if names_any(own_cpu) {
+    score += 2;
+  } else if names_any(other_cpu) || names_any(OTHER_CPU_WORDS) {
+    return None;
+  }
+  // statically linked executables run on all Linux distributions
+  if platform.os == Os::Linux && words.contains(&"musl") {
+    score += 1;
+  }
+  Some(score)
+}
+
+/// splits the given asset name into its words
+fn words(name: &str) -> Vec<&str> {
+  name.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()).collect()
+}
+
+/// provides the path of the executable with the given name in the given folder or its subfolders.
+/// If there is no file with that name, falls back to the only executable file or the only file in the folder.
+fn find_executable(folder: &Path, executable_filename: &ExecutableNamePlatform) -> Option<PathBuf> {
+  let files = files_in(folder);
+  if let Some(file) = files
+    .iter()
+    .find(|file| file.file_name().is_some_and(|name| name == executable_filename.as_ref()))
+  {
+    return Some(file.clone());
+  }
+  let executables: Vec<&PathBuf> = files.iter().filter(|file| is_executable(file)).collect();
+  if let [executable] = executables[..] {
+    return Some(executable.clone());
+  }
+  match &files[..] {
+    [file] => Some(file.clone()),
+    _ => None,
+  }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+  use std::os::unix::fs::PermissionsExt;
+  fs::metadata(path).is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+  path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("exe"))
+}
+
+/// provides all files in the given folder and its subfolders, sorted so that files in outer folders come first
+fn files_in(folder: &Path) -> Vec<PathBuf> {
+  let mut result = vec![];
+  let mut folders = vec![folder.to_path_buf()];
+  while let Some(folder) = folders.pop() {
+    let Ok(entries) = fs::read_dir(&folder) else {
+      continue;
+    };
+    for entry in entries.flatten() {
+      let path = entry.path();
+      if path.is_dir() {
+        folders.push(path);
+      } else {
+        result.push(path);
+      }
+    }
+  }
+  result.sort_by_key(|path| (path.components().count(), path.clone()));
+  result
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod select_asset {
+    use super::super::select_asset;
+    use crate::hosting::github_releases::Asset;
+    use crate::platform::{Cpu, Os, Platform};
+
+    fn assets(names: &[&str]) -> Vec<Asset> {
+      names
+        .iter()
+        .map(|name| Asset {
+          name: (*name).to_string(),
+          url: format!("https://github.com/org/tool/releases/download/v1.0.0/{name}"),
+        })
+        .collect()
+    }
+
+    fn select(names: &[&str], os: Os, cpu: Cpu) -> Option<String> {
+      let assets = assets(names);
+      select_asset(&assets, Platform { os, cpu }).map(|asset| asset.name.clone())
+    }
+
+    const GO_STYLE: &[&str] = &[
+      "checksums.txt",
+      "tool_1.0.0_darwin_amd64.tar.gz",
+      "tool_1.0.0_darwin_arm64.tar.gz",
+      "tool_1.0.0_linux_386.tar.gz",
+      "tool_1.0.0_linux_amd64.tar.gz",
+      "tool_1.0.0_linux_amd64.deb",
+      "tool_1.0.0_linux_arm64.tar.gz",
+      "tool_1.0.0_windows_amd64.zip",
+      "tool_1.0.0_windows_arm64.zip",
+    ];
+
+    const RUST_STYLE: &[&str] = &[
+      "tool-v1.0.0-aarch64-apple-darwin.tar.gz",
+      "tool-v1.0.0-aarch64-unknown-linux-gnu.tar.gz",
+      "tool-v1.0.0-x86_64-apple-darwin.tar.gz",
+      "tool-v1.0.0-x86_64-pc-windows-msvc.zip",
+      "tool-v1.0.0-x86_64-unknown-linux-gnu.tar.gz",
+      "tool-v1.0.0-x86_64-unknown-linux-gnu.tar.gz.sha256",
+      "tool-v1.0.0-x86_64-unknown-linux-musl.tar.gz",
+    ];
+
+    #[test]
+    fn go_style_linux_intel() {
+      let have = select(GO_STYLE, Os::Linux, Cpu::Intel64);
+      assert_eq!(have.as_deref(), Some("tool_1.0.0_linux_amd64.tar.gz"));
+    }
+
+    #[test]
+    fn go_style_linux_arm() {
+      let have = select(GO_STYLE, Os::Linux, Cpu::Arm64);
+      assert_eq!(have.as_deref(), Some("tool_1.0.0_linux_arm64.tar.gz"));
+    }
+
+    #[test]
+    fn go_style_macos_arm() {
+      let have = select(GO_STYLE, Os::MacOS, Cpu::Arm64);
+      assert_eq!(have.as_deref(), Some("tool_1.0.0_darwin_arm64.tar.gz"));
+    }
+
+    #[test]
+    fn go_style_windows_intel() {
+      let have = select(GO_STYLE, Os::Windows, Cpu::Intel64);
+      assert_eq!(have.as_deref(), Some("tool_1.0.0_windows_amd64.zip"));
+    }
+
+    #[test]
+    fn rust_style_linux_prefers_musl() {
+      let have = select(RUST_STYLE, Os::Linux, Cpu::Intel64);
+      assert_eq!(have.as_deref(), Some("tool-v1.0.0-x86_64-unknown-linux-musl.tar.gz"));
+    }
+
+    #[test]
+    fn rust_style_macos_intel() {
+      let have = select(RUST_STYLE, Os::MacOS, Cpu::Intel64);
+      assert_eq!(have.as_deref(), Some("tool-v1.0.0-x86_64-apple-darwin.tar.gz"));
+    }
+
+    #[test]
+    fn rust_style_windows_arm() {
+      let have = select(RUST_STYLE, Os::Windows, Cpu::Arm64);
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn plain_executables() {
+      let names = &["tool-linux-x64", "tool-macos-arm64", "tool-win-x64.exe"];
+      assert_eq!(select(names, Os::Linux, Cpu::Intel64).as_deref(), Some("tool-linux-x64"));
+      assert_eq!(select(names, Os::MacOS, Cpu::Arm64).as_deref(), Some("tool-macos-arm64"));
+      assert_eq!(select(names, Os::Windows, Cpu::Intel64).as_deref(), Some("tool-win-x64.exe"));
+    }
+
+    #[test]
+    fn universal_macos_binary() {
+      let names = &["tool_darwin_all.tar.gz", "tool_linux_amd64.tar.gz"];
+      let have = select(names, Os::MacOS, Cpu::Arm64);
+      assert_eq!(have.as_deref(), Some("tool_darwin_all.tar.gz"));
+    }
+
+    #[test]
+    fn no_assets() {
+      assert_eq!(select(&[], Os::Linux, Cpu::Intel64), None);
+    }
+  }
+
+  mod find_executable {
+    use super::super::find_executable;
+    use crate::executables::ExecutableNamePlatform;
+    use std::fs;
+
+    #[test]
+    fn in_subfolder() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let folder = tempdir.path().join("tool-1.0.0").join("bin");
+      fs::create_dir_all(&folder).unwrap();
+      fs::write(tempdir.path().join("tool-1.0.0").join("README.md"), "").unwrap();
+      fs::write(folder.join("tool"), "").unwrap();
+      let have = find_executable(tempdir.path(), &ExecutableNamePlatform::from(String::from("tool")));
+      assert_eq!(have, Some(folder.join("tool")));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn only_executable_with_other_name() {
+      use std::os::unix::fs::PermissionsExt;
+      let tempdir = tempfile::tempdir().unwrap();
+      fs::write(tempdir.path().join("README.md"), "").unwrap();
+      fs::write(tempdir.path().join("rg"), "").unwrap();
+      fs::set_permissions(tempdir.path().join("rg"), fs::Permissions::from_mode(0o755)).unwrap();
+      let have = find_executable(tempdir.path(), &ExecutableNamePlatform::from(String::from("ripgrep")));
+      assert_eq!(have, Some(tempdir.path().join("rg")));
+    }
+
+    #[test]
+    fn only_file_with_other_name() {
+      let tempdir = tempfile::tempdir().unwrap();
+      fs::write(tempdir.path().join("tool-linux-amd64"), "").unwrap();
+      let have = find_executable(tempdir.path(), &ExecutableNamePlatform::from(String::from("tool")));
+      assert_eq!(have, Some(tempdir.path().join("tool-linux-amd64")));
+    }
+
+    #[test]
+    fn not_found() {
+      let tempdir = tempfile::tempdir().unwrap();
+      fs::write(tempdir.path().join("README.md"), "").unwrap();
+      fs::write(tempdir.path().join("LICENSE"), "").unwrap();
+      let have = find_executable(tempdir.path(), &ExecutableNamePlatform::from(String::from("tool")));
+      assert_eq!(have, None);
+    }
+  }
+
+  mod executable_paths {
+    use super::super::executable_paths;
+    use crate::executables::ExecutableNamePlatform;
+    use std::fs;
+
+    #[test]
+    fn recorded() {
+      let tempdir = tempfile::tempdir().unwrap();
+      fs::write(
+        tempdir.path().join(".run-that-app-asset"),
+        "asset=https://github.com/org/tool/releases/download/v1.0.0/tool.tar.gz\nexecutable=tool-1.0.0/tool\n",
+      )
+      .unwrap();
+      let have = executable_paths(tempdir.path(), &ExecutableNamePlatform::from(String::from("tool")));
+      assert_eq!(have, vec![tempdir.path().join("tool-1.0.0/tool")]);
+    }
+
+    #[test]
+    fn not_recorded() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let have = executable_paths(tempdir.path(), &ExecutableNamePlatform::from(String::from("tool")));
+      assert_eq!(have, vec![tempdir.path().join("tool")]);
+    }
+  }
+}
@@ -33,6 +33,7 @@ pub fn run(package_name: &str, app_folder: &Path, version: &Version, optional: b
       optional,
       from_source: false,
       include_apps: vec![nodejs.name()],
+      offline: false,
       verbose: false,
       error_on_output: false,
       cwd: Some(app_folder.to_path_buf()),
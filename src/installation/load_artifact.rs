@@ -0,0 +1,47 @@
+use super::{Checksum, Method};
+use crate::applications::ApplicationName;
+use crate::configuration::Version;
+use crate::context::RuntimeContext;
+use crate::download::{self, Artifact, Url};
+use crate::error::{Result, UserError};
+use std::fs;
+
+/// Provides the artifact for installing the given app version using the given installation method.
+/// Takes it from the imported bundle if there is one, otherwise downloads and verifies it.
+pub fn load_artifact(
+  app_name: &ApplicationName,
+  version: &Version,
+  install_method: &Method,
+  url: &Url,
+  checksum: &Checksum,
+  optional: bool,
+  ctx: &RuntimeContext,
+) -> Result<Option<Artifact>> {
+  if let Some(bundle) = ctx.bundle
+    && bundle.is_imported()
+  {
+    // the bundle verified its artifacts against the checksums when it was created
+    let Some(entry) = bundle.lookup(app_name, version, ctx.platform, install_method) else {
+      return Err(UserError::NotInBundle {
+        app: app_name.clone(),
+        version: version.clone(),
+      });
+    };
+    let data = fs::read(&entry.file).map_err(|err| UserError::CannotReadFile {
+      filename: entry.file.clone(),
+      err: err.to_string(),
+    })?;
+    return Ok(Some(Artifact {
+      filename: entry.url.clone(),
+      data,
+    }));
+  }
+  let Some(artifact) = download::artifact(url, app_name, version, optional, ctx.log)? else {
+    return Ok(None);
+  };
+  checksum.verify(app_name, version, &artifact, ctx.log)?;
+  if let Some(bundle) = ctx.bundle {
+    bundle.record(app_name, version, ctx.platform, install_method, url, &artifact.data)?;
+  }
+  Ok(Some(artifact))
+}
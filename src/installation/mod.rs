@@ -1,18 +1,24 @@
 //! This module implements the different ways to download and install an application.
 
+mod checksum;
 mod compile_go;
 mod compile_rust;
 mod download_archive;
 mod download_executable;
+mod download_github_asset;
 mod install_nodejs_package;
+mod load_artifact;
 
 use crate::applications::{AppDefinition, ApplicationName, Apps};
+use crate::bundle::Bundle;
 use crate::configuration::{RequestedVersion, RequestedVersions, Version};
 use crate::context::RuntimeContext;
 use crate::download::Url;
-use crate::error::Result;
+use crate::error::{Result, UserError};
 use crate::executables::ExecutableNamePlatform;
 use crate::installation::compile_rust::RustSource;
+pub use checksum::{Checksum, sha256};
+use load_artifact::load_artifact;
 use std::fmt::{Debug, Display};
 use std::path::{Path, PathBuf};
 
@@ -23,6 +29,8 @@ pub enum Method {
   DownloadArchive {
     /// the URL of the archive to download
     url: Url,
+    /// how to verify the integrity of the downloaded archive
+    checksum: Checksum,
     /// The possible folders within the archive that might contain the executable files.
     /// Multiple options exist because for some apps, the Windows archive contains a different folder structure than the Linux or macOS archive.
     /// Provide all possible folders here. If the executables are in the root folder of the archive, leave this empty.
@@ -33,6 +41,18 @@ pub enum Method {
   DownloadExecutable {
     /// the URL of the executable to download
     url: Url,
+    /// how to verify the integrity of the downloaded executable
+    checksum: Checksum,
+  },
+
+  /// installs the application by downloading the release asset for the current platform from GitHub Releases
+  DownloadGitHubAsset {
+    /// the GitHub organization that publishes the release
+    org: String,
+    /// the GitHub repository that publishes the release
+    repo: String,
+    /// the Git tag of the release
+    tag: String,
   },
 
   /// installs an application written in Go by compiling it from its source hosted on a remote repository
@@ -43,8 +63,8 @@ pub enum Method {
 
   /// installs an application written in Rust by compiling it from its source hosted on crates.io
   CompileRustCrate {
-    /// the name of the Rust crate that contains the executable
-    name: &'static str,
+    /// the name of the Rust crate that contains the executable, optionally with a version like "tokei@12.1.2"
+    name: String,
     /// The subfolder that contains the executables after compilation.
     bin_folder: BinFolder,
   },
@@ -57,10 +77,10 @@ pub enum Method {
 
   InstallNodeJSPackage {
     /// the name of the `NodeJS` package to install
-    package: &'static str,
+    package: String,
 
     /// unix name of the shell script for the package in `node_modules/.bin`
-    script: &'static str,
+    script: String,
   },
 }
 
@@ -68,8 +88,13 @@ impl Method {
   /// provides possible locations of the given executable within the given app folder in the given yard
   pub fn executable_paths(&self, app_folder: &Path, executable_filename: &ExecutableNamePlatform) -> Vec<PathBuf> {
     match self {
-      Method::DownloadArchive { url: _, bin_folder } => bin_folder.executable_paths(app_folder, executable_filename),
-      Method::DownloadExecutable { url: _ } | Method::CompileGoSource { import_path: _ } => vec![app_folder.join(executable_filename.as_ref())],
+      Method::DownloadArchive {
+        url: _,
+        checksum: _,
+        bin_folder,
+      } => bin_folder.executable_paths(app_folder, executable_filename),
+      Method::DownloadExecutable { url: _, checksum: _ } | Method::CompileGoSource { import_path: _ } => vec![app_folder.join(executable_filename.as_ref())],
+      Method::DownloadGitHubAsset { org: _, repo: _, tag: _ } => download_github_asset::executable_paths(app_folder, executable_filename),
       Method::CompileRustCrate { name: _, bin_folder } => match bin_folder {
         BinFolder::Root => vec![app_folder.join(executable_filename.as_ref())],
         BinFolder::Subfolder { path } => vec![app_folder.join(path).join(executable_filename.as_ref())],
@@ -88,17 +113,89 @@ impl Method {
 
   pub fn is_from_source(&self) -> bool {
     match self {
-      Method::DownloadArchive { url: _, bin_folder: _ } | Method::DownloadExecutable { url: _ } | Method::InstallNodeJSPackage { package: _, script: _ } => {
-        false
+      Method::DownloadArchive {
+        url: _,
+        checksum: _,
+        bin_folder: _,
       }
+      | Method::DownloadExecutable { url: _, checksum: _ }
+      | Method::DownloadGitHubAsset { org: _, repo: _, tag: _ }
+      | Method::InstallNodeJSPackage { package: _, script: _ } => false,
       Method::CompileGoSource { import_path: _ } | Method::CompileRustCrate { name: _, bin_folder: _ } | Method::CompileRustRepo { url: _ } => true,
     }
   }
 
+  /// indicates whether this installation method only downloads files, without running anything on this machine
+  pub fn is_download(&self) -> bool {
+    match self {
+      Method::DownloadArchive {
+        url: _,
+        checksum: _,
+        bin_folder: _,
+      }
+      | Method::DownloadExecutable { url: _, checksum: _ }
+      | Method::DownloadGitHubAsset { org: _, repo: _, tag: _ } => true,
+      Method::CompileGoSource { import_path: _ }
+      | Method::CompileRustCrate { name: _, bin_folder: _ }
+      | Method::CompileRustRepo { url: _ }
+      | Method::InstallNodeJSPackage { package: _, script: _ } => false,
+    }
+  }
+
+  /// identifies this installation method in the lockfile
+  pub fn lock_name(&self) -> &'static str {
+    match self {
+      Method::DownloadArchive {
+        url: _,
+        checksum: _,
+        bin_folder: _,
+      } => "download-archive",
+      Method::DownloadExecutable { url: _, checksum: _ } => "download-executable",
+      Method::DownloadGitHubAsset { org: _, repo: _, tag: _ } => "download-github-asset",
+      Method::CompileGoSource { import_path: _ } => "compile-go-source",
+      Method::CompileRustCrate { name: _, bin_folder: _ } => "compile-rust-crate",
+      Method::CompileRustRepo { url: _ } => "compile-rust-repo",
+      Method::InstallNodeJSPackage { package: _, script: _ } => "install-nodejs-package",
+    }
+  }
+
+  /// Provides the location this installation method installs from.
+  /// For GitHub release assets this is the release page, lockfiles and bundles record the URL of the selected asset.
+  pub fn source(&self) -> String {
+    match self {
+      Method::DownloadArchive {
+        url,
+        checksum: _,
+        bin_folder: _,
+      }
+      | Method::DownloadExecutable { url, checksum: _ }
+      | Method::CompileRustRepo { url } => url.to_string(),
+      Method::DownloadGitHubAsset { org, repo, tag } => format!("https://github.com/{org}/{repo}/releases/tag/{tag}"),
+      Method::CompileGoSource { import_path } => import_path.clone(),
+      Method::CompileRustCrate { name, bin_folder: _ } => name.clone(),
+      Method::InstallNodeJSPackage { package, script: _ } => package.clone(),
+    }
+  }
+
+  /// indicates whether the given source recorded in a lockfile or bundle belongs to this installation method
+  pub fn has_source(&self, source: &str) -> bool {
+    match self {
+      Method::DownloadGitHubAsset { org, repo, tag } => source
+        .strip_prefix(&format!("https://github.com/{org}/{repo}/releases/download/{tag}/"))
+        .is_some_and(|asset| !asset.is_empty() && !asset.contains('/')),
+      _ => source == self.source(),
+    }
+  }
+
   pub fn name(&self, app: &ApplicationName, version: &Version) -> String {
     match self {
-      Method::DownloadArchive { url: _, bin_folder: _ } => format!("download archive for {app}@{version}"),
-      Method::DownloadExecutable { url: _ } => format!("download executable for {app}@{version}"),
+      Method::DownloadArchive {
+        url: _,
+        checksum: _,
+        bin_folder: _,
+      } => format!("download archive for {app}@{version}"),
+      Method::DownloadExecutable { url: _, checksum: _ } => format!("download executable for {app}@{version}"),
+      Method::DownloadGitHubAsset { org: _, repo: _, tag: _ } => format!("download release asset for {app}@{version}"),
       Method::CompileGoSource { import_path: _ } | Method::CompileRustCrate { name: _, bin_folder: _ } | Method::CompileRustRepo { url: _ } => {
         format!("compile {app}@{version} from source")
       }
@@ -187,7 +284,7 @@ pub fn versions(
 ) -> Result<Outcome> {
   for version in versions {
     match version {
-      RequestedVersion::Path(_version_req) => {
+      RequestedVersion::Path(_) | RequestedVersion::PathAuto => {
         // we can't install anything into the global path
       }
       RequestedVersion::Yard(version) => match app_version(app, version, optional, from_source, ctx, apps)? {
@@ -201,17 +298,52 @@ pub fn versions(
 
 /// installs the given app at the given version using any of its installation methods
 pub fn app_version(app: &dyn AppDefinition, version: &Version, optional: bool, from_source: bool, ctx: &RuntimeContext, apps: &Apps) -> Result<Outcome> {
-  for install_method in app.run_method(version, ctx.platform).install_methods() {
+  let mut install_methods = app.run_method(version, ctx.platform).install_methods();
+  // use only the installation method recorded in the lockfile
+  if let Some(locked) = ctx.lock_file_mut().lookup(&app.name(), version, ctx.platform) {
+    install_methods.retain(|install_method| locked.matches(install_method));
+    if install_methods.is_empty() {
+      return Err(UserError::LockFileMethodMismatch {
+        app: app.name(),
+        version: version.clone(),
+        locked: format!("{} {}", locked.method, locked.source),
+      });
+    }
+  }
+  // use only the installation methods whose artifacts the imported bundle contains
+  if let Some(bundle) = ctx.bundle
+    && bundle.is_imported()
+  {
+    install_methods.retain(|install_method| bundle.lookup(&app.name(), version, ctx.platform, install_method).is_some());
+    if install_methods.is_empty() {
+      return Err(UserError::NotInBundle {
+        app: app.name(),
+        version: version.clone(),
+      });
+    }
+  }
+  // whether this run skipped installation methods that could work in a regular run
+  let mut skipped_methods = ctx.bundle.is_some_and(Bundle::is_imported);
+  for install_method in install_methods {
     if from_source && !install_method.is_from_source() {
       continue;
     }
+    // compilers and package managers running on this machine produce executables for this machine,
+    // and bundles contain only downloaded artifacts
+    if (ctx.cross_platform || ctx.bundle.is_some()) && !install_method.is_download() {
+      skipped_methods = true;
+      continue;
+    }
     match version_method(app, &install_method, version, optional, ctx, apps)? {
       Outcome::Installed => return Ok(Outcome::Installed),
       Outcome::NotInstalled { app: _ } => {}
     }
   }
   let app_name = app.name();
-  ctx.yard.mark_not_installable(&app_name, version)?;
+  // the skipped installation methods might install this version in a regular run
+  if !skipped_methods {
+    ctx.yard.mark_not_installable(&app_name, version)?;
+  }
   Ok(Outcome::NotInstalled { app: app_name })
 }
 
@@ -242,18 +374,41 @@ pub fn version_method(
       return Ok(Outcome::NotInstalled { app: app_definition.name() });
     }
 
+    if ctx.offline {
+      return Err(UserError::NotAvailableOffline {
+        needed: install_method.name(&app_definition.name(), version),
+      });
+    }
     let staging_folder = ctx.yard.create_staging_folder(&app_definition.name(), version)?;
     let outcome = match install_method {
-      Method::DownloadArchive { url, bin_folder } => download_archive::run(app_definition, &staging_folder, version, url, bin_folder, optional, ctx),
-      Method::DownloadExecutable { url: download_url } => download_executable::run(app_definition, &staging_folder, version, download_url, optional, ctx),
+      Method::DownloadArchive { url, checksum, bin_folder } => download_archive::run(
+        app_definition,
+        install_method,
+        &staging_folder,
+        version,
+        url,
+        checksum,
+        bin_folder,
+        optional,
+        ctx,
+      ),
+      Method::DownloadExecutable { url: download_url, checksum } => {
+        download_executable::run(app_definition, install_method, &staging_folder, version, download_url, checksum, optional, ctx)
+      }
+      Method::DownloadGitHubAsset { org, repo, tag } => {
+        download_github_asset::run(app_definition, install_method, &staging_folder, version, org, repo, tag, optional, ctx)
+      }
       Method::CompileGoSource { import_path } => compile_go::run(&staging_folder, import_path, optional, ctx, apps),
-      Method::CompileRustCrate { name, bin_folder: _ } => compile_rust::run(app_definition, version, &staging_folder, &RustSource::CratesIo { name }, ctx),
+      Method::CompileRustCrate { name, bin_folder: _ } => {
+        compile_rust::run(app_definition, version, &staging_folder, &RustSource::CratesIo { name: name.clone() }, ctx)
+      }
       Method::CompileRustRepo { url } => compile_rust::run(app_definition, version, &staging_folder, &RustSource::Repository { url: url.clone() }, ctx),
       Method::InstallNodeJSPackage { package, script: _ } => install_nodejs_package::run(package, &staging_folder, version, optional, apps),
     }?;
     match outcome {
       Outcome::Installed => {
         ctx.yard.move_staging_folder_to_app_folder(staging_folder, app_folder_path)?;
+        ctx.lock_file_mut().record(&app_definition.name(), version, ctx.platform, install_method);
         Ok(Outcome::Installed)
       }
       Outcome::NotInstalled { app } => Ok(Outcome::NotInstalled { app }),
@@ -29,6 +29,7 @@
 //!   from_source: false,
 //!   include_apps: vec![],
 //!   optional: true,
+//!   offline: false,
 //!   verbose: false,
 //! };
 //! let result = rta::commands::run(args, &apps);
@@ -43,6 +44,7 @@
 
 pub mod applications;
 mod archives;
+mod bundle;
 mod cli;
 pub mod commands;
 mod configuration;
@@ -71,6 +73,7 @@ pub use error::UserError;
 use logging::Log;
 use std::path::Path;
 use std::process::ExitCode;
+use std::sync::Mutex;
 
 /// Runs run-that-app with the given CLI arguments.
 ///
@@ -80,22 +83,40 @@ use std::process::ExitCode;
 /// let exit_code = rta::run(args.into_iter());
 /// ```
 pub fn run(args: impl Iterator<Item = String>) -> error::Result<ExitCode> {
-  let apps = applications::all();
-  match cli::parse(args, &apps)? {
+  let args: Vec<String> = args.collect();
+  // these commands don't resolve apps, so they work even if the custom apps file is broken
+  match cli::parse(args.iter().cloned(), &applications::all()) {
+    Ok(Cli::DisplayHelp) => return Ok(commands::help()),
+    Ok(Cli::Version) => return Ok(commands::version()),
+    Ok(Cli::Gc(args)) => return commands::gc(&args),
+    _ => {}
+  }
+  let mut apps = applications::load()?;
+  apps.add_adhoc_apps(args.iter().map(|arg| cli::app_name(arg)));
+  match cli::parse(args.into_iter(), &apps)? {
     Cli::Add(args) => commands::add(args, &apps),
+    Cli::AppsJson => Ok(commands::applications::json(&apps)),
     Cli::AppsLong => Ok(commands::applications::long(&apps)),
     Cli::AppsShort => Ok(commands::applications::short(&apps)),
     Cli::Available(args) => commands::available(args, &apps),
+    Cli::Bundle(args) => commands::bundle(&args, &apps),
     Cli::DisplayHelp => Ok(commands::help()),
+    Cli::Env(args) => commands::env(&args, &apps),
+    Cli::Gc(args) => commands::gc(&args),
+    Cli::Import(args) => commands::import(&args, &apps),
     Cli::Install(args) => commands::install(args, &apps),
-    Cli::InstallAll => commands::install_all(&apps),
+    Cli::InstallAll(args) => commands::install_all(&args, &apps),
+    Cli::Installed(args) => commands::installed(&args, &apps),
+    Cli::Outdated(args) => commands::outdated(&args, &apps),
     Cli::Reinstall(args) => commands::reinstall(args, &apps),
     Cli::RunApp(args) => commands::run(args, &apps),
+    Cli::Shims(args) => commands::shims(&args, &apps),
     Cli::Test(mut args) => commands::test(&mut args, &apps),
     Cli::Update(args) => commands::update(&args, &apps),
     Cli::Version => Ok(commands::version()),
     Cli::Versions(args) => commands::versions(&args, &apps),
     Cli::Which(args) => commands::which(args, &apps),
+    Cli::WhichConfig => commands::which_config(&apps),
   }
 }
 
@@ -118,6 +139,7 @@ pub fn run(args: impl Iterator<Item = String>) -> error::Result<ExitCode> {
 ///     from_source: false,
 ///     include_apps: vec![],
 ///     optional: false,
+///     offline: false,
 ///     verbose: false,
 ///   },
 /// );
@@ -142,6 +164,7 @@ pub fn get_cmd(
     from_source,
     include_apps,
     optional,
+    offline,
     verbose,
   }: GetCmdArgs,
 ) -> Result<Option<CommandInfo>, error::UserError> {
@@ -149,11 +172,16 @@ pub fn get_cmd(
   let platform = platform::detect(log)?;
   let yard = Yard::load_or_create(&yard::production_location()?)?;
   let config_file = configuration::File::load(apps)?;
+  let lock_file = Mutex::new(configuration::LockFile::load(apps)?);
   let ctx = RuntimeContext {
     platform,
     yard: &yard,
     config_file: &config_file,
+    lock_file: &lock_file,
     log,
+    offline: context::offline(offline),
+    cross_platform: false,
+    bundle: None,
   };
   let include_apps = load_or_install_apps(include_apps, apps, optional, &ctx)?;
   let executable = match load_or_install_app_and_carrier(LoadOrInstallAppAndCarrierArgs {
@@ -168,6 +196,7 @@ pub fn get_cmd(
     LoadOrInstallAppOutcome::NotInstallable { app: _ } if optional => return Ok(None),
     LoadOrInstallAppOutcome::NotInstallable { app } => return Err(error::UserError::UnsupportedPlatform { app }),
   };
+  ctx.lock_file_mut().save(&config_file)?;
   let mut paths_to_include: Vec<&Path> = vec![&executable.parent_path()];
   for app_to_include in &include_apps {
     paths_to_include.push(app_to_include.parent_path());
@@ -206,5 +235,8 @@ pub struct GetCmdArgs<'a> {
   /// whether it's okay to not run the app if it cannot be installed
   pub optional: bool,
 
+  /// whether to use only already installed applications, without accessing the network
+  pub offline: bool,
+
   pub verbose: bool,
 }
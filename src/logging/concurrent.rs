@@ -0,0 +1,64 @@
+use super::Event;
+use crate::applications::ApplicationName;
+use colored::Colorize;
+use std::cell::RefCell;
+
+thread_local! {
+  /// the app, and once known its version, that the installation running on this thread works on
+  static JOB: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// registers that the current thread starts installing the given app
+pub fn start_job(app: &ApplicationName) {
+  JOB.with_borrow_mut(|job| *job = app.to_string());
+}
+
+/// a logger for installing multiple apps at the same time,
+/// prints only complete lines and prefixes them with the app version they belong to,
+/// so that the output of the different installations doesn't get mixed up
+#[allow(clippy::needless_pass_by_value)]
+pub fn log(event: Event) {
+  if let Event::LockAcquireBegin { app, version } = &event {
+    JOB.with_borrow_mut(|job| *job = format!("{app}@{version}"));
+  }
+  let Some(text) = message(&event) else {
+    return;
+  };
+  JOB.with_borrow(|job| {
+    if job.is_empty() {
+      eprintln!("{text}");
+    } else {
+      eprintln!("{} {text}", format!("[{job}]").cyan());
+    }
+  });
+}
+
+/// provides the line to print for the given event, None if the event doesn't print anything
+fn message(event: &Event) -> Option<String> {
+  match event {
+    Event::ArchiveExtractFailed { err } => Some(format!("extracting failed: {}", err.to_string().red())),
+
+    Event::ChecksumFileDownloadFail { err } => Some(format!("downloading the checksums failed: {}", err.to_string().red())),
+    Event::ChecksumVerifyFail { app, version, file } => Some(format!("{}", format!("checksum mismatch for {app}@{version} ({file})").red())),
+
+    Event::CompileGoBegin { go_path: _, args } => Some(format!("go {}", args.join(" "))),
+    Event::CompileGoFailed => Some(format!("{}", "Go compilation failed".red())),
+
+    Event::CompileRustStart { cargo_path: _, args } => Some(format!("cargo {}", args.join(" "))),
+    Event::CompileRustFailed => Some(format!("{}", "Rust compilation failed".red())),
+
+    Event::DownloadBegin { app: _, version: _, url: _ } => Some(String::from("downloading")),
+    Event::DownloadFail { code } => Some(format!("download failed: {}", code.to_string().red())),
+    Event::DownloadNotFound { is_optional: true } => Some(format!("{}", "download not found, skipping".yellow())),
+    Event::DownloadNotFound { is_optional: false } => Some(format!("{}", "download not found".yellow())),
+
+    Event::ExecutableInstallSaveFail { err } => Some(format!("saving failed: {}", err.to_string().red())),
+    Event::FileCreateFail { err } | Event::FolderCreateFail { err } | Event::LockAcquireFail { err } => Some(format!("{}", err.to_string().red())),
+    Event::GitHubApiRequestFail { err } => Some(format!("GitHub API request failed: {}", err.to_string().red())),
+    Event::PackageRegistryRequestFail { err } => Some(format!("package registry request failed: {}", err.to_string().red())),
+    Event::NotOnline => Some(format!("{}", "not online".red())),
+
+    // the remaining events print nothing in normal output, or don't happen while installing apps
+    _ => None,
+  }
+}
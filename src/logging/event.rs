@@ -1,5 +1,5 @@
 use crate::applications::ApplicationName;
-use crate::configuration::Version;
+use crate::configuration::{Version, VersionRange};
 use crate::download::Url;
 use crate::executables::Executable;
 use crate::installation::Method;
@@ -19,6 +19,21 @@ pub enum Event<'a> {
   ArchiveExtractFailed {
     err: &'a dyn Display,
   },
+  ChecksumFileDownloadBegin {
+    url: &'a Url,
+  },
+  ChecksumFileDownloadSuccess,
+  ChecksumFileDownloadFail {
+    err: &'a dyn Display,
+  },
+  ChecksumVerifyBegin,
+  ChecksumVerifySuccess,
+  ChecksumVerifyFail {
+    app: &'a ApplicationName,
+    version: &'a Version,
+    /// name of the downloaded file
+    file: &'a str,
+  },
   CompileGoBegin {
     go_path: Cow<'a, str>,
     args: &'a [String],
@@ -76,11 +91,11 @@ pub enum Event<'a> {
     path: &'a Path,
   },
   GlobalInstallMatchingVersion {
-    range: &'a semver::VersionReq,
+    range: &'a VersionRange,
     version: Option<&'a Version>,
   },
   GlobalInstallMismatchingVersion {
-    range: &'a semver::VersionReq,
+    range: &'a VersionRange,
     version: Option<&'a Version>,
   },
   GlobalInstallNotFound,
@@ -104,6 +119,7 @@ pub enum Event<'a> {
   },
   LockAcquireBegin {
     app: &'a ApplicationName,
+    version: &'a Version,
   },
   LockAcquireFail {
     err: &'a dyn Display,
@@ -112,7 +128,18 @@ pub enum Event<'a> {
   LockRelease {
     app: &'a ApplicationName,
   },
+  MirrorRewrite {
+    from: &'a str,
+    to: &'a str,
+  },
   NotOnline,
+  PackageRegistryRequestBegin {
+    url: &'a str,
+  },
+  PackageRegistryRequestFail {
+    err: &'a dyn Display,
+  },
+  PackageRegistryRequestSuccess,
   UpdateBegin {
     app: &'a ApplicationName,
   },
@@ -124,6 +151,15 @@ pub enum Event<'a> {
   UpdateAlreadyNewest {
     app: &'a ApplicationName,
   },
+  VersionsCacheHit {
+    app: &'a ApplicationName,
+    /// how many seconds ago the cached versions were fetched
+    age: u64,
+  },
+  VersionsCacheWriteFail {
+    path: &'a Path,
+    err: &'a dyn Display,
+  },
   YardCheckExistingAppBegin {
     path: &'a Path,
   },
@@ -1,5 +1,6 @@
 //! This module implements a flexible logging mechanism.
 
+mod concurrent;
 mod event;
 #[macro_use]
 mod eprintf;
@@ -7,6 +8,7 @@ mod log;
 mod normal;
 mod verbose;
 
+pub use concurrent::{log as concurrent_log, start_job as start_concurrent_job};
 pub use event::Event;
 pub use log::{Log, new};
 pub use normal::log as normal_log;
@@ -13,6 +13,13 @@ pub fn log(event: Event) {
     Event::ArchiveExtractSuccess => eprintln!("{}", "ok".green()),
     Event::ArchiveExtractFailed { err } => eprintln!("{}", err.to_string().red()),
 
+    Event::ChecksumFileDownloadBegin { url: _ } => {}
+    Event::ChecksumFileDownloadSuccess => {}
+    Event::ChecksumFileDownloadFail { err } => eprintln!("{}", err.to_string().red()),
+    Event::ChecksumVerifyBegin => {}
+    Event::ChecksumVerifySuccess => {}
+    Event::ChecksumVerifyFail { app, version, file } => eprintln!("{}", format!("checksum mismatch for {app}@{version} ({file})").red()),
+
     Event::CompileGoBegin { go_path: _, args } => eprintln!("go {}", args.join(" ")),
     Event::CompileGoSuccess => {}
     Event::CompileGoFailed => eprintln!("{}", "Go compilation failed".red()),
@@ -62,17 +69,26 @@ pub fn log(event: Event) {
     Event::IntegrationTestDeterminedVersion { version: _ } => {}
     Event::IntegrationTestNewInstallMethod { app, method, version } => eprintln!("\n{}", method.name(app, version).bold()),
 
-    Event::LockAcquireBegin { app: _ } => {}
+    Event::LockAcquireBegin { app: _, version: _ } => {}
     Event::LockAcquireSuccess => {}
     Event::LockAcquireFail { err } => eprintln!("{}", err.to_string().red()),
     Event::LockRelease { app: _ } => {}
 
+    Event::MirrorRewrite { from: _, to: _ } => {}
+
     Event::NotOnline => eprintln!("{}", "not online".red()),
 
+    Event::PackageRegistryRequestBegin { url: _ } => {}
+    Event::PackageRegistryRequestFail { err } => eprintln!("package registry request failed: {}", err.to_string().red()),
+    Event::PackageRegistryRequestSuccess => {}
+
     Event::UpdateBegin { app: _ } => {}
     Event::UpdateNewVersion { app, old_version, new_version } => eprintln!("{app}  {} -> {}", old_version.as_str().green(), new_version.as_str().green()),
     Event::UpdateAlreadyNewest { app } => eprintln!("{app}  {}", "up to date".green()),
 
+    Event::VersionsCacheHit { app: _, age: _ } => {}
+    Event::VersionsCacheWriteFail { path: _, err: _ } => {}
+
     Event::YardCheckExistingAppBegin { path: _ } => {}
     Event::YardCheckExistingAppFound => {}
     Event::YardCheckExistingAppNotFound => {}
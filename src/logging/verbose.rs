@@ -12,6 +12,13 @@ pub fn log(event: Event) {
     Event::ArchiveExtractSuccess => eprintln!("{}", "ok".green()),
     Event::ArchiveExtractFailed { err } => eprintln!("{}", err.to_string().red()),
 
+    Event::ChecksumFileDownloadBegin { url } => eprintf!("downloading checksums {} ... ", url.as_ref().cyan()),
+    Event::ChecksumFileDownloadSuccess => eprintln!("{}", "ok".green()),
+    Event::ChecksumFileDownloadFail { err } => eprintln!("{}", err.to_string().red()),
+    Event::ChecksumVerifyBegin => eprintf!("verifying checksum ... "),
+    Event::ChecksumVerifySuccess => eprintln!("{}", "ok".green()),
+    Event::ChecksumVerifyFail { app, version, file } => eprintln!("{}", format!("mismatch for {app}@{version} ({file})").red()),
+
     Event::CompileGoBegin { go_path, args } => eprintln!("{go_path} {}", args.join(" ")),
     Event::CompileGoSuccess => eprintln!("{}", "Go compilation successful".green()),
     Event::CompileGoFailed => eprintln!("{}", "Go compilation failed".red()),
@@ -83,20 +90,29 @@ pub fn log(event: Event) {
     Event::IntegrationTestDeterminedVersion { version } => eprintln!("Latest version: {}", version.as_str().cyan()),
     Event::IntegrationTestNewInstallMethod { app, method, version } => eprintln!("\n{}", method.name(app, version).bold()),
 
-    Event::LockAcquireBegin { app } => {
-      eprint!("acquiring lock for {} ... ", app.as_str().cyan());
+    Event::LockAcquireBegin { app, version } => {
+      eprint!("acquiring lock for {} {} ... ", app.as_str().cyan(), version.as_str().cyan());
       let _ = io::stderr().flush();
     }
     Event::LockAcquireSuccess => eprintln!("{}", "ok".green()),
     Event::LockAcquireFail { err } => eprintln!("{}", err.to_string().red()),
     Event::LockRelease { app } => eprintln!("releasing lock for {}", app.as_str().cyan()),
 
+    Event::MirrorRewrite { from, to } => eprintln!("using mirror {} for {from}", to.cyan()),
+
     Event::NotOnline => eprintln!("{}", "not online".red()),
 
+    Event::PackageRegistryRequestBegin { url } => eprintf!("Talking to package registry ({url}) ... "),
+    Event::PackageRegistryRequestFail { err } => eprintln!("{}", err.to_string().red()),
+    Event::PackageRegistryRequestSuccess => eprintln!("{}", "ok".green()),
+
     Event::UpdateBegin { app } => eprintln!("updating {} ...", app.as_str().cyan()),
     Event::UpdateNewVersion { app, old_version, new_version } => eprintln!("{app}  {} -> {}", old_version.as_str().green(), new_version.as_str().green()),
     Event::UpdateAlreadyNewest { app } => eprintln!("{app}  {}", "up to date".green()),
 
+    Event::VersionsCacheHit { app, age } => eprintln!("using versions of {app} cached {age} seconds ago"),
+    Event::VersionsCacheWriteFail { path, err } => eprintln!("{}", format!("cannot cache versions in {}: {err}", path.to_string_lossy()).yellow()),
+
     Event::YardCheckExistingAppBegin { path } => eprintf!("Checking for existing app {} ... ", path.to_string_lossy()),
     Event::YardCheckExistingAppFound => eprintln!("{}", "exists".green()),
     Event::YardCheckExistingAppNotFound => eprintln!("{}", "not found".red()),
@@ -5,8 +5,10 @@ mod detect;
 mod os;
 #[allow(clippy::module_inception)]
 mod platform;
+mod target;
 
 pub use cpu::Cpu;
 pub use detect::detect;
 pub use os::Os;
 pub use platform::Platform;
+pub use target::target;
@@ -1,4 +1,5 @@
 use super::{Cpu, Os};
+use crate::error::{Result, UserError};
 use std::fmt::Display;
 
 /// description of the local platform that the binary must be able to execute on
@@ -8,8 +9,78 @@ pub struct Platform {
   pub cpu: Cpu,
 }
 
+impl Platform {
+  /// parses platforms given by the user in the format "<os>/<cpu>", for example "linux/arm64"
+  pub fn parse(text: &str) -> Result<Platform> {
+    let Some((os, cpu)) = text.split_once('/') else {
+      return Err(UserError::UnknownPlatform(text.to_string()));
+    };
+    let os = match os.to_lowercase().as_str() {
+      "linux" => Os::Linux,
+      "macos" | "darwin" => Os::MacOS,
+      "windows" => Os::Windows,
+      _ => return Err(UserError::UnknownPlatform(text.to_string())),
+    };
+    let cpu = match cpu.to_lowercase().as_str() {
+      "intel64" | "amd64" | "x86_64" => Cpu::Intel64,
+      "arm64" | "aarch64" => Cpu::Arm64,
+      _ => return Err(UserError::UnknownPlatform(text.to_string())),
+    };
+    Ok(Platform { os, cpu })
+  }
+}
+
 impl Display for Platform {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.write_fmt(format_args!("{os}/{cpu}", os = self.os, cpu = self.cpu))
   }
 }
+
+#[cfg(test)]
+mod tests {
+
+  mod parse {
+    use super::super::Platform;
+    use crate::error::UserError;
+    use crate::platform::{Cpu, Os};
+
+    #[test]
+    fn canonical() {
+      let have = Platform::parse("linux/arm64");
+      pretty::assert_eq!(
+        have,
+        Ok(Platform {
+          os: Os::Linux,
+          cpu: Cpu::Arm64
+        })
+      );
+    }
+
+    #[test]
+    fn aliases() {
+      let have = Platform::parse("darwin/x86_64");
+      pretty::assert_eq!(
+        have,
+        Ok(Platform {
+          os: Os::MacOS,
+          cpu: Cpu::Intel64
+        })
+      );
+    }
+
+    #[test]
+    fn roundtrip() {
+      let platform = Platform {
+        os: Os::Windows,
+        cpu: Cpu::Intel64,
+      };
+      pretty::assert_eq!(Platform::parse(&platform.to_string()), Ok(platform));
+    }
+
+    #[test]
+    fn unknown() {
+      pretty::assert_eq!(Platform::parse("linux/riscv64"), Err(UserError::UnknownPlatform("linux/riscv64".into())));
+      pretty::assert_eq!(Platform::parse("linux"), Err(UserError::UnknownPlatform("linux".into())));
+    }
+  }
+}
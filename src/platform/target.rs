@@ -0,0 +1,18 @@
+use super::Platform;
+use crate::error::Result;
+use std::env;
+
+/// name of the environment variable that defines the platform to install applications for
+const PLATFORM_ENV_VAR: &str = "RTA_PLATFORM";
+
+/// provides the platform to install applications for:
+/// the given platform, otherwise the one in the environment, otherwise the host platform
+pub fn target(requested: Option<Platform>, host: Platform) -> Result<Platform> {
+  if let Some(platform) = requested {
+    return Ok(platform);
+  }
+  match env::var(PLATFORM_ENV_VAR) {
+    Ok(value) if !value.trim().is_empty() => Platform::parse(value.trim()),
+    _ => Ok(host),
+  }
+}
@@ -1,11 +1,14 @@
 //! This module manages the locally installed applications.
 
+mod versions_cache;
 #[allow(clippy::module_inception)] // I can't come up with a better name for this
 mod yard;
 
 use crate::error::{Result, UserError};
+use crate::platform::Platform;
 use std::path::{Path, PathBuf};
-pub use yard::Yard;
+pub use versions_cache::{VERSIONS_TTL_ENV_VAR, VersionsCache};
+pub use yard::{Yard, app_version, folder_last_used, is_folder_not_installable, parse_app_version};
 
 /// provides the location of the production yard
 pub fn production_location() -> Result<PathBuf> {
@@ -15,6 +18,20 @@ pub fn production_location() -> Result<PathBuf> {
   Ok(home_dir)
 }
 
+/// provides the location of the yard for installing applications for the given target platform
+pub fn location(target: Platform, host: Platform) -> Result<PathBuf> {
+  let production = production_location()?;
+  if target == host {
+    return Ok(production);
+  }
+  // executables for other platforms must not mix with the ones that run on this machine
+  Ok(
+    root_path(&production)
+      .join("platforms")
+      .join(format!("{}-{}", target.os, target.cpu).to_lowercase()),
+  )
+}
+
 pub fn root_path(containing_folder: &Path) -> PathBuf {
   containing_folder.join(".run-that-app")
 }
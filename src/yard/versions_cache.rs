@@ -0,0 +1,558 @@
+use super::Yard;
+use crate::applications::{AppDefinition, ApplicationName, NodeJS};
+use crate::configuration::Version;
+use crate::error::{Result, UserError};
+use crate::hosting::nodejs_dist::{self, LtsLine};
+use crate::logging::{Event, Log};
+use serde_json::{Value, json};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs};
+
+/// name of the environment variable that configures how many seconds cached versions remain valid
+pub const VERSIONS_TTL_ENV_VAR: &str = "RTA_VERSIONS_TTL";
+
+/// how many seconds cached versions remain valid if the user doesn't configure this
+const DEFAULT_TTL: u64 = 3600;
+
+/// Caches the versions of applications available on the internet in the yard,
+/// so that repeated lookups don't query GitHub or other hosting services again.
+pub struct VersionsCache<'a> {
+  yard: &'a Yard,
+  /// how many seconds cached versions remain valid
+  ttl: u64,
+  /// whether to ignore the cached versions and query the internet again
+  refresh: bool,
+  /// whether to use only cached versions, regardless of their age
+  offline: bool,
+}
+
+impl<'a> VersionsCache<'a> {
+  pub fn new(yard: &'a Yard, refresh: bool, offline: bool) -> Result<VersionsCache<'a>> {
+    let ttl = match env::var(VERSIONS_TTL_ENV_VAR) {
+      Ok(value) => parse_ttl(&value)?,
+      Err(_) => DEFAULT_TTL,
+    };
+    Ok(VersionsCache { yard, ttl, refresh, offline })
+  }
+
+  /// provides the latest version of the given app, from the cache if possible
+  pub fn latest_installable_version(&self, app: &dyn AppDefinition, log: Log) -> Result<Version> {
+    let app_name = app.name();
+    let mut entries = self.load(&app_name);
+    if let Some(mut versions) = self.cached(entries.latest.as_ref(), 1, &app_name, log)
+      && let Some(version) = versions.pop()
+    {
+      return Ok(version);
+    }
+    if self.offline {
+      return Err(UserError::NotAvailableOffline {
+        needed: format!("determining the latest version of {app_name}"),
+      });
+    }
+    let version = app.latest_installable_version(log)?;
+    entries.latest = Some(Entry {
+      fetched_at: now(),
+      amount: 1,
+      versions: vec![version.clone()],
+    });
+    self.save(&app_name, &entries, log);
+    Ok(version)
+  }
+
+  /// provides the given amount of the newest versions of the given app, from the cache if possible
+  pub fn installable_versions(&self, app: &dyn AppDefinition, amount: usize, log: Log) -> Result<Vec<Version>> {
+    let app_name = app.name();
+    let mut entries = self.load(&app_name);
+    if let Some(versions) = self.cached(entries.versions.as_ref(), amount, &app_name, log) {
+      return Ok(versions);
+    }
+    if self.offline {
+      return Err(UserError::NotAvailableOffline {
+        needed: format!("determining the available versions of {app_name}"),
+      });
+    }
+    let versions = app.installable_versions(amount, log)?;
+    entries.versions = Some(Entry {
+      fetched_at: now(),
+      amount,
+      versions: versions.clone(),
+    });
+    self.save(&app_name, &entries, log);
+    Ok(versions)
+  }
+
+  /// provides the LTS release lines of Node.js, from the cache if possible
+  pub fn nodejs_lts_lines(&self, log: Log) -> Result<Vec<LtsLine>> {
+    self.lts_lines(&NodeJS {}.name(), || nodejs_dist::lts_lines(log), log)
+  }
+
+  fn lts_lines(&self, app_name: &ApplicationName, fetch: impl FnOnce() -> Result<Vec<LtsLine>>, log: Log) -> Result<Vec<LtsLine>> {
+    let mut entries = self.load(app_name);
+    let now = now();
+    if (!self.refresh || self.offline)
+      && let Some(lts) = &entries.lts
+      // in offline mode, outdated LTS lines are better than none
+      && (self.offline || now.saturating_sub(lts.fetched_at) < self.ttl)
+    {
+      log(Event::VersionsCacheHit {
+        app: app_name,
+        age: now.saturating_sub(lts.fetched_at),
+      });
+      return Ok(lts.lines.clone());
+    }
+    if self.offline {
+      return Err(UserError::NotAvailableOffline {
+        needed: format!("determining the LTS release lines of {app_name}"),
+      });
+    }
+    let lines = fetch()?;
+    entries.lts = Some(LtsEntry {
+      fetched_at: now,
+      lines: lines.clone(),
+    });
+    self.save(app_name, &entries, log);
+    Ok(lines)
+  }
+
+  /// provides the cached versions in the given entry if they are still usable
+  fn cached(&self, entry: Option<&Entry>, amount: usize, app_name: &ApplicationName, log: Log) -> Option<Vec<Version>> {
+    if self.refresh && !self.offline {
+      return None;
+    }
+    let entry = entry?;
+    let now = now();
+    // in offline mode, outdated versions are better than none
+    let max_age = if self.offline { None } else { Some(self.ttl) };
+    let versions = entry.usable_versions(amount, max_age, now)?;
+    log(Event::VersionsCacheHit {
+      app: app_name,
+      age: now.saturating_sub(entry.fetched_at),
+    });
+    Some(versions)
+  }
+
+  fn load(&self, app_name: &ApplicationName) -> Entries {
+    // a missing or unreadable cache file only means that we have to query the internet again
+    fs::read_to_string(self.yard.versions_cache_path(app_name))
+      .ok()
+      .and_then(|text| Entries::parse(&text))
+      .unwrap_or_default()
+  }
+
+  fn save(&self, app_name: &ApplicationName, entries: &Entries, log: Log) {
+    let path = self.yard.versions_cache_path(app_name);
+    let result = match path.parent() {
+      Some(folder) => fs::create_dir_all(folder).and_then(|()| fs::write(&path, entries.to_json().to_string())),
+      None => fs::write(&path, entries.to_json().to_string()),
+    };
+    // failing to cache versions doesn't prevent the current command from succeeding
+    if let Err(err) = result {
+      log(Event::VersionsCacheWriteFail { path: &path, err: &err });
+    }
+  }
+}
+
+/// the cached versions of a particular app
+#[derive(Debug, Default, PartialEq)]
+struct Entries {
+  /// the result of the last lookup of the latest version
+  latest: Option<Entry>,
+  /// the result of the last lookup of all available versions
+  versions: Option<Entry>,
+  /// the result of the last lookup of LTS release lines, only for Node.js
+  lts: Option<LtsEntry>,
+}
+
+impl Entries {
+  /// parses the given cache file content, None if it is corrupted
+  fn parse(text: &str) -> Option<Entries> {
+    let value: Value = serde_json::from_str(text).ok()?;
+    Some(Entries {
+      latest: value.get("latest").and_then(Entry::parse),
+      versions: value.get("versions").and_then(Entry::parse),
+      lts: value.get("lts").and_then(LtsEntry::parse),
+    })
+  }
+
+  fn to_json(&self) -> Value {
+    let mut result = json!({});
+    if let Some(latest) = &self.latest {
+      result["latest"] = latest.to_json();
+    }
+    if let Some(versions) = &self.versions {
+      result["versions"] = versions.to_json();
+    }
+    if let Some(lts) = &self.lts {
+      result["lts"] = lts.to_json();
+    }
+    result
+  }
+}
+
+/// the result of a particular version lookup
+#[derive(Debug, PartialEq)]
+struct Entry {
+  /// when these versions were fetched, in seconds since the Unix epoch
+  fetched_at: u64,
+  /// how many versions were requested when fetching
+  amount: usize,
+  versions: Vec<Version>,
+}
+
+impl Entry {
+  fn parse(value: &Value) -> Option<Entry> {
+    let fetched_at = value.get("fetched_at")?.as_u64()?;
+    let amount = usize::try_from(value.get("amount")?.as_u64()?).ok()?;
+    let mut versions = vec![];
+    for version in value.get("versions")?.as_array()? {
+      versions.push(Version::from(version.as_str()?));
+    }
+    Some(Entry { fetched_at, amount, versions })
+  }
+
+  fn to_json(&self) -> Value {
+    json!({
+      "fetched_at": self.fetched_at,
+      "amount": self.amount,
+      "versions": self.versions.iter().map(Version::as_str).collect::<Vec<_>>(),
+    })
+  }
+
+  /// provides the given amount of versions if this entry contains enough of them and isn't older than the given maximum age
+  fn usable_versions(&self, amount: usize, max_age: Option<u64>, now: u64) -> Option<Vec<Version>> {
+    if self.amount < amount {
+      return None;
+    }
+    if let Some(max_age) = max_age
+      && now.saturating_sub(self.fetched_at) >= max_age
+    {
+      return None;
+    }
+    Some(self.versions.iter().take(amount).cloned().collect())
+  }
+}
+
+/// the result of a lookup of LTS release lines
+#[derive(Debug, PartialEq)]
+struct LtsEntry {
+  /// when these release lines were fetched, in seconds since the Unix epoch
+  fetched_at: u64,
+  lines: Vec<LtsLine>,
+}
+
+impl LtsEntry {
+  fn parse(value: &Value) -> Option<LtsEntry> {
+    let fetched_at = value.get("fetched_at")?.as_u64()?;
+    let mut lines = vec![];
+    for line in value.get("lines")?.as_array()? {
+      lines.push(LtsLine {
+        codename: line.get("codename")?.as_str()?.to_string(),
+        major: line.get("major")?.as_u64()?,
+      });
+    }
+    Some(LtsEntry { fetched_at, lines })
+  }
+
+  fn to_json(&self) -> Value {
+    json!({
+      "fetched_at": self.fetched_at,
+      "lines": self.lines.iter().map(|line| json!({"codename": line.codename, "major": line.major})).collect::<Vec<_>>(),
+    })
+  }
+}
+
+/// parses the TTL given by the user, in seconds
+fn parse_ttl(value: &str) -> Result<u64> {
+  value.trim().parse().map_err(|_| UserError::InvalidVersionsTtl { value: value.to_string() })
+}
+
+/// provides the current time in seconds since the Unix epoch
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs())
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+
+  mod entries {
+    use super::super::{Entries, Entry, LtsEntry};
+    use crate::hosting::nodejs_dist::LtsLine;
+
+    #[test]
+    fn round_trip() {
+      let entries = Entries {
+        latest: Some(Entry {
+          fetched_at: 1_700_000_000,
+          amount: 1,
+          versions: vec!["1.7.7".into()],
+        }),
+        versions: Some(Entry {
+          fetched_at: 1_700_000_100,
+          amount: 3,
+          versions: vec!["1.7.7".into(), "1.7.6".into(), "1.7.5".into()],
+        }),
+        lts: Some(LtsEntry {
+          fetched_at: 1_700_000_200,
+          lines: vec![LtsLine {
+            codename: "jod".into(),
+            major: 22,
+          }],
+        }),
+      };
+      let text = entries.to_json().to_string();
+      let have = Entries::parse(&text);
+      pretty::assert_eq!(have, Some(entries));
+    }
+
+    #[test]
+    fn only_latest() {
+      let have = Entries::parse(r#"{"latest":{"fetched_at":1700000000,"amount":1,"versions":["0.9.0"]}}"#);
+      let want = Some(Entries {
+        latest: Some(Entry {
+          fetched_at: 1_700_000_000,
+          amount: 1,
+          versions: vec!["0.9.0".into()],
+        }),
+        versions: None,
+        lts: None,
+      });
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn corrupted() {
+      assert_eq!(Entries::parse("{\"latest\":"), None);
+    }
+
+    #[test]
+    fn wrong_types() {
+      let have = Entries::parse(r#"{"latest":{"fetched_at":"yesterday","amount":1,"versions":["0.9.0"]}}"#);
+      assert_eq!(have, Some(Entries::default()));
+    }
+  }
+
+  mod usable_versions {
+    use super::super::Entry;
+
+    fn entry() -> Entry {
+      Entry {
+        fetched_at: 1_700_000_000,
+        amount: 3,
+        versions: vec!["1.7.7".into(), "1.7.6".into(), "1.7.5".into()],
+      }
+    }
+
+    #[test]
+    fn fresh() {
+      let have = entry().usable_versions(3, Some(3600), 1_700_000_100);
+      let want = Some(vec!["1.7.7".into(), "1.7.6".into(), "1.7.5".into()]);
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn fewer_requested() {
+      let have = entry().usable_versions(2, Some(3600), 1_700_000_100);
+      let want = Some(vec!["1.7.7".into(), "1.7.6".into()]);
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn more_requested() {
+      let have = entry().usable_versions(10, Some(3600), 1_700_000_100);
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn expired() {
+      let have = entry().usable_versions(3, Some(3600), 1_700_003_600);
+      assert_eq!(have, None);
+    }
+
+    #[test]
+    fn expired_without_max_age() {
+      let have = entry().usable_versions(1, None, 1_800_000_000);
+      let want = Some(vec!["1.7.7".into()]);
+      pretty::assert_eq!(have, want);
+    }
+
+    #[test]
+    fn fewer_available_than_requested() {
+      let entry = Entry {
+        fetched_at: 1_700_000_000,
+        amount: 10,
+        versions: vec!["0.1.0".into()],
+      };
+      let have = entry.usable_versions(5, Some(3600), 1_700_000_100);
+      let want = Some(vec!["0.1.0".into()]);
+      pretty::assert_eq!(have, want);
+    }
+  }
+
+  mod parse_ttl {
+    use super::super::parse_ttl;
+    use crate::error::UserError;
+    use big_s::S;
+
+    #[test]
+    fn seconds() {
+      assert_eq!(parse_ttl("600"), Ok(600));
+    }
+
+    #[test]
+    fn zero() {
+      assert_eq!(parse_ttl(" 0 "), Ok(0));
+    }
+
+    #[test]
+    fn invalid() {
+      let want = Err(UserError::InvalidVersionsTtl { value: S("1h") });
+      assert_eq!(parse_ttl("1h"), want);
+    }
+  }
+
+  mod versions_cache {
+    use super::super::VersionsCache;
+    use crate::applications::{AnalyzeResult, AppDefinition, ApplicationName};
+    use crate::configuration::{TagFormat, Version};
+    use crate::error::{Result, UserError};
+    use crate::executables::{Executable, ExecutableNameUnix, RunMethod};
+    use crate::hosting::nodejs_dist::LtsLine;
+    use crate::logging::{self, Log};
+    use crate::platform::Platform;
+    use crate::yard::Yard;
+    use big_s::S;
+
+    /// an App instance that simulates looking up versions on the internet
+    #[derive(Clone)]
+    struct TestApp {
+      /// the latest version on the internet, None if the internet is not available
+      latest: Option<&'static str>,
+    }
+    impl AppDefinition for TestApp {
+      fn name(&self) -> ApplicationName {
+        "shellcheck".into()
+      }
+      fn executable_filename(&self) -> ExecutableNameUnix {
+        unimplemented!()
+      }
+      fn homepage(&self) -> &str {
+        unimplemented!()
+      }
+      fn installable_versions(&self, amount: usize, _log: Log) -> Result<Vec<Version>> {
+        let latest = self.latest.ok_or(UserError::NotOnline)?;
+        Ok(vec![latest.into(), "0.8.0".into(), "0.7.2".into()].into_iter().take(amount).collect())
+      }
+      fn latest_installable_version(&self, _log: Log) -> Result<Version> {
+        self.latest.map(Version::from).ok_or(UserError::NotOnline)
+      }
+      fn analyze_executable(&self, _executable: &Executable) -> Result<AnalyzeResult> {
+        unimplemented!()
+      }
+      fn run_method(&self, _version: &Version, _platform: Platform) -> RunMethod {
+        unimplemented!()
+      }
+      fn tag_format(&self) -> TagFormat {
+        unimplemented!()
+      }
+    }
+
+    const ONLINE: TestApp = TestApp { latest: Some("0.9.0") };
+    const DISCONNECTED: TestApp = TestApp { latest: None };
+
+    fn cache(yard: &Yard, refresh: bool, offline: bool) -> VersionsCache<'_> {
+      VersionsCache {
+        yard,
+        ttl: 3600,
+        refresh,
+        offline,
+      }
+    }
+
+    #[test]
+    fn caches_latest_version() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let log = logging::new(false);
+      let have = cache(&yard, false, false).latest_installable_version(&ONLINE, log);
+      assert_eq!(have, Ok("0.9.0".into()));
+      assert!(yard.versions_cache_path(&"shellcheck".into()).exists());
+      let have = cache(&yard, false, false).latest_installable_version(&DISCONNECTED, log);
+      assert_eq!(have, Ok("0.9.0".into()));
+    }
+
+    #[test]
+    fn caches_versions() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let log = logging::new(false);
+      cache(&yard, false, false).installable_versions(&ONLINE, 3, log).unwrap();
+      let have = cache(&yard, false, false).installable_versions(&DISCONNECTED, 2, log);
+      assert_eq!(have, Ok(vec!["0.9.0".into(), "0.8.0".into()]));
+      let have = cache(&yard, false, false).installable_versions(&DISCONNECTED, 5, log);
+      assert_eq!(have, Err(UserError::NotOnline));
+    }
+
+    #[test]
+    fn caches_lts_lines() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let log = logging::new(false);
+      let lines = vec![LtsLine {
+        codename: "jod".into(),
+        major: 22,
+      }];
+      let have = cache(&yard, false, false).lts_lines(&"node".into(), || Ok(lines.clone()), log);
+      assert_eq!(have, Ok(lines.clone()));
+      let have = cache(&yard, false, false).lts_lines(&"node".into(), || Err(UserError::NotOnline), log);
+      assert_eq!(have, Ok(lines.clone()));
+      let have = cache(&yard, false, true).lts_lines(&"node".into(), || Err(UserError::NotOnline), log);
+      assert_eq!(have, Ok(lines));
+    }
+
+    #[test]
+    fn lts_lines_offline_without_cache() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let have = cache(&yard, false, true).lts_lines(&"node".into(), || unreachable!(), logging::new(false));
+      let want = Err(UserError::NotAvailableOffline {
+        needed: S("determining the LTS release lines of node"),
+      });
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn refresh() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let log = logging::new(false);
+      cache(&yard, false, false).latest_installable_version(&ONLINE, log).unwrap();
+      let have = cache(&yard, true, false).latest_installable_version(&DISCONNECTED, log);
+      assert_eq!(have, Err(UserError::NotOnline));
+    }
+
+    #[test]
+    fn offline_with_cache() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let log = logging::new(false);
+      cache(&yard, false, false).latest_installable_version(&ONLINE, log).unwrap();
+      let have = cache(&yard, true, true).latest_installable_version(&DISCONNECTED, log);
+      assert_eq!(have, Ok("0.9.0".into()));
+    }
+
+    #[test]
+    fn offline_without_cache() {
+      let tempdir = tempfile::tempdir().unwrap();
+      let yard = Yard::create(tempdir.path()).unwrap();
+      let log = logging::new(false);
+      let have = cache(&yard, false, true).latest_installable_version(&ONLINE, log);
+      let want = Err(UserError::NotAvailableOffline {
+        needed: S("determining the latest version of shellcheck"),
+      });
+      assert_eq!(have, want);
+    }
+  }
+}
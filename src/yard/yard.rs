@@ -8,6 +8,13 @@ use crate::yard::root_path;
 use fd_lock::RwLock;
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// name of the file that marks an app folder as not installable
+const NOT_INSTALLABLE_MARKER: &str = ".run-that-app-not-installable";
+
+/// name of the file whose modification time records when the app version in an app folder was last used
+const LAST_USED_MARKER: &str = ".run-that-app-last-used";
 
 /// The Yard stores application executables and metadata.
 /// Named after rail yards, i.e. locations where passenger cars of trains are stored, sorted, and repaired.
@@ -67,7 +74,7 @@ impl Yard {
   }
 
   pub fn create_staging_folder(&self, app_name: &ApplicationName, version: &Version) -> Result<PathBuf> {
-    let folder_path = self.root.join("staging").join(app_version(app_name, version));
+    let folder_path = self.staging_folder().join(app_version(app_name, version));
     // at this point have have exclusive access to install this app
     // if a folder exists it is from a previous failed installation
     // and safe to delete
@@ -112,9 +119,14 @@ impl Yard {
   }
 
   fn create_lockfile(&self, app_name: &ApplicationName, version: &Version, log: Log) -> Result<(File, PathBuf)> {
+    self.create_named_lockfile(&app_version(app_name, version), log)
+  }
+
+  /// creates the lockfile with the given name, which is the name of the app folder it locks
+  fn create_named_lockfile(&self, name: &str, log: Log) -> Result<(File, PathBuf)> {
     // fast path: try to create the lockfile directly
     let lock_folder = self.lock_folder();
-    let lock_path = lock_folder.join(app_version(app_name, version));
+    let lock_path = lock_folder.join(name);
     log(Event::FileCreateBegin {
       filename: &lock_path.display(),
     });
@@ -186,7 +198,7 @@ impl Yard {
   }
 
   pub fn is_not_installable(&self, app: &ApplicationName, version: &Version) -> bool {
-    self.not_installable_path(app, version).exists()
+    is_folder_not_installable(&self.app_folder(app, version))
   }
 
   pub fn load_executable(
@@ -213,6 +225,11 @@ impl Yard {
     None
   }
 
+  /// provides when the given app version was last used, None if it was never used
+  pub fn last_used(&self, app_name: &ApplicationName, version: &Version) -> Option<SystemTime> {
+    folder_last_used(&self.app_folder(app_name, version))
+  }
+
   pub fn lock_folder(&self) -> PathBuf {
     self.root.join("locks")
   }
@@ -226,6 +243,17 @@ impl Yard {
     }
   }
 
+  /// records that the given app version got used just now
+  pub fn mark_used(&self, app_name: &ApplicationName, version: &Version) {
+    // failing to record the usage must not prevent running the app
+    let _ = File::options()
+      .create(true)
+      .truncate(false)
+      .write(true)
+      .open(self.used_marker_path(app_name, version))
+      .and_then(|file| file.set_modified(SystemTime::now()));
+  }
+
   pub fn move_staging_folder_to_app_folder(&self, staging_folder: PathBuf, app_folder: PathBuf) -> Result<()> {
     // fast path: try to move the folder directly
     let Err(err) = fs::rename(&staging_folder, &app_folder) else {
@@ -251,15 +279,32 @@ impl Yard {
     })
   }
 
+  pub fn staging_folder(&self) -> PathBuf {
+    self.root.join("staging")
+  }
+
+  /// provides the path of the file that caches the versions of the given app available on the internet
+  pub fn versions_cache_path(&self, app_name: &ApplicationName) -> PathBuf {
+    self
+      .root
+      .join("cache")
+      .join("versions")
+      .join(format!("{}.json", urlencoding::encode(app_name.as_str())))
+  }
+
   fn not_installable_path(&self, app_name: &ApplicationName, app_version: &Version) -> PathBuf {
-    self.app_folder(app_name, app_version).join(".run-that-app-not-installable")
+    self.app_folder(app_name, app_version).join(NOT_INSTALLABLE_MARKER)
+  }
+
+  fn used_marker_path(&self, app_name: &ApplicationName, app_version: &Version) -> PathBuf {
+    self.app_folder(app_name, app_version).join(LAST_USED_MARKER)
   }
 
   /// runs the given function while holding a lock on the app folder
   pub fn with_lock<T>(&self, app_name: &ApplicationName, version: &Version, ctx: &RuntimeContext, f: impl FnOnce() -> Result<T>) -> Result<T> {
     // acquire the lock
     let (lock_file, lock_path) = self.create_lockfile(app_name, version, ctx.log)?;
-    (ctx.log)(Event::LockAcquireBegin { app: app_name });
+    (ctx.log)(Event::LockAcquireBegin { app: app_name, version });
     let mut lock = RwLock::new(lock_file);
     let guard = match lock.write() {
       Ok(guard) => {
@@ -288,16 +333,49 @@ impl Yard {
 
     result
   }
+
+  /// runs the given function while holding the lock on the app folder with the given name,
+  /// or provides None without running the function if another process holds that lock
+  pub fn with_lock_if_free<T>(&self, folder_name: &str, log: Log, f: impl FnOnce() -> Result<T>) -> Result<Option<T>> {
+    let (lock_file, _lock_path) = self.create_named_lockfile(folder_name, log)?;
+    let mut lock = RwLock::new(lock_file);
+    let Ok(guard) = lock.try_write() else {
+      return Ok(None);
+    };
+    let result = f();
+    drop(guard);
+    result.map(Some)
+  }
 }
 
 /// provides the filename for the file that locks the installation of the given application at the given version.
+///
+/// Names of ad-hoc apps like "github:org/repo" contain characters that aren't allowed in folder names,
+/// so this function percent-encodes them.
 pub fn app_version(app_name: &ApplicationName, version: &Version) -> String {
-  format!("{app_name}@{version}")
+  format!("{}@{version}", urlencoding::encode(app_name.as_str()))
+}
+
+/// indicates whether the given app folder is marked as not installable
+pub fn is_folder_not_installable(app_folder: &Path) -> bool {
+  app_folder.join(NOT_INSTALLABLE_MARKER).exists()
+}
+
+/// provides when the app version in the given app folder was last used, None if it was never used
+pub fn folder_last_used(app_folder: &Path) -> Option<SystemTime> {
+  fs::metadata(app_folder.join(LAST_USED_MARKER)).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// provides the app name and version encoded in the given name of an app folder
+pub fn parse_app_version(folder_name: &str) -> Option<(String, Version)> {
+  let (app_name, version) = folder_name.rsplit_once('@')?;
+  let app_name = urlencoding::decode(app_name).ok()?;
+  Some((app_name.into_owned(), Version::from(version)))
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::applications::{AppDefinition, ShellCheck};
+  use crate::applications::{AppDefinition, ApplicationName, ShellCheck};
   use crate::configuration::Version;
   use crate::yard::Yard;
   use std::path::PathBuf;
@@ -370,6 +448,36 @@ mod tests {
     assert_eq!(have, want);
   }
 
+  #[test]
+  fn app_version_adhoc_app() {
+    let have = super::app_version(&ApplicationName::from("github:org/repo"), &Version::from("v1.2.3"));
+    assert_eq!(have, "github%3Aorg%2Frepo@v1.2.3");
+  }
+
+  mod parse_app_version {
+    use crate::configuration::Version;
+    use crate::yard::parse_app_version;
+
+    #[test]
+    fn normal() {
+      let have = parse_app_version("shellcheck@0.9.0");
+      let want = Some((String::from("shellcheck"), Version::from("0.9.0")));
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn encoded() {
+      let have = parse_app_version("github%3Aorg%2Frepo@v1.2.3");
+      let want = Some((String::from("github:org/repo"), Version::from("v1.2.3")));
+      assert_eq!(have, want);
+    }
+
+    #[test]
+    fn no_version() {
+      assert_eq!(parse_app_version("shellcheck"), None);
+    }
+  }
+
   #[test]
   fn not_installable_path() {
     let shellcheck = ShellCheck {};